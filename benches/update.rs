@@ -0,0 +1,247 @@
+//! `update()` cost per sample across filter, curve and hysteresis combinations.
+//!
+//! Each group varies one stage with the others off, so a regression points at
+//! its stage; the `pipeline` group covers typical full configurations. Inputs
+//! are a noisy 12-bit triangle sweep, so hysteresis and snap zones see both
+//! held and moving values.
+//!
+//! ```bash
+//! cargo bench --bench update
+//! cargo bench --bench update --all-features -- curve
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{
+    BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::WallTime,
+};
+#[cfg(feature = "grab-mode")]
+use pot_head::GrabMode;
+use pot_head::{
+    Config, ConfigBuilder, Float, HysteresisMode, NoiseFilter, PotBank, PotHead, ResponseCurve,
+    SnapZone, SnapZoneType, StageOrder,
+};
+
+/// One sweep up and down, long enough that the moving average window and
+/// the hysteresis state see realistic movement.
+const SAMPLES: usize = 1024;
+
+static ZONES: [SnapZone<Float>; 3] = [
+    SnapZone::new(0.0, 0.02, SnapZoneType::Snap),
+    SnapZone::new(0.5, 0.03, SnapZoneType::Dead),
+    SnapZone::new(1.0, 0.02, SnapZoneType::Snap),
+];
+
+/// Triangle sweep over 0..=4095 with ±8 counts of deterministic noise.
+fn samples() -> Vec<u16> {
+    let mut seed: u32 = 0x2545_f491;
+    (0..SAMPLES)
+        .map(|i| {
+            let half = SAMPLES / 2;
+            let ramp = if i < half { i } else { SAMPLES - 1 - i };
+            let clean = (ramp * 4095 / (half - 1)) as i32;
+
+            // LCG: cheap and reproducible, so runs compare like for like
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = (seed >> 28) as i32 - 8;
+            (clean + noise).clamp(0, 4095) as u16
+        })
+        .collect()
+}
+
+/// 12-bit ADC to a MIDI CC value, every stage off.
+fn base() -> ConfigBuilder<'static, u16, u8> {
+    Config::from_adc_bits(12).output_range(0, 127)
+}
+
+/// Time one `update()` per iteration, cycling through the sweep.
+fn bench_config(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    config: Config<'static, u16, u8>,
+    samples: &[u16],
+) {
+    let mut pot = PotHead::new(config).unwrap();
+    let mut index = 0;
+
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            index = (index + 1) % samples.len();
+            pot.update(black_box(samples[index]))
+        })
+    });
+}
+
+fn filters(c: &mut Criterion) {
+    let samples = samples();
+    let mut group = c.benchmark_group("filter");
+
+    let configs = [
+        ("none", NoiseFilter::None),
+        ("ema", NoiseFilter::ExponentialMovingAverage { alpha: 0.3 }),
+        #[cfg(feature = "moving-average")]
+        (
+            "moving_average_8",
+            NoiseFilter::MovingAverage { window_size: 8 },
+        ),
+        #[cfg(feature = "moving-average")]
+        (
+            "moving_average_32",
+            NoiseFilter::MovingAverage { window_size: 32 },
+        ),
+    ];
+    for (name, filter) in configs {
+        let config = base().filter(filter).build().unwrap();
+        bench_config(&mut group, name, config, &samples);
+    }
+
+    group.finish();
+}
+
+fn curves(c: &mut Criterion) {
+    let samples = samples();
+    let mut group = c.benchmark_group("curve");
+
+    let configs = [
+        ("linear", ResponseCurve::Linear),
+        #[cfg(feature = "std-math")]
+        ("logarithmic", ResponseCurve::Logarithmic),
+        #[cfg(feature = "log-lut")]
+        ("logarithmic_lut", ResponseCurve::LogarithmicLut),
+    ];
+    for (name, curve) in configs {
+        let config = base().curve(curve).build().unwrap();
+        bench_config(&mut group, name, config, &samples);
+    }
+
+    group.finish();
+}
+
+fn hysteresis(c: &mut Criterion) {
+    let samples = samples();
+    let mut group = c.benchmark_group("hysteresis");
+
+    let configs = [
+        ("none", HysteresisMode::none()),
+        (
+            "change_threshold",
+            HysteresisMode::ChangeThreshold { threshold: 0.01 },
+        ),
+        (
+            "schmitt_trigger",
+            HysteresisMode::SchmittTrigger {
+                rising: 0.6,
+                falling: 0.4,
+            },
+        ),
+    ];
+    for (name, hysteresis) in configs {
+        let config = base().hysteresis(hysteresis).build().unwrap();
+        bench_config(&mut group, name, config, &samples);
+    }
+
+    group.finish();
+}
+
+fn pipeline(c: &mut Criterion) {
+    let samples = samples();
+    let mut group = c.benchmark_group("pipeline");
+
+    // Every stage off: the cost of normalization and denormalization alone
+    let minimal = base().build().unwrap();
+    bench_config(&mut group, "minimal", minimal, &samples);
+
+    // A typical mixer fader: EMA, threshold hysteresis and snap zones
+    let typical = base()
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap();
+    bench_config(&mut group, "typical", typical, &samples);
+
+    #[cfg(feature = "std-math")]
+    {
+        let audio = base()
+            .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+            .curve(ResponseCurve::Logarithmic)
+            .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+            .snap_zones(&ZONES)
+            .build()
+            .unwrap();
+        bench_config(&mut group, "audio_taper", audio, &samples);
+    }
+
+    #[cfg(feature = "grab-mode")]
+    {
+        let grab = base()
+            .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+            .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+            .snap_zones(&ZONES)
+            .grab_mode(GrabMode::PassThrough)
+            .build()
+            .unwrap();
+        bench_config(&mut group, "pass_through", grab, &samples);
+    }
+
+    group.finish();
+}
+
+/// The curve moves between orders, so each runs it at a different point.
+fn stage_orders(c: &mut Criterion) {
+    let samples = samples();
+    let mut group = c.benchmark_group("stage_order");
+
+    let configs = [
+        ("curve_first", StageOrder::CurveFirst),
+        ("hysteresis_first", StageOrder::HysteresisFirst),
+        ("physical_first", StageOrder::PhysicalFirst),
+    ];
+    for (name, order) in configs {
+        let config = base()
+            .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+            .snap_zones(&ZONES)
+            .stage_order(order)
+            .build()
+            .unwrap();
+        bench_config(&mut group, name, config, &samples);
+    }
+
+    group.finish();
+}
+
+/// `PotBank::update_all()` over 16 channels, e.g. one mux scan; compare with
+/// and without the `simd` feature.
+fn bank(c: &mut Criterion) {
+    let samples = samples();
+    let mut group = c.benchmark_group("bank");
+
+    let config = base()
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .build()
+        .unwrap();
+    let mut bank: PotBank<16, u16, u8> = PotBank::from_config(config).unwrap();
+    let mut index = 0;
+
+    group.bench_function("update_all_16", |b| {
+        b.iter(|| {
+            index = (index + 1) % samples.len();
+            let scan = core::array::from_fn(|channel| samples[(index + channel * 64) % SAMPLES]);
+            bank.update_all(black_box(&scan))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    filters,
+    curves,
+    hysteresis,
+    stage_orders,
+    pipeline,
+    bank
+);
+criterion_main!(benches);
@@ -0,0 +1,6 @@
+/* STM32F411CE (Black Pill); adjust for other Cortex-M4F/M7 parts */
+MEMORY
+{
+  FLASH : ORIGIN = 0x08000000, LENGTH = 512K
+  RAM : ORIGIN = 0x20000000, LENGTH = 128K
+}
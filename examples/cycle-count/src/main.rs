@@ -0,0 +1,170 @@
+//! Cycles per `update()` on a Cortex-M, counted with SysTick.
+//!
+//! Runs the same noisy 12-bit sweep through each configuration and prints the
+//! minimum, average and maximum cycles per sample over semihosting. SysTick
+//! runs from the core clock on every Cortex-M, so this works on thumbv6m
+//! (Cortex-M0/M0+) as well as thumbv7. The host-side equivalent is
+//! `cargo bench --bench update` in the crate root.
+
+#![no_std]
+#![no_main]
+
+use core::hint::black_box;
+
+use cortex_m::peripheral::{Peripherals, SYST, syst::SystClkSource};
+use cortex_m_rt::entry;
+use cortex_m_semihosting::{debug, hprintln};
+use panic_semihosting as _;
+use pot_head::{
+    Config, ConfigBuilder, Float, GrabMode, HysteresisMode, NoiseFilter, PotHead, ResponseCurve,
+    SnapZone, SnapZoneType, StageOrder,
+};
+
+const SAMPLES: usize = 256;
+
+static ZONES: [SnapZone<Float>; 3] = [
+    SnapZone::new(0.0, 0.02, SnapZoneType::Snap),
+    SnapZone::new(0.5, 0.03, SnapZoneType::Dead),
+    SnapZone::new(1.0, 0.02, SnapZoneType::Snap),
+];
+
+/// Triangle sweep over 0..=4095 with ±8 counts of deterministic noise, like
+/// the host benchmark.
+fn samples() -> [u16; SAMPLES] {
+    let mut samples = [0; SAMPLES];
+    let mut seed: u32 = 0x2545_f491;
+    let half = SAMPLES / 2;
+
+    for (i, sample) in samples.iter_mut().enumerate() {
+        let ramp = if i < half { i } else { SAMPLES - 1 - i };
+        let clean = (ramp * 4095 / (half - 1)) as i32;
+
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let noise = (seed >> 28) as i32 - 8;
+        *sample = (clean + noise).clamp(0, 4095) as u16;
+    }
+    samples
+}
+
+fn base() -> ConfigBuilder<'static, u16, u8> {
+    Config::from_adc_bits(12).output_range(0, 127)
+}
+
+/// SysTick counts down through 24 bits; one `update()` is far shorter than a
+/// wrap, so the difference modulo 2^24 is exact.
+const RELOAD: u32 = 0x00ff_ffff;
+
+fn elapsed(start: u32, end: u32) -> u32 {
+    start.wrapping_sub(end) & RELOAD
+}
+
+/// Cycles spent reading the counter twice, subtracted from every measurement.
+fn overhead() -> u32 {
+    let start = SYST::get_current();
+    let end = SYST::get_current();
+    elapsed(start, end)
+}
+
+/// Print min/avg/max cycles per `update()` after one warm-up sweep.
+fn measure(name: &str, config: Config<'static, u16, u8>, samples: &[u16], overhead: u32) {
+    let mut pot = PotHead::new(config).unwrap();
+    for &sample in samples {
+        black_box(pot.update(black_box(sample)));
+    }
+
+    let (mut min, mut max, mut total) = (u32::MAX, 0, 0_u64);
+    for &sample in samples {
+        let sample = black_box(sample);
+        let start = SYST::get_current();
+        let output = pot.update(sample);
+        let end = SYST::get_current();
+        black_box(output);
+
+        let cycles = elapsed(start, end).saturating_sub(overhead);
+        min = min.min(cycles);
+        max = max.max(cycles);
+        total += cycles as u64;
+    }
+
+    let avg = total / samples.len() as u64;
+    hprintln!("{:<20} {:>6} {:>6} {:>6}", name, min, avg, max);
+}
+
+#[entry]
+fn main() -> ! {
+    let mut cp = Peripherals::take().unwrap();
+    cp.SYST.set_clock_source(SystClkSource::Core);
+    cp.SYST.set_reload(RELOAD);
+    cp.SYST.clear_current();
+    cp.SYST.enable_counter();
+
+    let samples = samples();
+    let overhead = overhead();
+
+    hprintln!(
+        "{:<20} {:>6} {:>6} {:>6}",
+        "cycles/update",
+        "min",
+        "avg",
+        "max"
+    );
+
+    let config = base().build().unwrap();
+    measure("minimal", config, &samples, overhead);
+
+    let ema = NoiseFilter::ExponentialMovingAverage { alpha: 0.3 };
+    let config = base().filter(ema).build().unwrap();
+    measure("ema", config, &samples, overhead);
+
+    let log = base().curve(ResponseCurve::Logarithmic).build().unwrap();
+    measure("logarithmic", log, &samples, overhead);
+
+    let lut = base().curve(ResponseCurve::LogarithmicLut).build().unwrap();
+    measure("logarithmic_lut", lut, &samples, overhead);
+
+    let threshold = HysteresisMode::ChangeThreshold { threshold: 0.01 };
+    let config = base().hysteresis(threshold).build().unwrap();
+    measure("change_threshold", config, &samples, overhead);
+
+    let schmitt = HysteresisMode::SchmittTrigger {
+        rising: 0.6,
+        falling: 0.4,
+    };
+    let config = base().hysteresis(schmitt).build().unwrap();
+    measure("schmitt_trigger", config, &samples, overhead);
+
+    let typical = base()
+        .filter(ema)
+        .hysteresis(threshold)
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap();
+    measure("typical", typical, &samples, overhead);
+
+    // The curve moves between stage orders; hysteresis and zones stay put
+    let orders = [
+        ("hysteresis_first", StageOrder::HysteresisFirst),
+        ("physical_first", StageOrder::PhysicalFirst),
+    ];
+    for (name, order) in orders {
+        let config = base()
+            .curve(ResponseCurve::Logarithmic)
+            .hysteresis(threshold)
+            .snap_zones(&ZONES)
+            .stage_order(order)
+            .build()
+            .unwrap();
+        measure(name, config, &samples, overhead);
+    }
+
+    let pass_through = base()
+        .filter(ema)
+        .hysteresis(threshold)
+        .grab_mode(GrabMode::PassThrough)
+        .build()
+        .unwrap();
+    measure("pass_through", pass_through, &samples, overhead);
+
+    debug::exit(debug::EXIT_SUCCESS);
+    loop {}
+}
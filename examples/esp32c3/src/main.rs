@@ -0,0 +1,51 @@
+//! A pot on an ESP32-C3 read through esp-hal's calibrated ADC path.
+//!
+//! Wiring: pot ends to 3V3 and GND, wiper to GPIO2. At 11 dB attenuation the
+//! calibrated range ends at 2500 mV, so the top of the pot's travel reads as
+//! full scale; a resistor in series with the pot's 3V3 end (about 1/3 of the
+//! pot's value) uses the whole travel.
+
+#![no_std]
+#![no_main]
+
+use esp_backtrace as _;
+use esp_hal::analog::adc::{Adc, AdcCalCurve, AdcConfig, Attenuation};
+use esp_hal::delay::Delay;
+use esp_hal::peripherals::ADC1;
+use esp_println::println;
+use pot_head::{Config, EspAttenuation, PotHead};
+
+esp_bootloader_esp_idf::esp_app_desc!();
+
+#[esp_hal::main]
+fn main() -> ! {
+    let peripherals = esp_hal::init(esp_hal::Config::default());
+
+    let mut adc_config = AdcConfig::new();
+    let mut pin = adc_config
+        .enable_pin_with_cal::<_, AdcCalCurve<ADC1>>(peripherals.GPIO2, Attenuation::_11dB);
+    let mut adc = Adc::new(peripherals.ADC1, adc_config);
+
+    // Millivolt input range plus the filter/hysteresis preset for this ADC
+    let config = Config::<u16, u8>::builder()
+        .input_esp32c3_millivolts(EspAttenuation::Db11)
+        .output_midi7()
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    let delay = Delay::new();
+    let mut last = None;
+
+    loop {
+        let millivolts: u16 = nb::block!(adc.read_oneshot(&mut pin)).unwrap();
+        let value = pot.update(millivolts);
+
+        if last != Some(value) {
+            println!("{} mV -> {}", millivolts, value);
+            last = Some(value);
+        }
+
+        delay.delay_millis(2);
+    }
+}
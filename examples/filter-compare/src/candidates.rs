@@ -0,0 +1,59 @@
+//! The configs compared side by side. Add or edit entries to try your own.
+
+use pot_head::{Config, HysteresisMode, NoiseFilter};
+
+pub struct Candidate {
+    pub name: &'static str,
+    pub filter: NoiseFilter,
+    pub hysteresis: HysteresisMode<f32>,
+}
+
+pub const CANDIDATES: &[Candidate] = &[
+    Candidate {
+        name: "No filter",
+        filter: NoiseFilter::None,
+        hysteresis: HysteresisMode::none(),
+    },
+    Candidate {
+        name: "EMA 0.3",
+        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.3 },
+        hysteresis: HysteresisMode::none(),
+    },
+    Candidate {
+        name: "EMA 0.1",
+        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.1 },
+        hysteresis: HysteresisMode::none(),
+    },
+    Candidate {
+        name: "MA 8",
+        filter: NoiseFilter::MovingAverage { window_size: 8 },
+        hysteresis: HysteresisMode::none(),
+    },
+    Candidate {
+        name: "MA 16",
+        filter: NoiseFilter::MovingAverage { window_size: 16 },
+        hysteresis: HysteresisMode::none(),
+    },
+    Candidate {
+        name: "Threshold 1%",
+        filter: NoiseFilter::None,
+        hysteresis: HysteresisMode::ChangeThreshold { threshold: 0.01 },
+    },
+    Candidate {
+        name: "EMA 0.3 + 0.5%",
+        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.3 },
+        hysteresis: HysteresisMode::ChangeThreshold { threshold: 0.005 },
+    },
+];
+
+impl Candidate {
+    pub fn config(&self, input_max: u16, output_max: u16) -> Config<'static, u16, u16> {
+        Config::builder()
+            .input_range(0, input_max)
+            .output_range(0, output_max)
+            .filter(self.filter)
+            .hysteresis(self.hysteresis)
+            .build()
+            .expect("candidate configs are valid")
+    }
+}
@@ -0,0 +1,256 @@
+//! Runs one input through several configs side by side.
+//!
+//! The input is either a synthetic knob movement with seeded noise
+//! (`pot_head::test_utils`) or a capture read with `pot_head::csv`. Each
+//! candidate's output is drawn as a sparkline and scored on jitter, error and
+//! settling time, so choosing a filter is a measurement rather than a guess.
+
+mod candidates;
+#[cfg(feature = "plot")]
+mod plot;
+mod report;
+
+use pot_head::test_utils::{Motion, Noise, Signal};
+use pot_head::{IntoNormalized, PotHead};
+use std::fs::File;
+use std::io::BufReader;
+use std::process::ExitCode;
+
+use candidates::CANDIDATES;
+use report::{Scores, or_dash, sparkline};
+
+const USAGE: &str = "\
+Usage: filter-compare [options]
+
+  --motion M         step, sweep, hold or wiggle (default step)
+  --noise N          gaussian, uniform, spikes or none (default gaussian)
+  --level L          Noise sigma or amplitude, fraction of the range (default 0.004)
+  --seed N           Noise seed (default 1)
+  --samples N        Synthetic input length (default 400)
+  --csv FILE         Compare on a capture instead (raw column, or one value per line)
+  --input-max N      Full-scale raw reading of the capture (default 4095)
+  --output-max N     Output range 0..=N, the steps the firmware sees (default 127)
+  --width N          Sparkline width (default 80)
+  --plot FILE        Also write an SVG chart (needs --features plot)";
+
+/// Synthetic input resolution
+const ADC_MAX: u16 = 4095;
+
+struct Args {
+    motion: String,
+    noise: String,
+    level: f32,
+    seed: u32,
+    samples: u32,
+    csv: Option<String>,
+    input_max: u16,
+    output_max: u16,
+    width: usize,
+    plot: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        motion: "step".into(),
+        noise: "gaussian".into(),
+        level: 0.004,
+        seed: 1,
+        samples: 400,
+        csv: None,
+        input_max: ADC_MAX,
+        output_max: 127,
+        width: 80,
+        plot: None,
+    };
+
+    let mut argv = std::env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        let value = argv.next().ok_or(format!("{arg} needs a value\n\n{USAGE}"));
+        let number = |value: Result<String, String>| {
+            let value = value?;
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("{arg}: '{value}' is not a number"))
+        };
+
+        match arg.as_str() {
+            "--motion" => args.motion = value?,
+            "--noise" => args.noise = value?,
+            "--level" => args.level = number(value)? as f32,
+            "--seed" => args.seed = number(value)? as u32,
+            "--samples" => args.samples = number(value)? as u32,
+            "--csv" => args.csv = Some(value?),
+            "--input-max" => args.input_max = number(value)? as u16,
+            "--output-max" => args.output_max = number(value)? as u16,
+            "--width" => args.width = number(value)? as usize,
+            "--plot" => args.plot = Some(value?),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    Ok(args)
+}
+
+struct Input {
+    raw: Vec<u16>,
+
+    /// Normalized position without noise, for synthetic input
+    truth: Option<Vec<f32>>,
+
+    description: String,
+}
+
+fn input(args: &Args) -> Result<Input, String> {
+    if let Some(path) = &args.csv {
+        let file = File::open(path).map_err(|err| format!("{path}: {err}"))?;
+        let raw = pot_head::csv::read_samples(BufReader::new(file))
+            .map_err(|err| format!("{path}: {err}"))?;
+        return Ok(Input {
+            raw,
+            truth: None,
+            description: format!("capture {path}"),
+        });
+    }
+
+    let n = args.samples;
+    let motion = match args.motion.as_str() {
+        "step" => Motion::Step {
+            from: 0.3,
+            to: 0.7,
+            at: n / 4,
+        },
+        "sweep" => Motion::Sweep {
+            from: 0.2,
+            to: 0.8,
+            samples: n / 2,
+        },
+        "hold" => Motion::Hold { position: 0.5 },
+        "wiggle" => Motion::Wiggle {
+            center: 0.5,
+            amplitude: 0.05,
+            period: n / 4,
+        },
+        other => return Err(format!("unknown motion '{other}'\n\n{USAGE}")),
+    };
+    let level = args.level;
+    let noise = match args.noise.as_str() {
+        "gaussian" => Noise::Gaussian { sigma: level },
+        "uniform" => Noise::Uniform { amplitude: level },
+        "spikes" => Noise::Spikes {
+            probability: 0.02,
+            amplitude: level * 10.0,
+        },
+        "none" => Noise::None,
+        other => return Err(format!("unknown noise '{other}'\n\n{USAGE}")),
+    };
+
+    let raw = Signal::new(motion, noise, args.seed)
+        .adc(12)
+        .take(n as usize)
+        .collect();
+    let truth = Signal::new(motion, Noise::None, 0)
+        .take(n as usize)
+        .collect();
+    let description = format!(
+        "{} motion, {} noise {}, seed {}",
+        args.motion, args.noise, level, args.seed
+    );
+    Ok(Input {
+        raw,
+        truth: Some(truth),
+        description,
+    })
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let Input {
+        raw,
+        truth,
+        description,
+    } = input(args)?;
+    let input_max = if args.csv.is_some() {
+        args.input_max
+    } else {
+        ADC_MAX
+    };
+    if raw.len() < 2 {
+        return Err("need at least 2 samples".into());
+    }
+
+    let traces: Vec<(&str, Vec<f32>)> = CANDIDATES
+        .iter()
+        .map(|candidate| {
+            let mut pot = PotHead::new(candidate.config(input_max, args.output_max))
+                .map_err(|err| format!("{}: {}", candidate.name, err))?;
+            let output = pot
+                .update_vec(&raw)
+                .into_iter()
+                .map(|output| output.into_normalized() / args.output_max.into_normalized())
+                .collect();
+            Ok((candidate.name, output))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let raw_normalized: Vec<f32> = raw
+        .iter()
+        .map(|&raw| raw.into_normalized() / input_max.into_normalized())
+        .collect();
+    let (low, high) = raw_normalized
+        .iter()
+        .chain(truth.iter().flatten())
+        .fold((f32::MAX, f32::MIN), |(low, high), &value| {
+            (low.min(value), high.max(value))
+        });
+
+    println!("{} samples of {}", raw.len(), description);
+    println!("Sparklines span {:.3}..{:.3} of the range\n", low, high);
+
+    let width = args.width.max(1);
+    if let Some(truth) = &truth {
+        println!("{:<16}{}", "Clean", sparkline(truth, width, low, high));
+    }
+    println!(
+        "{:<16}{}",
+        "Raw input",
+        sparkline(&raw_normalized, width, low, high)
+    );
+    for (name, output) in &traces {
+        println!("{:<16}{}", name, sparkline(output, width, low, high));
+    }
+
+    println!(
+        "\n{:<16}{:>8}{:>10}{:>11}{:>9}",
+        "", "changes", "at rest", "rms error", "lag"
+    );
+    for (name, output) in &traces {
+        let scores = Scores::new(output, truth.as_deref());
+        println!(
+            "{:<16}{:>8}{:>10}{:>11}{:>9}",
+            name,
+            scores.changes,
+            or_dash(scores.changes_at_rest),
+            or_dash(scores.rms_error.map(|error| format!("{error:.4}"))),
+            or_dash(scores.lag),
+        );
+    }
+
+    if let Some(path) = &args.plot {
+        #[cfg(feature = "plot")]
+        plot::write_svg(path, &raw_normalized, truth.as_deref(), &traces)
+            .map_err(|err| format!("{path}: {err}"))?;
+
+        #[cfg(not(feature = "plot"))]
+        return Err(format!("can't write {path}: rebuild with --features plot"));
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match parse_args().and_then(|args| run(&args)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! SVG chart of the traces, for comparisons too fine for sparklines.
+
+use plotters::prelude::*;
+use std::error::Error;
+
+const SIZE: (u32, u32) = (1200, 600);
+
+pub fn write_svg(
+    path: &str,
+    raw: &[f32],
+    truth: Option<&[f32]>,
+    traces: &[(&str, Vec<f32>)],
+) -> Result<(), Box<dyn Error>> {
+    let root = SVGBackend::new(path, SIZE).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let (low, high) = raw
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(low, high), &value| {
+            (low.min(value), high.max(value))
+        });
+    let margin = (high - low).max(0.01) * 0.05;
+
+    let mut chart = ChartBuilder::on(&root)
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0..raw.len(), (low - margin)..(high + margin))?;
+    chart
+        .configure_mesh()
+        .x_desc("sample")
+        .y_desc("normalized")
+        .draw()?;
+
+    let series = |values: &[f32]| values.iter().copied().enumerate().collect::<Vec<_>>();
+
+    chart
+        .draw_series(LineSeries::new(series(raw), RGBColor(200, 200, 200)))?
+        .label("Raw input")
+        .legend(|(x, y)| PathElement::new([(x, y), (x + 20, y)], RGBColor(200, 200, 200)));
+
+    if let Some(truth) = truth {
+        chart
+            .draw_series(LineSeries::new(series(truth), BLACK))?
+            .label("Clean")
+            .legend(|(x, y)| PathElement::new([(x, y), (x + 20, y)], BLACK));
+    }
+
+    for (index, (name, output)) in traces.iter().enumerate() {
+        let color = Palette99::pick(index).to_rgba();
+        chart
+            .draw_series(LineSeries::new(series(output), color))?
+            .label(*name)
+            .legend(move |(x, y)| PathElement::new([(x, y), (x + 20, y)], color));
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+    root.present()?;
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Scores and terminal sparklines for the traces.
+
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Longest delay `lag` looks for
+const MAX_LAG: usize = 64;
+
+/// One column per `values.len() / width` samples, showing the last sample of
+/// each so jitter stays visible. `low..high` maps to the full block height.
+pub fn sparkline(values: &[f32], width: usize, low: f32, high: f32) -> String {
+    let step = values.len().div_ceil(width).max(1);
+    let span = (high - low).max(f32::EPSILON);
+
+    values
+        .chunks(step)
+        .map(|chunk| {
+            let level = ((chunk[chunk.len() - 1] - low) / span).clamp(0.0, 1.0);
+            LEVELS[(level * (LEVELS.len() - 1) as f32).round() as usize]
+        })
+        .collect()
+}
+
+pub struct Scores {
+    /// Samples whose output differs from the one before
+    pub changes: usize,
+
+    /// Output changes while the true position held still: noise let through.
+    /// Needs the clean signal.
+    pub changes_at_rest: Option<usize>,
+
+    /// Root mean square distance from the clean signal
+    pub rms_error: Option<f32>,
+
+    /// Delay in samples that best lines the output up with the clean signal.
+    /// `None` if the clean signal never moves.
+    pub lag: Option<usize>,
+}
+
+impl Scores {
+    pub fn new(output: &[f32], truth: Option<&[f32]>) -> Self {
+        let changes = output.windows(2).filter(|pair| pair[0] != pair[1]).count();
+        let Some(truth) = truth else {
+            return Self {
+                changes,
+                changes_at_rest: None,
+                rms_error: None,
+                lag: None,
+            };
+        };
+
+        let changes_at_rest = (1..output.len())
+            .filter(|&i| truth[i] == truth[i - 1] && output[i] != output[i - 1])
+            .count();
+
+        let rms_error = rms(output, truth);
+        let lag = (truth.windows(2).any(|pair| pair[0] != pair[1])).then(|| {
+            (0..=MAX_LAG.min(output.len() / 4))
+                .min_by(|&a, &b| {
+                    let error = |lag: usize| rms(&output[lag..], truth);
+                    error(a).total_cmp(&error(b))
+                })
+                .unwrap_or(0)
+        });
+
+        Self {
+            changes,
+            changes_at_rest: Some(changes_at_rest),
+            rms_error: Some(rms_error),
+            lag,
+        }
+    }
+}
+
+/// Root mean square difference over the shorter of the two.
+fn rms(a: &[f32], b: &[f32]) -> f32 {
+    let squared: f32 = a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum();
+    (squared / a.len().min(b.len()).max(1) as f32).sqrt()
+}
+
+/// `value` as text, or "-" when it wasn't measured.
+pub fn or_dash<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".into(), |value| value.to_string())
+}
@@ -3,7 +3,7 @@
 //! This example shows how to use EMA and Moving Average filters
 //! to smooth noisy ADC readings.
 
-use pot_head::{Config, HysteresisMode, NoiseFilter, PotHead, ResponseCurve};
+use pot_head::{Config, HysteresisMode, NoiseFilter, PotHead};
 
 fn main() {
     println!("=== pot-head Filtering Examples ===\n");
@@ -12,15 +12,11 @@ fn main() {
 
     // Example 1: No Filter (for comparison)
     println!("1. No Filter (raw passthrough)");
-    let config = Config {
-        input_min: 0_u16,
-        input_max: 4095_u16,
-        output_min: 0_u16,
-        output_max: 4095_u16,
-        hysteresis: HysteresisMode::none(),
-        curve: ResponseCurve::Linear,
-        filter: NoiseFilter::None,
-    };
+    let config = Config::builder()
+        .input_range(0_u16, 4095_u16)
+        .output_range(0_u16, 4095_u16)
+        .build()
+        .expect("Valid config");
 
     let mut pot = PotHead::new(config).expect("Valid config");
 
@@ -33,15 +29,12 @@ fn main() {
 
     // Example 2: Moving Average Filter
     println!("2. Moving Average Filter (window=5)");
-    let config = Config {
-        input_min: 0_u16,
-        input_max: 4095_u16,
-        output_min: 0_u16,
-        output_max: 4095_u16,
-        hysteresis: HysteresisMode::none(),
-        curve: ResponseCurve::Linear,
-        filter: NoiseFilter::MovingAverage { window_size: 5 },
-    };
+    let config = Config::builder()
+        .input_range(0_u16, 4095_u16)
+        .output_range(0_u16, 4095_u16)
+        .filter(NoiseFilter::MovingAverage { window_size: 5 })
+        .build()
+        .expect("Valid config");
 
     let mut pot = PotHead::new(config).expect("Valid config");
 
@@ -55,15 +48,12 @@ fn main() {
 
     // Example 3: EMA Filter (Exponential Moving Average)
     println!("3. EMA Filter (alpha=0.3)");
-    let config = Config {
-        input_min: 0_u16,
-        input_max: 4095_u16,
-        output_min: 0_u16,
-        output_max: 4095_u16,
-        hysteresis: HysteresisMode::none(),
-        curve: ResponseCurve::Linear,
-        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.3 },
-    };
+    let config = Config::builder()
+        .input_range(0_u16, 4095_u16)
+        .output_range(0_u16, 4095_u16)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .build()
+        .expect("Valid config");
 
     let mut pot = PotHead::new(config).expect("Valid config");
 
@@ -77,15 +67,13 @@ fn main() {
 
     // Example 4: Filter + Hysteresis
     println!("4. EMA Filter + 1% Threshold hysteresis");
-    let config = Config {
-        input_min: 0_u16,
-        input_max: 4095_u16,
-        output_min: 0_u16,
-        output_max: 4095_u16,
-        hysteresis: HysteresisMode::ChangeThreshold { threshold: 0.01 },
-        curve: ResponseCurve::Linear,
-        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.3 },
-    };
+    let config = Config::builder()
+        .input_range(0_u16, 4095_u16)
+        .output_range(0_u16, 4095_u16)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .build()
+        .expect("Valid config");
 
     let mut pot = PotHead::new(config).expect("Valid config");
 
@@ -3,8 +3,9 @@ use crate::pot_spec::{
 };
 use crate::renderable_pot::RenderablePot;
 use crate::rendering::BAR_WIDTH;
-use rand_distr::{Distribution, Normal};
+use pot_head::test_utils::{Noise, Rng};
 use std::io::Result;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 // Step size matches bar width so each arrow key press moves one position on the bar
 const STEP_SIZE: f32 = 1.0 / BAR_WIDTH as f32;
@@ -16,6 +17,7 @@ pub struct AppState {
     pub pots: Vec<Box<dyn RenderablePot>>,
     pub selected_pot_index: usize,
     pub running: bool,
+    rng: Rng,
 }
 
 impl AppState {
@@ -42,6 +44,7 @@ impl AppState {
             pots,
             selected_pot_index: 0,
             running: true,
+            rng: Rng::new(seed()),
         })
     }
 
@@ -85,22 +88,21 @@ impl AppState {
 
     /// Get the current input with noise applied
     /// Uses Gaussian noise scaled by noise_level
-    pub fn get_noisy_input(&self) -> f32 {
-        if self.noise_level == 0.0 {
-            return self.normalized_input;
-        }
-
-        let mut rng = rand::rng();
-
+    pub fn get_noisy_input(&mut self) -> f32 {
         // Standard deviation scales with noise_level
         // Max noise is ~10% of full range (3 sigma rule: 99.7% within ±3σ)
-        let sigma = self.noise_level * 0.033;
-
-        // Create normal distribution centered at 0
-        let normal = Normal::new(0.0, sigma as f64).unwrap();
-        let noise = normal.sample(&mut rng) as f32;
+        let noise = Noise::Gaussian {
+            sigma: self.noise_level * 0.033,
+        };
 
         // Apply noise and clamp to valid range
-        (self.normalized_input + noise).clamp(0.0, 1.0)
+        (self.normalized_input + noise.sample(&mut self.rng)).clamp(0.0, 1.0)
     }
 }
+
+/// Different noise on every run, like a real pot
+fn seed() -> u32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(1, |elapsed| elapsed.subsec_nanos())
+}
@@ -1,12 +1,12 @@
 use crate::color_scheme::ColorScheme;
 use crate::renderable_pot::{RenderInfo, RenderablePot, SnapZoneKind, SnapZoneRange};
 use num_traits::AsPrimitive;
-use pot_head::{HysteresisMode, PotHead, SnapZoneType};
+use pot_head::{FromNormalized, IntoNormalized, PotHead, SnapZoneType};
 use std::fmt::Display;
 
 /// Adapts a PotHead<TIn, TOut> to the RenderablePot trait
-pub struct PotAdapter<TIn, TOut> {
-    pot: PotHead<TIn, TOut>,
+pub struct PotAdapter<TIn, TOut: 'static> {
+    pot: PotHead<'static, TIn, TOut>,
     label: &'static str,
     color_scheme: ColorScheme,
     precision: usize,
@@ -17,14 +17,14 @@ pub struct PotAdapter<TIn, TOut> {
     input_max: TIn,
 }
 
-impl<TIn, TOut> PotAdapter<TIn, TOut>
+impl<TIn, TOut: 'static> PotAdapter<TIn, TOut>
 where
-    TIn: Copy + PartialOrd + AsPrimitive<f32>,
-    TOut: Copy + PartialOrd + AsPrimitive<f32> + Display,
+    TIn: Copy + PartialOrd + AsPrimitive<f32> + IntoNormalized,
+    TOut: Copy + PartialOrd + AsPrimitive<f32> + IntoNormalized + FromNormalized + Display,
     f32: AsPrimitive<TIn> + AsPrimitive<TOut>,
 {
     pub fn new(
-        pot: PotHead<TIn, TOut>,
+        pot: PotHead<'static, TIn, TOut>,
         label: &'static str,
         color_scheme: ColorScheme,
         precision: usize,
@@ -67,10 +67,10 @@ where
     }
 }
 
-impl<TIn, TOut> RenderablePot for PotAdapter<TIn, TOut>
+impl<TIn, TOut: 'static> RenderablePot for PotAdapter<TIn, TOut>
 where
-    TIn: Copy + PartialOrd + AsPrimitive<f32>,
-    TOut: Copy + PartialOrd + AsPrimitive<f32> + Display,
+    TIn: Copy + PartialOrd + AsPrimitive<f32> + IntoNormalized,
+    TOut: Copy + PartialOrd + AsPrimitive<f32> + IntoNormalized + FromNormalized + Display,
     f32: AsPrimitive<TIn> + AsPrimitive<TOut>,
 {
     fn update(&mut self, normalized_input: f32) {
@@ -80,7 +80,7 @@ where
     }
 
     fn get_render_info(&self) -> RenderInfo {
-        let config = self.pot.config();
+        let summary = self.pot.config().describe();
         let (output_min, output_max) = self.output_range();
 
         let input_min_f = self.input_min.as_();
@@ -90,14 +90,12 @@ where
         let output_f = self.last_output.as_();
 
         // Format hysteresis info
-        let hysteresis_info = match &config.hysteresis {
-            HysteresisMode::None(_) => "None".to_string(),
-            HysteresisMode::ChangeThreshold { threshold } => {
-                format!("Threshold: {:.1}%", threshold * 100.0)
-            }
-            HysteresisMode::SchmittTrigger { rising, falling } => {
+        let hysteresis_info = match (summary.change_threshold, summary.schmitt_levels) {
+            (Some(threshold), _) => format!("Threshold: {:.1}%", threshold * 100.0),
+            (_, Some((falling, rising))) => {
                 format!("Schmitt: ↑{:.0}% ↓{:.0}%", rising * 100.0, falling * 100.0)
             }
+            _ => summary.hysteresis.to_string(),
         };
 
         // Use the actual input value that was last provided
@@ -138,32 +136,37 @@ where
             self.precision
         };
 
+        // Normalized output positions count from output_min, but the bar always
+        // ascends, so flip them for descending output ranges
+        let reversed = output_min_f > output_max_f;
+        let to_bar = |position: f32| if reversed { 1.0 - position } else { position };
+
         // Calculate threshold positions for visualization
-        let threshold_positions = match &config.hysteresis {
-            HysteresisMode::None(_) => vec![],
-            HysteresisMode::ChangeThreshold { threshold } => {
+        let threshold_positions = match (summary.change_threshold, summary.schmitt_levels) {
+            (Some(threshold), _) => {
                 // Show the dead zone boundaries around the current input position
                 let lower_threshold = (input_normalized - threshold).max(0.0);
                 let upper_threshold = (input_normalized + threshold).min(1.0);
                 vec![lower_threshold, upper_threshold]
             }
-            HysteresisMode::SchmittTrigger { rising, falling } => {
-                vec![*falling, *rising]
-            }
+            (_, Some((falling, rising))) => vec![to_bar(falling), to_bar(rising)],
+            _ => vec![],
         };
 
         // Convert snap zones to render ranges
-        let snap_zones = config
-            .snap_zones
-            .iter()
-            .map(|zone| {
-                let min = (zone.target - zone.threshold).max(0.0);
-                let max = (zone.target + zone.threshold).min(1.0);
-                let kind = match zone.zone_type {
+        let snap_zones = summary
+            .zone_spans()
+            .map(|span| {
+                let kind = match span.zone_type {
                     SnapZoneType::Snap => SnapZoneKind::Snap,
                     SnapZoneType::Dead => SnapZoneKind::Dead,
                 };
-                SnapZoneRange { min, max, kind }
+                let (start, end) = (to_bar(span.min), to_bar(span.max));
+                SnapZoneRange {
+                    min: start.min(end),
+                    max: start.max(end),
+                    kind,
+                }
             })
             .collect();
 
@@ -4,7 +4,8 @@ use crate::renderable_pot::RenderablePot;
 use crossterm::style::Color;
 use num_traits::AsPrimitive;
 use pot_head::{
-    Config, GrabMode, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone, SnapZoneType,
+    Config, FromNormalized, GrabMode, HysteresisMode, IntoNormalized, NoiseFilter, PotHead,
+    ReleasePolicy, ResponseCurve, SnapZone, SnapZoneType, StageOrder,
 };
 use std::fmt::Display;
 use std::io::Result;
@@ -57,21 +58,30 @@ pub struct PotSpec<TIn, TOut> {
 
 impl<TIn, TOut> PotSpec<TIn, TOut>
 where
-    TIn: Copy + PartialOrd + AsPrimitive<f32> + 'static,
-    TOut: Copy + PartialOrd + AsPrimitive<f32> + Display + 'static,
+    TIn: Copy + PartialOrd + AsPrimitive<f32> + IntoNormalized + 'static,
+    TOut:
+        Copy + PartialOrd + AsPrimitive<f32> + IntoNormalized + FromNormalized + Display + 'static,
     f32: AsPrimitive<TIn> + AsPrimitive<TOut>,
 {
     pub fn build(&self) -> Result<Box<dyn RenderablePot>> {
         let config = Config {
             input_min: self.input_min,
             input_max: self.input_max,
+            input_center: None,
+            end_margin: 0.0,
+            invert_input: false,
             output_min: self.output_min,
             output_max: self.output_max,
             hysteresis: self.hysteresis,
             curve: self.curve,
             filter: self.filter,
             snap_zones: self.snap_zones,
+            stage_order: StageOrder::CurveFirst,
+            values: &[],
+            slew_rate: None,
             grab_mode: self.grab_mode,
+            grab_margin: 0.0,
+            release_policy: ReleasePolicy::RequireMovement,
         };
 
         let pot = PotHead::new(config).map_err(|e| {
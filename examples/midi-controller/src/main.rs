@@ -0,0 +1,250 @@
+//! Three-knob USB MIDI controller on an RP2040 (e.g. a Raspberry Pi Pico).
+//!
+//! A 1 kHz timer interrupt scans the pots on GPIO26-28 through a `PotBank` and
+//! queues every output change with `QueueSink`. The main loop services USB:
+//! it sends the queued changes as control change messages and applies CCs
+//! from the host (preset recall, automation) with `set_output()`, where grab
+//! mode keeps the knob from jumping the value until it reaches it.
+
+#![no_std]
+#![no_main]
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+use heapless::spsc::Queue;
+use panic_halt as _;
+use pot_head::{
+    Config, GrabMode, HysteresisMode, NoiseFilter, PotBank, PotHead, QueueSink, ResponseCurve,
+    SnapZone, SnapZoneType,
+};
+use rp2040_hal::{
+    self as hal, Adc, Sio, Timer, Watchdog,
+    adc::AdcPin,
+    fugit::MicrosDurationU32,
+    gpio::{DynPinId, FunctionSioInput, Pin, Pins, PullNone},
+    pac::{self, interrupt},
+    timer::{Alarm, Alarm0},
+    usb::UsbBus,
+};
+use static_cell::StaticCell;
+use usb_device::{class_prelude::UsbBusAllocator, prelude::*};
+use usbd_midi::{CableNumber, UsbMidiClass, UsbMidiEventPacket, UsbMidiPacketReader};
+
+#[unsafe(link_section = ".boot2")]
+#[used]
+pub static BOOT2: [u8; 256] = rp2040_boot2::BOOT_LOADER_GENERIC_03H;
+
+const XOSC_HZ: u32 = 12_000_000;
+
+const POTS: usize = 3;
+
+/// Volume, filter cutoff and pan
+const CONTROLLERS: [u8; POTS] = [7, 74, 10];
+
+/// Status byte of a control change on MIDI channel 1
+const CONTROL_CHANGE: u8 = 0xB0;
+
+/// ADC scan rate; the filters below are tuned for it
+const SAMPLE_PERIOD: MicrosDurationU32 = MicrosDurationU32::millis(1);
+
+/// Pan rests at 64 within 3% of the center detent
+static PAN_ZONES: [SnapZone<f32>; 1] = [SnapZone::new(0.5, 0.03, SnapZoneType::Dead)];
+
+type AdcInput = AdcPin<Pin<DynPinId, FunctionSioInput, PullNone>>;
+
+/// Everything the sampling interrupt touches.
+struct Sampler {
+    alarm: Alarm0,
+    adc: Adc,
+    pins: [AdcInput; POTS],
+    bank: PotBank<'static, POTS, u16, u8>,
+    sink: QueueSink<'static, u8>,
+}
+
+/// Owned by the interrupt; the main loop only locks it to apply host CCs.
+static SAMPLER: Mutex<RefCell<Option<Sampler>>> = Mutex::new(RefCell::new(None));
+
+/// (pot, value) changes from the interrupt to the main loop. More than one
+/// scan's worth, so a busy USB stack doesn't lose events.
+static CHANGES: StaticCell<Queue<(usize, u8), 16>> = StaticCell::new();
+
+fn pots() -> [PotHead<'static, u16, u8>; POTS] {
+    let volume = Config::<u16, u8>::builder()
+        .input_adc_bits(12)
+        .output_midi7()
+        .curve(ResponseCurve::LogarithmicLut)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.2 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .grab_mode(GrabMode::Pickup)
+        .build()
+        .unwrap();
+
+    // Sweeps are audible on a cutoff: a moving average has no exponential tail
+    let cutoff = Config::<u16, u8>::builder()
+        .input_adc_bits(12)
+        .output_midi7()
+        .filter(NoiseFilter::MovingAverage { window_size: 8 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.005 })
+        .grab_mode(GrabMode::Pickup)
+        .build()
+        .unwrap();
+
+    let pan = Config::<u16, u8>::builder()
+        .input_adc_bits(12)
+        .output_midi7()
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .snap_zones(&PAN_ZONES)
+        .grab_mode(GrabMode::PassThrough)
+        .build()
+        .unwrap();
+
+    [
+        PotHead::new(volume).unwrap(),
+        PotHead::new(cutoff).unwrap(),
+        PotHead::new(pan).unwrap(),
+    ]
+}
+
+#[hal::entry]
+fn main() -> ! {
+    let mut pac = pac::Peripherals::take().unwrap();
+    let mut watchdog = Watchdog::new(pac.WATCHDOG);
+    let clocks = hal::clocks::init_clocks_and_plls(
+        XOSC_HZ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+
+    let sio = Sio::new(pac.SIO);
+    let pins = Pins::new(
+        pac.IO_BANK0,
+        pac.PADS_BANK0,
+        sio.gpio_bank0,
+        &mut pac.RESETS,
+    );
+    let adc_pins = [
+        AdcPin::new(pins.gpio26.into_floating_input().into_dyn_pin()).unwrap(),
+        AdcPin::new(pins.gpio27.into_floating_input().into_dyn_pin()).unwrap(),
+        AdcPin::new(pins.gpio28.into_floating_input().into_dyn_pin()).unwrap(),
+    ];
+
+    let (producer, mut changes) = CHANGES.init(Queue::new()).split();
+
+    let mut timer = Timer::new(pac.TIMER, &mut pac.RESETS, &clocks);
+    let mut alarm = timer.alarm_0().unwrap();
+    alarm.schedule(SAMPLE_PERIOD).unwrap();
+    alarm.enable_interrupt();
+
+    critical_section::with(|cs| {
+        SAMPLER.borrow_ref_mut(cs).replace(Sampler {
+            alarm,
+            adc: Adc::new(pac.ADC, &mut pac.RESETS),
+            pins: adc_pins,
+            bank: PotBank::new(pots()),
+            sink: QueueSink::new(producer),
+        });
+    });
+    // SAFETY: the handler only touches `SAMPLER`, which is initialized above
+    unsafe { pac::NVIC::unmask(pac::Interrupt::TIMER_IRQ_0) };
+
+    let usb_bus = UsbBusAllocator::new(UsbBus::new(
+        pac.USBCTRL_REGS,
+        pac.USBCTRL_DPRAM,
+        clocks.usb_clock,
+        true,
+        &mut pac.RESETS,
+    ));
+    let mut midi = UsbMidiClass::new(&usb_bus, 1, 1).unwrap();
+    let mut usb_dev = UsbDeviceBuilder::new(&usb_bus, UsbVidPid(0x16c0, 0x5e4))
+        .strings(&[StringDescriptors::default()
+            .manufacturer("pot-head")
+            .product("MIDI Controller")
+            .serial_number("0001")])
+        .unwrap()
+        .build();
+
+    // Latest unsent value per pot: a knob turned faster than USB drains only
+    // sends where it ended up
+    let mut pending: [Option<u8>; POTS] = [None; POTS];
+
+    // Last value the host has per pot, so values it sent us aren't echoed back
+    let mut host: [Option<u8>; POTS] = [None; POTS];
+
+    loop {
+        if usb_dev.poll(&mut [&mut midi]) {
+            let mut buffer = [0; 64];
+            if let Ok(size) = midi.read(&mut buffer) {
+                for packet in UsbMidiPacketReader::new(&buffer, size).flatten() {
+                    if let [CONTROL_CHANGE, controller, value] = *packet.payload_bytes()
+                        && let Some(pot) = CONTROLLERS.iter().position(|&c| c == controller)
+                    {
+                        // The pot holds this value until the knob picks it up
+                        critical_section::with(|cs| {
+                            if let Some(sampler) = SAMPLER.borrow_ref_mut(cs).as_mut() {
+                                sampler.bank.get_mut(pot).unwrap().set_output(value);
+                            }
+                        });
+                        host[pot] = Some(value);
+                        pending[pot] = None;
+                    }
+                }
+            }
+        }
+
+        while let Some((pot, value)) = changes.dequeue() {
+            pending[pot] = Some(value);
+        }
+
+        for pot in 0..POTS {
+            let Some(value) = pending[pot] else {
+                continue;
+            };
+            if host[pot] == Some(value) {
+                pending[pot] = None;
+                continue;
+            }
+
+            let message = [CONTROL_CHANGE, CONTROLLERS[pot], value];
+            let packet = UsbMidiEventPacket::try_from_payload_bytes(CableNumber::Cable0, &message);
+            // Not configured yet or the endpoint is busy: retry next pass
+            if let Ok(packet) = packet
+                && midi.send_packet(packet).is_ok()
+            {
+                host[pot] = Some(value);
+                pending[pot] = None;
+            }
+        }
+    }
+}
+
+/// Scan every pot and queue the outputs that changed.
+#[interrupt]
+fn TIMER_IRQ_0() {
+    critical_section::with(|cs| {
+        let mut sampler = SAMPLER.borrow_ref_mut(cs);
+        let Some(sampler) = sampler.as_mut() else {
+            return;
+        };
+        sampler.alarm.clear_interrupt();
+        let _ = sampler.alarm.schedule(SAMPLE_PERIOD);
+
+        let mut raw = [0; POTS];
+        for (raw, pin) in raw.iter_mut().zip(&mut sampler.pins) {
+            match sampler.adc.read(pin) {
+                Ok(sample) => *raw = sample,
+                Err(_) => return,
+            }
+        }
+
+        sampler.bank.update_all(&raw);
+        sampler.sink.send_changed(&sampler.bank);
+    });
+}
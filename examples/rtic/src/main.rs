@@ -0,0 +1,90 @@
+//! RTIC v2: the ADC interrupt updates the pots, the idle task consumes the outputs.
+//!
+//! Runs on QEMU (lm3s6965). The GPIOA interrupt stands in for the ADC
+//! conversion-complete interrupt and feeds a simulated sample ramp.
+
+#![no_std]
+#![no_main]
+
+use panic_semihosting as _;
+
+#[rtic::app(device = lm3s6965)]
+mod app {
+    use cortex_m_semihosting::{debug, hprintln};
+    use lm3s6965::Interrupt;
+    use pot_head::{
+        Config, HysteresisMode, NoiseFilter, OutputSlot, PotHead, PotReader, PotSampler,
+    };
+
+    static VOLUME_SLOT: OutputSlot = OutputSlot::new();
+
+    #[shared]
+    struct Shared {
+        /// Updated in the interrupt and reconfigured from idle, so it needs a lock
+        tone: PotHead<'static, u16, u8>,
+    }
+
+    #[local]
+    struct Local {
+        /// Split pot: the interrupt owns the sampler, idle owns the reader,
+        /// and they only share the lock-free `VOLUME_SLOT`
+        volume: PotSampler<'static, 'static, u16, u8>,
+        volume_reader: PotReader<'static, u8>,
+        next_sample: u16,
+    }
+
+    #[init]
+    fn init(_: init::Context) -> (Shared, Local) {
+        let config = Config::builder()
+            .input_range(0_u16, 4095)
+            .output_range(0_u8, 127)
+            .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+            .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+            .build()
+            .unwrap();
+
+        let (volume, volume_reader) = PotHead::new(config).unwrap().split(&VOLUME_SLOT);
+        let tone = PotHead::new(config).unwrap();
+
+        (
+            Shared { tone },
+            Local {
+                volume,
+                volume_reader,
+                next_sample: 0,
+            },
+        )
+    }
+
+    /// ADC conversion complete: one sample per pot.
+    #[task(binds = GPIOA, shared = [tone], local = [volume, next_sample])]
+    fn adc(mut cx: adc::Context) {
+        let raw = *cx.local.next_sample;
+        *cx.local.next_sample = (raw + 97) % 4096;
+
+        cx.local.volume.update(raw);
+        cx.shared.tone.lock(|tone| tone.update(4095 - raw));
+    }
+
+    #[idle(shared = [tone], local = [volume_reader])]
+    fn idle(mut cx: idle::Context) -> ! {
+        for _ in 0..40 {
+            rtic::pend(Interrupt::GPIOA);
+
+            if let Some(volume) = cx.local.volume_reader.take() {
+                hprintln!("volume: {}", volume);
+            }
+        }
+
+        // Reconfiguration takes the lock, so it never races an update
+        cx.shared.tone.lock(|tone| {
+            hprintln!("tone: {}", tone.last_output());
+            tone.set_output_range(0, 63).unwrap();
+        });
+
+        debug::exit(debug::EXIT_SUCCESS);
+        loop {
+            cortex_m::asm::wfi();
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::Print,
+    terminal::{Clear, ClearType},
+};
+use pot_head::{HysteresisMode, NoiseFilter};
+use std::io::{Result, Write, stdout};
+
+use crate::tuner::Tuner;
+
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One block character per value (0.0-1.0), oldest first.
+fn sparkline(values: impl Iterator<Item = f32>) -> String {
+    values
+        .map(|value| LEVELS[(value.clamp(0.0, 1.0) * (LEVELS.len() - 1) as f32).round() as usize])
+        .collect()
+}
+
+pub fn render(tuner: &Tuner, source: &str, rate: f32, status: &str) -> Result<()> {
+    let config = tuner.pot.config();
+    let raw_span = config.input_max as f32;
+
+    let noise = match tuner.pot.noise_report() {
+        Some(report) => format!(
+            "{:.1} counts p-p, {:.2} rms over {} samples",
+            report.peak_to_peak * raw_span,
+            report.rms * raw_span,
+            report.samples
+        ),
+        None => "measuring...".into(),
+    };
+
+    let filter = match tuner.noise_filter() {
+        NoiseFilter::ExponentialMovingAverage { alpha } => {
+            format!("EMA alpha {alpha:.3}")
+        }
+        NoiseFilter::MovingAverage { window_size } => {
+            format!("MovingAverage window {window_size}")
+        }
+        NoiseFilter::None => "None".into(),
+    };
+    let hysteresis = match tuner.hysteresis_mode() {
+        HysteresisMode::ChangeThreshold { threshold } => {
+            format!(
+                "ChangeThreshold {:.4} ({:.1} counts)",
+                threshold,
+                threshold * raw_span
+            )
+        }
+        _ => "None".into(),
+    };
+
+    let lines = [
+        format!(
+            "pot-head serial tune  {}  {:.0} samples/s  {} samples, {} unparsed lines",
+            source, rate, tuner.samples, tuner.garbage
+        ),
+        String::new(),
+        format!("Filter      {filter:<40} [f] cycle  [Up/Down] adjust"),
+        format!("Hysteresis  {hysteresis:<40} [h] toggle [+/-] adjust"),
+        format!("Curve       {:<40} [c] toggle", config.curve.name()),
+        String::new(),
+        format!(
+            "Raw     {:>5}  {}",
+            tuner.last_raw.map_or("-".into(), |raw| raw.to_string()),
+            sparkline(tuner.history.iter().map(|&(raw, _)| raw))
+        ),
+        format!(
+            "Output  {:>5}  {}",
+            tuner.pot.last_output(),
+            sparkline(tuner.history.iter().map(|&(_, output)| output))
+        ),
+        String::new(),
+        format!("Input noise     {noise}"),
+        format!(
+            "Output changes  {} in the last {} samples",
+            tuner.output_changes(),
+            tuner.history.len()
+        ),
+        String::new(),
+        format!("[r] reset  [q] quit and print the config   {status}"),
+    ];
+
+    let mut out = stdout();
+    queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+    for (row, line) in lines.iter().enumerate() {
+        queue!(out, MoveTo(0, row as u16), Print(line))?;
+    }
+    out.flush()
+}
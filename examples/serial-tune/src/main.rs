@@ -0,0 +1,177 @@
+//! Live tuning against real hardware noise.
+//!
+//! A dev board streams raw ADC readings over serial, one number per line;
+//! this runs them through a `PotHead` on the host while filter and hysteresis
+//! parameters are adjusted from the keyboard. On quit it prints the tuned
+//! config as builder code for the firmware.
+
+mod display;
+mod source;
+mod tuner;
+
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use std::io::stdout;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::mpsc::TryRecvError;
+use std::time::{Duration, Instant};
+
+use source::Reading;
+use tuner::Tuner;
+
+const USAGE: &str = "\
+Usage: serial-tune <PORT|-> [options]
+
+  PORT               Serial port the board streams readings to, or - for stdin
+  --baud N           Baud rate (default 115200)
+  --input-max N      Full-scale raw reading (default 4095)
+  --output-max N     Output range 0..=N (default 1000)
+  --alpha A          Start with an EMA filter
+  --window N         Start with a moving average filter
+  --threshold T      Start with a change threshold (fraction of the range)
+  --log              Start with the logarithmic curve
+  --record FILE      Record every stage to a CSV file (pot_head::csv)";
+
+/// Redraw interval
+const FRAME: Duration = Duration::from_millis(50);
+
+pub struct Args {
+    pub port: String,
+    pub baud: u32,
+    pub input_max: u16,
+    pub output_max: u16,
+    pub alpha: Option<f32>,
+    pub window: Option<usize>,
+    pub threshold: Option<f32>,
+    pub log: bool,
+    pub record: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        port: String::new(),
+        baud: 115_200,
+        input_max: 4095,
+        output_max: 1000,
+        alpha: None,
+        window: None,
+        threshold: None,
+        log: false,
+        record: None,
+    };
+
+    let mut argv = std::env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        let mut value = |name: &str| argv.next().ok_or(format!("{name} needs a value"));
+        let number = |name: &str, value: String| {
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("{name}: '{value}' is not a number"))
+        };
+
+        match arg.as_str() {
+            "--baud" => args.baud = number("--baud", value("--baud")?)? as u32,
+            "--input-max" => args.input_max = number("--input-max", value("--input-max")?)? as u16,
+            "--output-max" => {
+                args.output_max = number("--output-max", value("--output-max")?)? as u16
+            }
+            "--alpha" => args.alpha = Some(number("--alpha", value("--alpha")?)? as f32),
+            "--window" => args.window = Some(number("--window", value("--window")?)? as usize),
+            "--threshold" => {
+                args.threshold = Some(number("--threshold", value("--threshold")?)? as f32)
+            }
+            "--log" => args.log = true,
+            "--record" => args.record = Some(value("--record")?.into()),
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ if args.port.is_empty() && (arg == "-" || !arg.starts_with('-')) => args.port = arg,
+            _ => return Err(format!("unexpected argument '{arg}'\n\n{USAGE}")),
+        }
+    }
+
+    if args.port.is_empty() {
+        return Err(USAGE.into());
+    }
+    Ok(args)
+}
+
+fn run(args: &Args) -> Result<Tuner, String> {
+    let mut tuner = Tuner::new(args)?;
+    let readings = source::open(&args.port, args.baud)
+        .map_err(|err| format!("can't open {}: {}", args.port, err))?;
+
+    let mut status = String::new();
+    let mut rate = 0.0;
+    let mut rate_start = (Instant::now(), 0);
+
+    loop {
+        // Every reading that arrived since the last frame
+        loop {
+            match readings.try_recv() {
+                Ok(Reading::Sample(raw)) => tuner.feed(raw).map_err(|err| err.to_string())?,
+                Ok(Reading::Garbage) => tuner.garbage += 1,
+                Ok(Reading::Closed(reason)) => status = format!("input closed: {reason}"),
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => break,
+            }
+        }
+
+        let elapsed = rate_start.0.elapsed();
+        if elapsed >= Duration::from_secs(1) {
+            rate = (tuner.samples - rate_start.1) as f32 / elapsed.as_secs_f32();
+            rate_start = (Instant::now(), tuner.samples);
+        }
+
+        display::render(&tuner, &args.port, rate, &status).map_err(|err| err.to_string())?;
+
+        if event::poll(FRAME).map_err(|err| err.to_string())?
+            && let Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read().map_err(|err| err.to_string())?
+        {
+            match code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(tuner),
+                code => {
+                    status = tuner.handle_key(code).err().unwrap_or_default();
+                }
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let setup = enable_raw_mode().and_then(|()| execute!(stdout(), EnterAlternateScreen, Hide));
+    let result = setup
+        .map_err(|err| err.to_string())
+        .and_then(|()| run(&args));
+    let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+    let _ = disable_raw_mode();
+
+    match result.and_then(|tuner| {
+        let code = tuner.builder_code();
+        tuner.finish().map_err(|err| err.to_string())?;
+        Ok(code)
+    }) {
+        Ok(code) => {
+            println!("{code}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Raw ADC readings from a serial port or stdin, read on a background thread.
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Read};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+pub enum Reading {
+    Sample(u16),
+
+    /// A line that isn't a number, e.g. a boot message from the board
+    Garbage,
+
+    /// The port closed or failed; no more readings follow
+    Closed(String),
+}
+
+/// Open `port` ("-" for stdin) and stream its readings.
+pub fn open(port: &str, baud: u32) -> io::Result<Receiver<Reading>> {
+    let reader: Box<dyn Read + Send> = if port == "-" {
+        Box::new(io::stdin())
+    } else {
+        Box::new(
+            serialport::new(port, baud)
+                .timeout(Duration::from_millis(500))
+                .open()?,
+        )
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        let mut line = Vec::new();
+
+        loop {
+            // A timeout leaves the partial line in `line` for the next read
+            let reading = match reader.read_until(b'\n', &mut line) {
+                Ok(0) => Reading::Closed("end of input".into()),
+                Ok(_) => {
+                    let reading = parse(&line);
+                    line.clear();
+                    reading
+                }
+                Err(err) if matches!(err.kind(), ErrorKind::TimedOut | ErrorKind::Interrupted) => {
+                    continue;
+                }
+                Err(err) => Reading::Closed(err.to_string()),
+            };
+
+            let closed = matches!(reading, Reading::Closed(_));
+            if sender.send(reading).is_err() || closed {
+                break;
+            }
+        }
+    });
+
+    Ok(receiver)
+}
+
+/// First comma-separated field as a raw reading, so both `1234` and
+/// `1234,<anything>` lines work.
+fn parse(line: &[u8]) -> Reading {
+    let line = String::from_utf8_lossy(line);
+    let field = line.split(',').next().unwrap_or("").trim();
+    match field.parse() {
+        Ok(raw) => Reading::Sample(raw),
+        Err(_) => Reading::Garbage,
+    }
+}
@@ -0,0 +1,234 @@
+//! The pot under test and the parameters being tuned from the keyboard.
+
+use crossterm::event::KeyCode;
+use pot_head::csv::Recorder;
+use pot_head::{Config, HysteresisMode, IntoNormalized, NoiseFilter, PotHead, ResponseCurve};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufWriter};
+
+use crate::Args;
+
+/// Samples kept for the sparklines and the jitter count
+pub const HISTORY: usize = 64;
+
+const ALPHA_STEP: f32 = 1.25;
+const THRESHOLD_STEP: f32 = 1.25;
+const MAX_WINDOW: usize = 32;
+
+#[derive(Clone, Copy, PartialEq)]
+enum FilterKind {
+    None,
+    Ema,
+    MovingAverage,
+}
+
+pub struct Tuner {
+    pub pot: PotHead<'static, u16, u16>,
+    recorder: Option<Recorder<BufWriter<File>>>,
+
+    // Parameters of every filter and hysteresis mode are kept while cycling
+    // through them, so switching back restores the tuned value
+    filter: FilterKind,
+    alpha: f32,
+    window: usize,
+    hysteresis: bool,
+    threshold: f32,
+
+    /// Normalized (raw, output) pairs, newest last
+    pub history: VecDeque<(f32, f32)>,
+    pub last_raw: Option<u16>,
+    pub samples: u64,
+    pub garbage: u64,
+}
+
+impl Tuner {
+    pub fn new(args: &Args) -> Result<Self, String> {
+        let filter = match (args.alpha, args.window) {
+            (Some(_), Some(_)) => return Err("--alpha and --window are exclusive".into()),
+            (Some(_), None) => FilterKind::Ema,
+            (None, Some(_)) => FilterKind::MovingAverage,
+            (None, None) => FilterKind::None,
+        };
+
+        let config = Config::builder()
+            .input_range(0, args.input_max)
+            .output_range(0, args.output_max)
+            .curve(if args.log {
+                ResponseCurve::Logarithmic
+            } else {
+                ResponseCurve::Linear
+            })
+            .build()
+            .map_err(|err| err.to_string())?;
+
+        let recorder = match &args.record {
+            Some(path) => Some(
+                Recorder::new(BufWriter::new(
+                    File::create(path).map_err(|e| e.to_string())?,
+                ))
+                .map_err(|e| e.to_string())?,
+            ),
+            None => None,
+        };
+
+        let mut tuner = Self {
+            pot: PotHead::new(config).map_err(|err| err.to_string())?,
+            recorder,
+            filter,
+            alpha: args.alpha.unwrap_or(0.2),
+            window: args.window.unwrap_or(8),
+            hysteresis: args.threshold.is_some(),
+            threshold: args.threshold.unwrap_or(0.01),
+            history: VecDeque::with_capacity(HISTORY),
+            last_raw: None,
+            samples: 0,
+            garbage: 0,
+        };
+        tuner.apply()?;
+        Ok(tuner)
+    }
+
+    /// Run one reading through the pot, recording it if asked to.
+    pub fn feed(&mut self, raw: u16) -> io::Result<()> {
+        let output = match &mut self.recorder {
+            Some(recorder) => recorder.update(&mut self.pot, raw)?,
+            None => self.pot.update(raw),
+        };
+
+        if self.history.len() == HISTORY {
+            self.history.pop_front();
+        }
+        let config = self.pot.config();
+        self.history.push_back((
+            raw.into_normalized() / config.input_max.into_normalized(),
+            output.into_normalized() / config.output_max.into_normalized(),
+        ));
+        self.last_raw = Some(raw);
+        self.samples += 1;
+        Ok(())
+    }
+
+    /// Adjust parameters for a key. Returns the error if the result was
+    /// rejected; the previous parameters stay in effect.
+    pub fn handle_key(&mut self, code: KeyCode) -> Result<(), String> {
+        match code {
+            KeyCode::Char('f') => {
+                self.filter = match self.filter {
+                    FilterKind::None => FilterKind::Ema,
+                    FilterKind::Ema => FilterKind::MovingAverage,
+                    FilterKind::MovingAverage => FilterKind::None,
+                }
+            }
+            KeyCode::Up => match self.filter {
+                FilterKind::Ema => self.alpha = (self.alpha * ALPHA_STEP).min(1.0),
+                FilterKind::MovingAverage => self.window = (self.window + 1).min(MAX_WINDOW),
+                FilterKind::None => {}
+            },
+            KeyCode::Down => match self.filter {
+                FilterKind::Ema => self.alpha = (self.alpha / ALPHA_STEP).max(0.001),
+                FilterKind::MovingAverage => self.window = (self.window - 1).max(1),
+                FilterKind::None => {}
+            },
+            KeyCode::Char('h') => self.hysteresis = !self.hysteresis,
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.threshold = (self.threshold * THRESHOLD_STEP).min(0.5)
+            }
+            KeyCode::Char('-') => self.threshold = (self.threshold / THRESHOLD_STEP).max(0.0001),
+            KeyCode::Char('c') => {
+                let curve = match self.pot.config().curve {
+                    ResponseCurve::Linear => ResponseCurve::Logarithmic,
+                    _ => ResponseCurve::Linear,
+                };
+                self.pot.set_curve(curve);
+            }
+            KeyCode::Char('r') => {
+                self.pot.reset();
+                self.history.clear();
+            }
+            _ => return Ok(()),
+        }
+        self.apply()
+    }
+
+    /// Push the tuned parameters into the pot, keeping filter and hysteresis
+    /// history when only their values changed.
+    fn apply(&mut self) -> Result<(), String> {
+        self.pot
+            .set_filter(self.noise_filter())
+            .and_then(|()| self.pot.set_hysteresis(self.hysteresis_mode()))
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn noise_filter(&self) -> NoiseFilter {
+        match self.filter {
+            FilterKind::None => NoiseFilter::None,
+            FilterKind::Ema => NoiseFilter::ExponentialMovingAverage { alpha: self.alpha },
+            FilterKind::MovingAverage => NoiseFilter::MovingAverage {
+                window_size: self.window,
+            },
+        }
+    }
+
+    pub fn hysteresis_mode(&self) -> HysteresisMode<f32> {
+        if self.hysteresis {
+            HysteresisMode::ChangeThreshold {
+                threshold: self.threshold,
+            }
+        } else {
+            HysteresisMode::none()
+        }
+    }
+
+    /// Output changes across the history. With the pot at rest, anything
+    /// above zero is noise getting through.
+    pub fn output_changes(&self) -> usize {
+        self.history
+            .iter()
+            .zip(self.history.iter().skip(1))
+            .filter(|((_, a), (_, b))| a != b)
+            .count()
+    }
+
+    /// Flush the recording, if any.
+    pub fn finish(self) -> io::Result<()> {
+        if let Some(recorder) = self.recorder {
+            recorder.into_inner()?;
+        }
+        Ok(())
+    }
+
+    /// The tuned config as builder code to paste into firmware.
+    pub fn builder_code(&self) -> String {
+        let config = self.pot.config();
+        let mut code = format!(
+            "Config::builder()\n    .input_range({}, {})\n    .output_range({}, {})\n",
+            config.input_min, config.input_max, config.output_min, config.output_max
+        );
+        if config.curve != ResponseCurve::Linear {
+            code += &format!("    .curve(ResponseCurve::{})\n", config.curve.name());
+        }
+        match self.noise_filter() {
+            NoiseFilter::None => {}
+            NoiseFilter::ExponentialMovingAverage { alpha } => {
+                code += &format!(
+                    "    .filter(NoiseFilter::ExponentialMovingAverage {{ alpha: {:.3} }})\n",
+                    alpha
+                )
+            }
+            NoiseFilter::MovingAverage { window_size } => {
+                code += &format!(
+                    "    .filter(NoiseFilter::MovingAverage {{ window_size: {} }})\n",
+                    window_size
+                )
+            }
+        }
+        if let HysteresisMode::ChangeThreshold { threshold } = self.hysteresis_mode() {
+            code += &format!(
+                "    .hysteresis(HysteresisMode::ChangeThreshold {{ threshold: {:.4} }})\n",
+                threshold
+            );
+        }
+        code + "    .build()"
+    }
+}
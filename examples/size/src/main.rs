@@ -0,0 +1,41 @@
+//! Smallest useful pot-head firmware: one linear pot through `StaticPotHead`.
+//!
+//! `scripts/size-check.sh` builds this with the minimal profile and fails if
+//! pot-head's code grows past its flash budget.
+
+#![no_std]
+#![no_main]
+
+use core::hint::black_box;
+
+use cortex_m_rt::entry;
+use panic_halt as _;
+use pot_head::StaticPotHead;
+use pot_head::static_pot::{Linear, NoFilter, NoHysteresis};
+
+type Pot = StaticPotHead<u16, u8, Linear, NoFilter, NoHysteresis>;
+
+// Out of line so `cargo bloat` attributes all of pot-head's code to these two
+
+#[inline(never)]
+fn pot_new() -> Option<Pot> {
+    // `.ok()` rather than `unwrap()`: formatting a ConfigError links core::fmt
+    Pot::new((0, 4095), (0, 127), NoFilter, NoHysteresis).ok()
+}
+
+#[inline(never)]
+fn pot_update(pot: &mut Pot, raw: u16) -> u8 {
+    pot.update(raw)
+}
+
+#[entry]
+fn main() -> ! {
+    let Some(mut pot) = pot_new() else { loop {} };
+
+    let mut raw: u16 = 0;
+    loop {
+        // Stand-ins for an ADC read and a DAC or PWM write
+        black_box(pot_update(&mut pot, black_box(raw)));
+        raw = (raw + 1) & 0x0fff;
+    }
+}
@@ -0,0 +1,208 @@
+//! Builds arbitrary configs, keeps those `validate()` accepts, and drives them
+//! with arbitrary calls, including NaN and infinite inputs. Checks that nothing
+//! panics, the output stays in range and no NaN reaches the pot's state.
+//!
+//! Run with `cargo +nightly fuzz run config` from the repository root.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use pot_head::{
+    Config, Float, GrabMode, HysteresisMode, NoiseFilter, PotHead, ReleasePolicy, ResponseCurve,
+    SnapZone, SnapZoneType, StageOrder,
+};
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    input_min: Float,
+    input_max: Float,
+    input_center: Option<Float>,
+    end_margin: Float,
+    invert_input: bool,
+    output_min: Float,
+    output_max: Float,
+    curve: u8,
+    filter: Filter,
+    hysteresis: Hysteresis,
+    zones: Vec<(Float, Float, bool)>,
+    stage_order: u8,
+    values: Vec<Float>,
+    slew_rate: Option<Float>,
+    grab_mode: u8,
+    grab_margin: Float,
+    grab_in_place: bool,
+    calls: Vec<Call>,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Filter {
+    None,
+    Ema(Float),
+    MovingAverage(u8),
+}
+
+#[derive(Debug, Arbitrary)]
+enum Hysteresis {
+    None,
+    ChangeThreshold(Float),
+    SchmittTrigger(Float, Float),
+}
+
+#[derive(Debug, Arbitrary)]
+enum Call {
+    Update(Float),
+    UpdateTimed(Float, Float),
+    SetOutput(Float),
+    Release,
+    Reset,
+}
+
+fn curve(selector: u8) -> ResponseCurve {
+    match selector % 3 {
+        0 => ResponseCurve::Linear,
+        1 => ResponseCurve::Logarithmic,
+        _ => ResponseCurve::LogarithmicLut,
+    }
+}
+
+fn stage_order(selector: u8) -> StageOrder {
+    match selector % 3 {
+        0 => StageOrder::CurveFirst,
+        1 => StageOrder::HysteresisFirst,
+        _ => StageOrder::PhysicalFirst,
+    }
+}
+
+fn grab_mode(selector: u8) -> GrabMode {
+    match selector % 3 {
+        0 => GrabMode::None,
+        1 => GrabMode::Pickup,
+        _ => GrabMode::PassThrough,
+    }
+}
+
+/// Every `Float` the pot reports about itself.
+fn assert_state_not_nan(pot: &PotHead<'_, Float, Float>) {
+    let snapshot = pot.save_state();
+    assert!(!snapshot.output.is_nan());
+    assert!(!snapshot.virtual_value.is_nan());
+    assert!(!snapshot.filtered.is_some_and(Float::is_nan));
+
+    assert!(!pot.last_output_normalized().is_nan());
+    assert!(!pot.filtered_position().is_nan());
+    assert!(!pot.physical_position().is_nan());
+    assert!(!pot.velocity().is_nan());
+
+    let taps = pot.taps();
+    for tap in [
+        taps.normalized,
+        taps.filtered,
+        taps.curved,
+        taps.hysteresis,
+        taps.snapped,
+        taps.output,
+    ] {
+        assert!(!tap.is_nan());
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let zones: Vec<SnapZone<Float>> = input
+        .zones
+        .iter()
+        .map(|&(target, threshold, dead)| {
+            let zone_type = if dead {
+                SnapZoneType::Dead
+            } else {
+                SnapZoneType::Snap
+            };
+            SnapZone::new(target, threshold, zone_type)
+        })
+        .collect();
+
+    let config = Config {
+        input_min: input.input_min,
+        input_max: input.input_max,
+        input_center: input.input_center,
+        end_margin: input.end_margin,
+        invert_input: input.invert_input,
+        output_min: input.output_min,
+        output_max: input.output_max,
+        curve: curve(input.curve),
+        filter: match input.filter {
+            Filter::None => NoiseFilter::None,
+            Filter::Ema(alpha) => NoiseFilter::ExponentialMovingAverage { alpha },
+            Filter::MovingAverage(window_size) => NoiseFilter::MovingAverage {
+                window_size: window_size as usize,
+            },
+        },
+        hysteresis: match input.hysteresis {
+            Hysteresis::None => HysteresisMode::none(),
+            Hysteresis::ChangeThreshold(threshold) => HysteresisMode::ChangeThreshold { threshold },
+            Hysteresis::SchmittTrigger(rising, falling) => {
+                HysteresisMode::SchmittTrigger { rising, falling }
+            }
+        },
+        snap_zones: &zones,
+        stage_order: stage_order(input.stage_order),
+        values: &input.values,
+        slew_rate: input.slew_rate,
+        grab_mode: grab_mode(input.grab_mode),
+        grab_margin: input.grab_margin,
+        release_policy: if input.grab_in_place {
+            ReleasePolicy::GrabInPlace
+        } else {
+            ReleasePolicy::RequireMovement
+        },
+    };
+    if config.validate().is_err() {
+        return;
+    }
+
+    let mut pot = PotHead::new(config).expect("validated configs construct");
+    let (low, high) = if input.output_min < input.output_max {
+        (input.output_min, input.output_max)
+    } else {
+        (input.output_max, input.output_min)
+    };
+
+    // Until a sample or `set_output()` gets through, the output is `output_min`
+    let mut seeded = false;
+
+    for call in input.calls {
+        let output = match call {
+            Call::Update(raw) => {
+                seeded |= !raw.is_nan();
+                pot.update(raw)
+            }
+            Call::UpdateTimed(raw, dt) => {
+                seeded |= !raw.is_nan();
+                pot.update_timed(raw, dt)
+            }
+            Call::SetOutput(value) => {
+                seeded |= !value.is_nan();
+                pot.set_output(value);
+                pot.last_output()
+            }
+            Call::Release => {
+                pot.release();
+                pot.last_output()
+            }
+            Call::Reset => {
+                seeded = false;
+                pot.reset();
+                pot.last_output()
+            }
+        };
+
+        if !seeded {
+            assert_eq!(output, input.output_min);
+        } else if input.values.is_empty() {
+            assert!(output >= low && output <= high, "{output} outside {low}..={high}");
+        } else {
+            assert!(input.values.contains(&output), "{output} not in values");
+        }
+        assert_state_not_nan(&pot);
+    }
+});
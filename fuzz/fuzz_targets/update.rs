@@ -0,0 +1,77 @@
+//! Feeds arbitrary input sequences into `update()` for a set of configs,
+//! checking that nothing panics and the output stays in range.
+//!
+//! Run with `cargo +nightly fuzz run update` from the repository root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pot_head::fixed::{LOG_LUT, ONE, ratio};
+use pot_head::{
+    Config, FixedConfig, FixedCurve, FixedPotHead, Float, GrabMode, HysteresisMode, NoiseFilter,
+    PotHead, ResponseCurve, SnapZone, SnapZoneType, StageOrder,
+};
+
+static ZONES: [SnapZone<Float>; 2] = [
+    SnapZone::new(0.0, 0.02, SnapZoneType::Snap),
+    SnapZone::new(0.5, 0.05, SnapZoneType::Dead),
+];
+
+static FIXED_ZONES: [SnapZone<i32>; 1] =
+    [SnapZone::new(ONE / 2, ratio(5, 100), SnapZoneType::Snap)];
+
+static FIXED: FixedConfig = FixedConfig {
+    input_min: 4095,
+    input_max: 0,
+    output_min: 0,
+    output_max: 127,
+    curve: FixedCurve::Lut(&LOG_LUT),
+    change_threshold: ratio(1, 100),
+    snap_zones: &FIXED_ZONES,
+};
+
+fn config(selector: u8) -> Config<'static, u16, u8> {
+    let builder = Config::builder().input_range(0, 4095).output_range(0, 127);
+
+    match selector % 4 {
+        0 => builder,
+        1 => builder
+            .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.2 })
+            .curve(ResponseCurve::Logarithmic)
+            .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+            .snap_zones(&ZONES),
+        2 => builder
+            .input_range(4095, 0)
+            .filter(NoiseFilter::MovingAverage { window_size: 8 })
+            .hysteresis(HysteresisMode::SchmittTrigger {
+                rising: 0.6,
+                falling: 0.4,
+            })
+            .stage_order(StageOrder::PhysicalFirst),
+        _ => builder
+            .grab_mode(GrabMode::Pickup)
+            .snap_zones(&ZONES)
+            .stage_order(StageOrder::HysteresisFirst),
+    }
+    .build()
+    .expect("fuzz configs are valid")
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, samples)) = data.split_first() else {
+        return;
+    };
+
+    let mut pot = PotHead::new(config(selector)).unwrap();
+    let mut fixed = FixedPotHead::new(&FIXED).unwrap();
+
+    for sample in samples.chunks_exact(2) {
+        let raw = u16::from_le_bytes([sample[0], sample[1]]);
+
+        let output = pot.update(raw);
+        assert!(output <= 127);
+
+        let output = fixed.update(raw as i32);
+        assert!((0..=127).contains(&output));
+    }
+});
@@ -0,0 +1,30 @@
+#!/bin/bash
+
+# size-check.sh - Check pot-head's flash footprint in the minimal profile
+#
+# Builds examples/size (one linear StaticPotHead) for thumbv7em-none-eabihf
+# and fails if pot-head's code exceeds BUDGET bytes.
+# Needs: rustup target add thumbv7em-none-eabihf; cargo install cargo-bloat
+
+set -e
+
+BUDGET=1024
+
+cd "$(dirname "$0")/../examples/size"
+
+echo "Building examples/size..."
+json=$(cargo bloat --release -n 0 --message-format json)
+
+# pot-head's code, inlined into the example's pot_* wrappers or left in its own symbols
+size=$(echo "$json" | python3 -c '
+import json, sys
+functions = json.load(sys.stdin)["functions"]
+print(sum(f["size"] for f in functions
+          if f.get("crate") == "pot_head" or f["name"].split("::")[-1].startswith("pot_")))
+')
+
+echo "pot-head code: $size bytes (budget $BUDGET)"
+if [ "$size" -gt "$BUDGET" ]; then
+    echo "Size check failed"
+    exit 1
+fi
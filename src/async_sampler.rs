@@ -0,0 +1,82 @@
+//! Async sampling for executors like embassy.
+//!
+//! `AsyncPotSampler` owns a `PotHead`, an async read function (e.g. an ADC
+//! conversion) and an async tick (e.g. a periodic ticker). Each sample awaits
+//! the tick, then the read, and feeds the pipeline. `next_change()` resolves
+//! only when the processed output changes, so a task can simply loop on it.
+//!
+//! Both functions are async closures, so they can borrow the ADC and ticker:
+//!
+//! ```ignore
+//! let mut ticker = Ticker::every(Duration::from_millis(1));
+//! let mut sampler = AsyncPotSampler::new(
+//!     pot,
+//!     async || adc.read(&mut pin).await,
+//!     async || ticker.next().await,
+//! );
+//!
+//! loop {
+//!     let value = sampler.next_change().await;
+//!     send_cc(value).await;
+//! }
+//! ```
+
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::pothead::PotHead;
+
+/// Feeds a `PotHead` from an async read at the rate of an async tick.
+pub struct AsyncPotSampler<'a, R, T, TIn, TOut = TIn> {
+    pot: PotHead<'a, TIn, TOut>,
+    read: R,
+    tick: T,
+}
+
+impl<'a, R, T, TIn, TOut> AsyncPotSampler<'a, R, T, TIn, TOut>
+where
+    R: AsyncFnMut() -> TIn,
+    T: AsyncFnMut(),
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    pub fn new(pot: PotHead<'a, TIn, TOut>, read: R, tick: T) -> Self {
+        Self { pot, read, tick }
+    }
+
+    /// Wait for the next tick, read one sample and return the output.
+    pub async fn sample(&mut self) -> TOut {
+        (self.tick)().await;
+        let input = (self.read)().await;
+        self.pot.update(input)
+    }
+
+    /// Sample until the output changes, and return the new output.
+    ///
+    /// Compares `TOut` values, so with integer outputs a sample that moves the
+    /// pipeline but rounds to the same value doesn't count as a change.
+    pub async fn next_change(&mut self) -> TOut {
+        let previous = self.pot.last_output();
+        loop {
+            let output = self.sample().await;
+            if output != previous {
+                return output;
+            }
+        }
+    }
+
+    pub fn last_output(&self) -> TOut {
+        self.pot.last_output()
+    }
+
+    pub fn pot(&self) -> &PotHead<'a, TIn, TOut> {
+        &self.pot
+    }
+
+    pub fn pot_mut(&mut self) -> &mut PotHead<'a, TIn, TOut> {
+        &mut self.pot
+    }
+
+    /// Give back the pot, dropping the read and tick functions.
+    pub fn into_inner(self) -> PotHead<'a, TIn, TOut> {
+        self.pot
+    }
+}
@@ -0,0 +1,164 @@
+//! Fixed-size group of pots updated together.
+//!
+//! `PotBank` owns N `PotHead`s, each with its own configuration, and updates
+//! them from one array of samples (e.g. one ADC scan). Everything lives inline,
+//! so a bank can sit in a `static` without allocation.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::pothead::PotHead;
+#[cfg(feature = "simd")]
+use crate::scaling::{InputScaling, LANES};
+
+/// N pots with shared iteration and bulk queries. N is limited to 64 so
+/// changes fit in a `u64` mask.
+#[derive(Clone)]
+pub struct PotBank<'a, const N: usize, TIn, TOut = TIn> {
+    pots: [PotHead<'a, TIn, TOut>; N],
+    changed: u64,
+}
+
+impl<'a, const N: usize, TIn, TOut> PotBank<'a, N, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Create a bank from individually configured pots.
+    pub fn new(pots: [PotHead<'a, TIn, TOut>; N]) -> Self {
+        const { assert!(N <= 64, "PotBank holds at most 64 pots") };
+
+        Self { pots, changed: 0 }
+    }
+
+    /// Create a bank of N pots sharing one configuration.
+    pub fn from_config(config: Config<'a, TIn, TOut>) -> Result<Self, ConfigError> {
+        let pot = PotHead::new(config)?;
+        Ok(Self::new(core::array::from_fn(|_| pot.clone())))
+    }
+
+    /// Process one sample per pot and return all outputs.
+    ///
+    /// With the `simd` feature, input normalization runs on 8 channels at a
+    /// time; outputs are identical to updating each pot on its own.
+    pub fn update_all(&mut self, inputs: &[TIn; N]) -> [TOut; N] {
+        self.changed = 0;
+
+        #[cfg(feature = "simd")]
+        let done = self.update_lanes(inputs);
+
+        #[cfg(not(feature = "simd"))]
+        let done = 0;
+
+        for (index, (pot, &input)) in self.pots.iter_mut().zip(inputs).enumerate().skip(done) {
+            let before = pot.last_output_normalized();
+            pot.update(input);
+            if pot.last_output_normalized() != before {
+                self.changed |= 1 << index;
+            }
+        }
+
+        self.outputs()
+    }
+
+    /// Update the channels in whole groups of `LANES`, normalizing each group
+    /// together. Returns how many channels were updated; the rest don't fill
+    /// a group.
+    #[cfg(feature = "simd")]
+    fn update_lanes(&mut self, inputs: &[TIn; N]) -> usize {
+        let groups = self
+            .pots
+            .chunks_exact_mut(LANES)
+            .zip(inputs.chunks_exact(LANES));
+
+        for (group, (pots, inputs)) in groups.enumerate() {
+            // NaN and faulted inputs end their update here; their lane still
+            // computes, and the result is dropped
+            let callbacks: [_; LANES] = core::array::from_fn(|lane| pots[lane].callbacks());
+            let mut prepared = [None; LANES];
+            for (lane, pot) in pots.iter_mut().enumerate() {
+                prepared[lane] = pot.prepare_input(inputs[lane]);
+            }
+
+            let scalings = core::array::from_fn(|lane| *pots[lane].input_scaling());
+            let inputs_f = prepared.map(|input_f| input_f.unwrap_or(0.0));
+            let normalized = InputScaling::normalize_lanes(&scalings, &inputs_f);
+
+            for (lane, pot) in pots.iter_mut().enumerate() {
+                let (output, changed) = match prepared[lane] {
+                    Some(input_f) => pot.process_normalized(input_f, normalized[lane]),
+                    None => (pot.last_output(), false),
+                };
+                if changed {
+                    self.changed |= 1 << (group * LANES + lane);
+                }
+                pot.notify(callbacks[lane], output, changed);
+            }
+        }
+
+        N - N % LANES
+    }
+
+    /// Process a sample for a single channel. Returns `None` if `channel` is out of range.
+    ///
+    /// Does not touch the changed mask, which only tracks `update_all()`.
+    pub fn update(&mut self, channel: usize, input: TIn) -> Option<TOut> {
+        self.pots.get_mut(channel).map(|pot| pot.update(input))
+    }
+
+    /// Current output of every pot, without processing new samples.
+    pub fn outputs(&self) -> [TOut; N] {
+        self.pots.each_ref().map(|pot| pot.last_output())
+    }
+
+    /// Bit `i` is set if pot `i`'s output changed during the last `update_all()`.
+    pub fn changed_mask(&self) -> u64 {
+        self.changed
+    }
+
+    /// Channel and output of each pot that changed during the last `update_all()`.
+    pub fn changed(&self) -> impl Iterator<Item = (usize, TOut)> + '_ {
+        self.pots
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.changed & (1 << index) != 0)
+            .map(|(index, pot)| (index, pot.last_output()))
+    }
+
+    /// Reset every pot to the condition right after `new()`.
+    pub fn reset_all(&mut self) {
+        for pot in &mut self.pots {
+            pot.reset();
+        }
+        self.changed = 0;
+    }
+
+    pub fn get(&self, channel: usize) -> Option<&PotHead<'a, TIn, TOut>> {
+        self.pots.get(channel)
+    }
+
+    pub fn get_mut(&mut self, channel: usize) -> Option<&mut PotHead<'a, TIn, TOut>> {
+        self.pots.get_mut(channel)
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, PotHead<'a, TIn, TOut>> {
+        self.pots.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, PotHead<'a, TIn, TOut>> {
+        self.pots.iter_mut()
+    }
+
+    /// Number of pots in the bank.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Give back the pots.
+    pub fn into_inner(self) -> [PotHead<'a, TIn, TOut>; N] {
+        self.pots
+    }
+}
@@ -0,0 +1,242 @@
+//! Input calibration.
+//!
+//! `CalibrationData` holds what belongs to one physical pot rather than to the
+//! product design: the raw readings at its ends and detent, the dead travel at
+//! its stops, and whether it is wired backwards. Keeping it apart from `Config`
+//! lets a static ROM config be shared by every unit while each unit stores only
+//! its own calibration.
+//!
+//! With the `calibration` feature, `PotHead::begin_calibration()` runs the
+//! factory flow "turn the knob fully left, press OK; fully right, press OK; to
+//! the detent, press OK": each `capture_*()` takes the latest raw sample and
+//! `finish_calibration()` applies the result to normalization.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::snapshot::{SnapshotError, crc8, from_wire, to_wire};
+
+/// Per-unit input calibration, applicable to any `Config` with the same input
+/// type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationData<TIn> {
+    /// Raw readings at the ends of travel
+    pub input_min: TIn,
+    pub input_max: TIn,
+
+    /// Raw reading at the mechanical center, for bipolar controls
+    pub input_center: Option<TIn>,
+
+    /// Fraction of travel ignored at each end, see `Config::end_margin`
+    pub end_margin: Float,
+
+    /// Pot wired backwards
+    pub invert_input: bool,
+}
+
+/// Encoded length, see `CalibrationData::SIZE`
+const SIZE: usize = 19;
+
+const HAS_CENTER: u8 = 1 << 0;
+const INVERT: u8 = 1 << 1;
+
+impl<TIn: Copy> CalibrationData<TIn> {
+    /// The calibration currently in `config`.
+    pub fn from_config<TOut>(config: &Config<'_, TIn, TOut>) -> Self {
+        Self {
+            input_min: config.input_min,
+            input_max: config.input_max,
+            input_center: config.input_center,
+            end_margin: config.end_margin,
+            invert_input: config.invert_input,
+        }
+    }
+
+    /// Overwrite `config`'s input range, center, end margin and inversion.
+    ///
+    /// Doesn't validate; see `validate()` or `PotHead::apply_calibration()`.
+    pub fn apply<TOut>(&self, config: &mut Config<'_, TIn, TOut>) {
+        config.input_min = self.input_min;
+        config.input_max = self.input_max;
+        config.input_center = self.input_center;
+        config.end_margin = self.end_margin;
+        config.invert_input = self.invert_input;
+    }
+}
+
+impl<TIn: Copy + PartialOrd + IntoNormalized> CalibrationData<TIn> {
+    /// Check the input range isn't degenerate, the center lies strictly
+    /// inside it and the end margin leaves some travel. `Config::validate()`
+    /// applies the same rules.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        validate_input_range(
+            self.input_min.into_normalized(),
+            self.input_max.into_normalized(),
+            self.input_center.map(IntoNormalized::into_normalized),
+        )?;
+
+        if !(self.end_margin >= 0.0 && self.end_margin < 0.5) {
+            return Err(ConfigError::InvalidEndMargin {
+                margin: self.end_margin,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// `input_center` as `Float` if it doesn't lie strictly inside the input
+    /// range, or too close to an end to scale that side.
+    pub(crate) fn invalid_center(&self) -> Option<Float> {
+        let center = self.input_center?.into_normalized();
+        let fits = center_fits(
+            self.input_min.into_normalized(),
+            self.input_max.into_normalized(),
+            center,
+        );
+        (!fits).then_some(center)
+    }
+}
+
+/// Input range checks on the normalized ends, shared by
+/// `CalibrationData::validate()` and `assert_valid!`.
+pub(crate) const fn validate_input_range(
+    min: Float,
+    max: Float,
+    center: Option<Float>,
+) -> Result<(), ConfigError> {
+    // Descending ranges are allowed; NaN or infinite ends, or a span too
+    // wide or narrow for `Float`, would normalize to infinity or NaN
+    if !is_finite_span(min, max) {
+        return Err(ConfigError::InvalidInputRange { min, max });
+    }
+
+    if let Some(center) = center
+        && !center_fits(min, max, center)
+    {
+        return Err(ConfigError::InvalidInputCenter { center });
+    }
+
+    Ok(())
+}
+
+/// `center` lies strictly inside `min..max` and far enough from both ends
+/// to scale each side.
+const fn center_fits(min: Float, max: Float, center: Float) -> bool {
+    let inside = (center > min && center < max) || (center < min && center > max);
+    inside && is_finite_span(min, center) && is_finite_span(center, max)
+}
+
+/// `max - min` and its reciprocal are finite, so normalizing by the span can't
+/// overflow: neither end is NaN or infinite, the ends differ, and the span isn't
+/// too wide or too narrow (subnormal) for `Float`.
+pub(crate) const fn is_finite_span(min: Float, max: Float) -> bool {
+    let span = max - min;
+    span.is_finite() && (1.0 / span).is_finite()
+}
+
+/// Compact binary encoding for EEPROM and flash.
+///
+/// Layout (little-endian): version, flags, input min/max/center and end margin
+/// as `f32`, then a CRC-8 over the preceding bytes, like
+/// `StateSnapshot::to_bytes()`.
+impl<TIn: IntoNormalized + FromNormalized> CalibrationData<TIn> {
+    /// Encoded length in bytes.
+    pub const SIZE: usize = SIZE;
+
+    /// Format version written to the first byte.
+    pub const VERSION: u8 = 2;
+
+    pub fn to_bytes(&self) -> [u8; SIZE] {
+        let mut bytes = [0; SIZE];
+        bytes[0] = Self::VERSION;
+        bytes[1] = self.flags();
+        self.write_inputs(&mut bytes[2..18]);
+        bytes[18] = crc8(&bytes[..18]);
+        bytes
+    }
+
+    /// Decode a record from `to_bytes()`. Extra trailing bytes are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let Some(bytes) = bytes.get(..SIZE) else {
+            return Err(SnapshotError::TooShort);
+        };
+        if bytes[0] != Self::VERSION {
+            return Err(SnapshotError::UnsupportedVersion(bytes[0]));
+        }
+        if crc8(&bytes[..18]) != bytes[18] {
+            return Err(SnapshotError::Corrupted);
+        }
+
+        Ok(Self::read_inputs(&bytes[2..18], bytes[1]))
+    }
+
+    /// Write min, max, center and end margin as `f32` into 16 bytes.
+    pub(crate) fn write_inputs(&self, bytes: &mut [u8]) {
+        let input = |value: Option<TIn>| {
+            value
+                .map_or(0.0, |value| to_wire(value.into_normalized()))
+                .to_le_bytes()
+        };
+        bytes[0..4].copy_from_slice(&input(Some(self.input_min)));
+        bytes[4..8].copy_from_slice(&input(Some(self.input_max)));
+        bytes[8..12].copy_from_slice(&input(self.input_center));
+        bytes[12..16].copy_from_slice(&to_wire(self.end_margin).to_le_bytes());
+    }
+
+    /// Read the inputs written by `write_inputs()`, with `flags` from `to_bytes()`.
+    pub(crate) fn read_inputs(bytes: &[u8], flags: u8) -> Self {
+        let wire = |at: usize| {
+            let mut raw = [0; 4];
+            raw.copy_from_slice(&bytes[at..at + 4]);
+            from_wire(f32::from_le_bytes(raw))
+        };
+        let input = |at: usize| TIn::from_normalized(wire(at));
+
+        Self {
+            input_min: input(0),
+            input_max: input(4),
+            input_center: (flags & HAS_CENTER != 0).then(|| input(8)),
+            end_margin: wire(12),
+            invert_input: flags & INVERT != 0,
+        }
+    }
+
+    /// Flag bits for `read_inputs()`, shared with `StateSnapshot::to_bytes()`
+    /// (bits 0 and 1).
+    pub(crate) fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.input_center.is_some() {
+            flags |= HAS_CENTER;
+        }
+        if self.invert_input {
+            flags |= INVERT;
+        }
+        flags
+    }
+}
+
+/// Points captured so far in a guided calibration session.
+#[cfg(feature = "calibration")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Calibration<TIn> {
+    /// Latest raw sample passed to `update()`
+    pub last: Option<TIn>,
+
+    pub min: Option<TIn>,
+    pub max: Option<TIn>,
+    pub center: Option<TIn>,
+}
+
+#[cfg(feature = "calibration")]
+impl<TIn> Calibration<TIn> {
+    pub const fn new() -> Self {
+        Self {
+            last: None,
+            min: None,
+            max: None,
+            center: None,
+        }
+    }
+}
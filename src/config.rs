@@ -1,75 +1,662 @@
-use num_traits::AsPrimitive;
+use num_traits::PrimInt;
 
+use crate::calibration::{CalibrationData, is_finite_span, validate_input_range};
+use crate::convert::{FromNormalized, IntoNormalized};
 use crate::curves::ResponseCurve;
 use crate::filters::NoiseFilter;
+use crate::float::Float;
 use crate::hysteresis::HysteresisMode;
+use crate::pipeline::StageOrder;
 use crate::snap_zones::SnapZone;
+use crate::state::State;
+use crate::summary::ConfigSummary;
 
 #[cfg(feature = "grab-mode")]
-use crate::grab_mode::GrabMode;
+use crate::grab_mode::{GrabMode, ReleasePolicy};
 
+#[cfg(feature = "fine-adjust")]
+use crate::fine_adjust::FineAdjust;
+
+/// Configuration error. Variants carry the offending values where available.
+///
+/// Range values are reported as `Float` (via `IntoNormalized`).
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ConfigError {
-    InvalidInputRange,
-    InvalidOutputRange,
-    InvalidHysteresis,
-    InvalidFilter,
-    OverlappingSnapZones,
+    InvalidInputRange { min: Float, max: Float },
+    InvalidInputCenter { center: Float },
+    InvalidEndMargin { margin: Float },
+    InvalidOutputRange { min: Float, max: Float },
+    MissingInputRange,
+    MissingOutputRange,
+    InvalidAdcBits { bits: u32 },
+    InvalidHysteresis { reason: &'static str },
+    InvalidFilter { reason: &'static str },
+    InvalidCurve { reason: &'static str },
+    InvalidCrossfadeLaw { reason: &'static str },
+    OverlappingSnapZones { first: usize, second: usize },
+    InvalidGrabMargin { margin: Float },
+    InvalidSnapZone { index: usize, threshold: Float },
+    UnsortedValues { index: usize },
+    InvalidSlewRate { rate: Float },
+    InvalidAcceleration { reason: &'static str },
+    InvalidTolerance { tolerance: Float },
+    InvalidDeadZone { size: Float },
+    InvalidServo { reason: &'static str },
+    InvalidFaultDetection { reason: &'static str },
+    InvalidPressAction { reason: &'static str },
+    InvalidPedal { reason: &'static str },
+    InvalidMidi { reason: &'static str },
+    InvalidFineAdjust { reason: &'static str },
+    UnsupportedSetting { reason: &'static str },
+    InvalidSnapshot,
+}
+
+impl ConfigError {
+    /// Human-readable error message without values. Usable in const contexts.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            ConfigError::InvalidInputRange { .. } => "input_min must not equal input_max",
+            ConfigError::InvalidInputCenter { .. } => {
+                "input_center must lie strictly between input_min and input_max"
+            }
+            ConfigError::InvalidEndMargin { .. } => "end_margin must be in range [0.0, 0.5)",
+            ConfigError::InvalidOutputRange { .. } => "output_min must not equal output_max",
+            ConfigError::MissingInputRange => "input range not set",
+            ConfigError::MissingOutputRange => "output range not set",
+            ConfigError::InvalidAdcBits { .. } => {
+                "ADC bits must be at least 1 and fit the input type"
+            }
+            ConfigError::InvalidHysteresis { reason } => reason,
+            ConfigError::InvalidFilter { reason } => reason,
+            ConfigError::InvalidCurve { reason } => reason,
+            ConfigError::InvalidCrossfadeLaw { reason } => reason,
+            ConfigError::OverlappingSnapZones { .. } => "snap zones must not overlap",
+            ConfigError::InvalidGrabMargin { .. } => "grab_margin must be in range [0.0, 1.0)",
+            ConfigError::InvalidSnapZone { .. } => {
+                "snap zone target must be in [0.0, 1.0] and threshold >= 0.0"
+            }
+            ConfigError::UnsortedValues { .. } => "values must be sorted in ascending order",
+            ConfigError::InvalidSlewRate { .. } => "slew_rate must be > 0.0",
+            ConfigError::InvalidAcceleration { reason } => reason,
+            ConfigError::InvalidTolerance { .. } => "tolerance must be in range [0.0, 1.0]",
+            ConfigError::InvalidDeadZone { .. } => "dead zone size must be in range [0.0, 1.0)",
+            ConfigError::InvalidServo { reason } => reason,
+            ConfigError::InvalidFaultDetection { reason } => reason,
+            ConfigError::InvalidPressAction { reason } => reason,
+            ConfigError::InvalidPedal { reason } => reason,
+            ConfigError::InvalidMidi { reason } => reason,
+            ConfigError::InvalidFineAdjust { reason } => reason,
+            ConfigError::UnsupportedSetting { reason } => reason,
+            ConfigError::InvalidSnapshot => "snapshot values must not be NaN",
+        }
+    }
 }
 
 impl core::fmt::Display for ConfigError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = self.message();
         match self {
-            ConfigError::InvalidInputRange => write!(f, "input_min must be less than input_max"),
-            ConfigError::InvalidOutputRange => write!(f, "output_min must not equal output_max"),
-            ConfigError::InvalidHysteresis => write!(f, "invalid hysteresis configuration"),
-            ConfigError::InvalidFilter => write!(f, "invalid filter configuration"),
-            ConfigError::OverlappingSnapZones => write!(f, "snap zones must not overlap"),
+            ConfigError::InvalidInputRange { min, max }
+            | ConfigError::InvalidOutputRange { min, max } => {
+                write!(f, "{} (min: {}, max: {})", message, min, max)
+            }
+            ConfigError::InvalidHysteresis { .. } => {
+                write!(f, "invalid hysteresis configuration: {}", message)
+            }
+            ConfigError::InvalidFilter { .. } => {
+                write!(f, "invalid filter configuration: {}", message)
+            }
+            ConfigError::InvalidCurve { .. } => {
+                write!(f, "invalid curve configuration: {}", message)
+            }
+            ConfigError::InvalidCrossfadeLaw { .. } => {
+                write!(f, "invalid crossfade law: {}", message)
+            }
+            ConfigError::InvalidServo { .. } => {
+                write!(f, "invalid servo configuration: {}", message)
+            }
+            ConfigError::InvalidFaultDetection { .. } => {
+                write!(f, "invalid fault detection: {}", message)
+            }
+            ConfigError::InvalidMidi { .. } => {
+                write!(f, "invalid MIDI configuration: {}", message)
+            }
+            ConfigError::InvalidPedal { .. } => {
+                write!(f, "invalid pedal profile: {}", message)
+            }
+            ConfigError::InvalidPressAction { .. } => {
+                write!(f, "invalid press action: {}", message)
+            }
+            ConfigError::InvalidAcceleration { .. } => {
+                write!(f, "invalid acceleration configuration: {}", message)
+            }
+            ConfigError::InvalidFineAdjust { .. } => {
+                write!(f, "invalid fine adjust configuration: {}", message)
+            }
+            ConfigError::UnsupportedSetting { .. } => {
+                write!(f, "unsupported setting: {}", message)
+            }
+            ConfigError::OverlappingSnapZones { first, second } => {
+                write!(f, "{} (zones {} and {})", message, first, second)
+            }
+            ConfigError::InvalidInputCenter { center } => {
+                write!(f, "{} (got {})", message, center)
+            }
+            ConfigError::InvalidEndMargin { margin } => {
+                write!(f, "{} (got {})", message, margin)
+            }
+            ConfigError::InvalidSlewRate { rate } => {
+                write!(f, "{} (got {})", message, rate)
+            }
+            ConfigError::InvalidTolerance { tolerance } => {
+                write!(f, "{} (got {})", message, tolerance)
+            }
+            ConfigError::InvalidDeadZone { size } => {
+                write!(f, "{} (got {})", message, size)
+            }
+            ConfigError::InvalidAdcBits { bits } => {
+                write!(f, "{} (got {})", message, bits)
+            }
+            ConfigError::InvalidGrabMargin { margin } => {
+                write!(f, "{} (got {})", message, margin)
+            }
+            ConfigError::InvalidSnapZone { index, threshold } => {
+                write!(f, "{} (zone {}: {})", message, index, threshold)
+            }
+            ConfigError::UnsortedValues { index } => {
+                write!(f, "{} (index {})", message, index)
+            }
+            ConfigError::MissingInputRange
+            | ConfigError::MissingOutputRange
+            | ConfigError::InvalidSnapshot => f.write_str(message),
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {}
+
+/// Set of failed validation rules, as returned by `Config::validate_all()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ValidationErrors(u16);
+
+impl ValidationErrors {
+    pub const INPUT_RANGE: Self = Self(1 << 0);
+    pub const OUTPUT_RANGE: Self = Self(1 << 1);
+    pub const HYSTERESIS: Self = Self(1 << 2);
+    pub const FILTER: Self = Self(1 << 3);
+    pub const SNAP_ZONE: Self = Self(1 << 4);
+    pub const GRAB_MARGIN: Self = Self(1 << 5);
+    pub const VALUES: Self = Self(1 << 6);
+    pub const SLEW_RATE: Self = Self(1 << 7);
+    pub const INPUT_CENTER: Self = Self(1 << 8);
+    pub const END_MARGIN: Self = Self(1 << 9);
+    pub const FINE_ADJUST: Self = Self(1 << 10);
+
+    /// Each rule with a representative error, whose `message()` describes it.
+    /// Rules with a per-stage reason use the generic one for their stage.
+    const ALL: [(Self, ConfigError); 11] = [
+        (
+            Self::INPUT_RANGE,
+            ConfigError::InvalidInputRange { min: 0.0, max: 0.0 },
+        ),
+        (
+            Self::OUTPUT_RANGE,
+            ConfigError::InvalidOutputRange { min: 0.0, max: 0.0 },
+        ),
+        (
+            Self::HYSTERESIS,
+            ConfigError::InvalidHysteresis {
+                reason: "invalid hysteresis configuration",
+            },
+        ),
+        (
+            Self::FILTER,
+            ConfigError::InvalidFilter {
+                reason: "invalid filter configuration",
+            },
+        ),
+        (
+            Self::SNAP_ZONE,
+            ConfigError::InvalidSnapZone {
+                index: 0,
+                threshold: 0.0,
+            },
+        ),
+        (
+            Self::GRAB_MARGIN,
+            ConfigError::InvalidGrabMargin { margin: 0.0 },
+        ),
+        (Self::VALUES, ConfigError::UnsortedValues { index: 0 }),
+        (Self::SLEW_RATE, ConfigError::InvalidSlewRate { rate: 0.0 }),
+        (
+            Self::INPUT_CENTER,
+            ConfigError::InvalidInputCenter { center: 0.0 },
+        ),
+        (
+            Self::END_MARGIN,
+            ConfigError::InvalidEndMargin { margin: 0.0 },
+        ),
+        (
+            Self::FINE_ADJUST,
+            ConfigError::InvalidFineAdjust {
+                reason: "invalid fine adjust configuration",
+            },
+        ),
+    ];
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// True if no rule failed.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Number of failed rules.
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Messages for all failed rules.
+    pub fn messages(&self) -> impl Iterator<Item = &'static str> + '_ {
+        Self::ALL
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, error)| error.message())
+    }
+}
+
+impl core::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, message) in self.messages().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            f.write_str(message)?;
         }
+        Ok(())
     }
 }
 
-pub struct Config<TIn, TOut = TIn> {
+/// Range checks on the normalized range ends, shared by `Config::validate()`
+/// and `assert_valid!`.
+///
+/// Both ranges must have a finite span with a finite reciprocal (so the ends
+/// are finite and differ), and the input center must lie strictly inside the
+/// input range, far enough from both ends to scale each side.
+#[doc(hidden)]
+pub const fn validate_normalized_ranges(
+    input_min: Float,
+    input_max: Float,
+    input_center: Option<Float>,
+    output_min: Float,
+    output_max: Float,
+) -> Result<(), ConfigError> {
+    if let Err(error) = validate_input_range(input_min, input_max, input_center) {
+        return Err(error);
+    }
+
+    // Like the input range, the output range is divided by when normalizing
+    if !is_finite_span(output_min, output_max) {
+        return Err(ConfigError::InvalidOutputRange {
+            min: output_min,
+            max: output_max,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate a `Config` at compile time.
+///
+/// Expands to a `()` expression that fails the build with the `ConfigError`
+/// message if the configuration is invalid. Range checks require primitive
+/// numeric input/output types.
+///
+/// Usage: `const _: () = pot_head::assert_valid!(CONFIG);`
+#[macro_export]
+macro_rules! assert_valid {
+    ($config:expr) => {{
+        let config = &$config;
+        let input_center = match config.input_center {
+            Some(center) => Some(center as $crate::Float),
+            None => None,
+        };
+        if let Err(e) = $crate::validate_normalized_ranges(
+            config.input_min as $crate::Float,
+            config.input_max as $crate::Float,
+            input_center,
+            config.output_min as $crate::Float,
+            config.output_max as $crate::Float,
+        ) {
+            panic!("{}", e.message());
+        }
+        let mut index = 1;
+        while index < config.values.len() {
+            if !(config.values[index - 1] < config.values[index]) {
+                panic!("values must be sorted in ascending order");
+            }
+            index += 1;
+        }
+        if let Err(e) = config.validate_processing() {
+            panic!("{}", e.message());
+        }
+    }};
+}
+
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Config<'a, TIn, TOut = TIn> {
     pub input_min: TIn,
     pub input_max: TIn,
+
+    /// Raw reading of the mechanical center, for bipolar controls (pan, balance,
+    /// joystick axes) whose detent isn't halfway along the electrical range.
+    /// Normalization maps it to exactly 0.5, scaling each side separately.
+    /// `None` normalizes linearly.
+    pub input_center: Option<TIn>,
+
+    /// Fraction of travel ignored at each end, for pots whose output doesn't
+    /// change over the first and last few degrees (mechanical slop at the
+    /// stops). Normalization stretches the remaining travel to 0.0..1.0, so
+    /// the output still reaches both extremes before the stops. `0.0` disables it.
+    pub end_margin: Float,
+
+    /// Invert the input direction (for pots wired backwards).
+    /// Applied right after normalization, so curves and snap zones see the corrected direction.
+    pub invert_input: bool,
+
     pub output_min: TOut,
     pub output_max: TOut,
-    pub hysteresis: HysteresisMode<f32>,
+    pub hysteresis: HysteresisMode<Float>,
     pub curve: ResponseCurve,
     pub filter: NoiseFilter,
-    pub snap_zones: &'static [SnapZone<f32>],
+    pub snap_zones: &'a [SnapZone<Float>],
+
+    /// Order of the curve, hysteresis and snap zone stages.
+    pub stage_order: StageOrder,
+
+    /// Discrete output values, sorted ascending. When not empty, the output is the
+    /// entry nearest to the processed value, with boundary hysteresis.
+    pub values: &'a [TOut],
+
+    /// Maximum output change per update, in output units. The output ramps towards
+    /// the processed value after snap zones and grab mode. `None` disables it.
+    pub slew_rate: Option<Float>,
 
     #[cfg(feature = "grab-mode")]
     pub grab_mode: GrabMode,
+
+    /// Distance (normalized) the pot must travel past the virtual value before grabbing.
+    /// Prevents jitter around the virtual value from causing accidental grabs.
+    #[cfg(feature = "grab-mode")]
+    pub grab_margin: Float,
+
+    /// How the pot re-grabs after `release()`.
+    #[cfg(feature = "grab-mode")]
+    pub release_policy: ReleasePolicy,
+
+    /// Scale slow knob movement down for fine adjustment, after the curve and
+    /// before snap zones. `None` disables it.
+    #[cfg(feature = "fine-adjust")]
+    pub fine_adjust: Option<FineAdjust>,
 }
 
-impl<TIn, TOut> Config<TIn, TOut>
+impl<'a, TIn, TOut> Config<'a, TIn, TOut> {
+    /// Input range, center, end margin and inversion, e.g. to persist
+    /// separately from the rest of the configuration.
+    pub fn calibration(&self) -> CalibrationData<TIn>
+    where
+        TIn: Copy,
+    {
+        CalibrationData::from_config(self)
+    }
+
+    /// Start building a configuration with default processing settings.
+    pub const fn builder() -> ConfigBuilder<'a, TIn, TOut> {
+        ConfigBuilder::new()
+    }
+
+    /// RAM used by a `PotHead`'s processing state (filter buffers, hysteresis,
+    /// zone and grab state), in bytes.
+    ///
+    /// State is stored inline at a fixed size, so this depends only on the enabled
+    /// features and `Float`, not on which filter or zones are configured. See
+    /// `PotHead::SIZE_BYTES` for the total including the configuration.
+    pub const fn state_size_bytes() -> usize {
+        size_of::<State<Float>>()
+    }
+
+    /// Validate everything except the input/output ranges.
+    ///
+    /// These settings all operate on normalized `Float` values, so this check is
+    /// `const` regardless of `TIn`/`TOut`. See `assert_valid!` for compile-time use.
+    pub const fn validate_processing(&self) -> Result<(), ConfigError> {
+        if let Err(reason) = self.hysteresis.validate_normalized() {
+            return Err(ConfigError::InvalidHysteresis { reason });
+        }
+
+        if let Err(reason) = self.filter.validate() {
+            return Err(ConfigError::InvalidFilter { reason });
+        }
+
+        let mut index = 0;
+        while index < self.snap_zones.len() {
+            let zone = &self.snap_zones[index];
+            if zone.validate().is_err() {
+                return Err(ConfigError::InvalidSnapZone {
+                    index,
+                    threshold: zone.threshold,
+                });
+            }
+            index += 1;
+        }
+
+        if let Some(rate) = self.slew_rate
+            && (rate.is_nan() || rate <= 0.0)
+        {
+            return Err(ConfigError::InvalidSlewRate { rate });
+        }
+
+        // Both margins together must leave some travel
+        if !(self.end_margin >= 0.0 && self.end_margin < 0.5) {
+            return Err(ConfigError::InvalidEndMargin {
+                margin: self.end_margin,
+            });
+        }
+
+        // Grab margin must be non-negative and smaller than the full range
+        #[cfg(feature = "grab-mode")]
+        if !(self.grab_margin >= 0.0 && self.grab_margin < 1.0) {
+            return Err(ConfigError::InvalidGrabMargin {
+                margin: self.grab_margin,
+            });
+        }
+
+        #[cfg(feature = "fine-adjust")]
+        if let Some(fine) = &self.fine_adjust
+            && let Err(reason) = fine.validate()
+        {
+            return Err(ConfigError::InvalidFineAdjust { reason });
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints the config summary (see `ConfigSummary`).
+impl<TIn, TOut> core::fmt::Display for Config<'_, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.describe(), f)
+    }
+}
+
+impl<'a, TIn, TOut> Config<'a, TIn, TOut>
 where
-    TIn: Copy + PartialOrd + AsPrimitive<f32>,
-    TOut: Copy + PartialOrd + AsPrimitive<f32>,
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized,
 {
+    /// Summarize the configuration for UIs and debug logs.
+    pub fn describe(&self) -> ConfigSummary<'a> {
+        let (change_threshold, schmitt_levels) = match self.hysteresis {
+            HysteresisMode::None(_) => (None, None),
+            HysteresisMode::ChangeThreshold { threshold } => (Some(threshold), None),
+            HysteresisMode::SchmittTrigger { rising, falling } => (None, Some((falling, rising))),
+        };
+
+        ConfigSummary {
+            input_range: (
+                self.input_min.into_normalized(),
+                self.input_max.into_normalized(),
+            ),
+            output_range: (
+                self.output_min.into_normalized(),
+                self.output_max.into_normalized(),
+            ),
+            invert_input: self.invert_input,
+            curve: self.curve.name(),
+            filter: self.filter.name(),
+            hysteresis: self.hysteresis.name(),
+            change_threshold,
+            schmitt_levels,
+            stage_order: self.stage_order,
+            #[cfg(feature = "grab-mode")]
+            grab_mode: self.grab_mode.name(),
+            #[cfg(feature = "grab-mode")]
+            grab_margin: self.grab_margin,
+            snap_zones: self.snap_zones,
+        }
+    }
+
     pub fn validate(&self) -> Result<(), ConfigError> {
-        // Input range must be valid (min < max)
-        if self.input_min >= self.input_max {
-            return Err(ConfigError::InvalidInputRange);
+        self.validate_ranges()?;
+
+        if let Some(index) = self.unsorted_value() {
+            return Err(ConfigError::UnsortedValues { index });
+        }
+
+        // Validate normalized processing settings
+        self.validate_processing()?;
+
+        Ok(())
+    }
+
+    /// Validate only the input and output ranges (and the input center).
+    ///
+    /// For pipelines that take no other settings from the config, so they
+    /// don't link the checks for stages they don't have.
+    pub(crate) fn validate_ranges(&self) -> Result<(), ConfigError> {
+        let calibration = self.calibration();
+        validate_normalized_ranges(
+            calibration.input_min.into_normalized(),
+            calibration.input_max.into_normalized(),
+            calibration
+                .input_center
+                .map(IntoNormalized::into_normalized),
+            self.output_min.into_normalized(),
+            self.output_max.into_normalized(),
+        )
+    }
+
+    /// Check every validation rule and report all failures at once.
+    ///
+    /// Checks the same rules as `validate()`, which stops at the first error.
+    pub fn validate_all(&self) -> ValidationErrors {
+        let mut errors = ValidationErrors::empty();
+
+        if !is_finite_span(
+            self.input_min.into_normalized(),
+            self.input_max.into_normalized(),
+        ) {
+            errors.insert(ValidationErrors::INPUT_RANGE);
         }
 
-        // Output range must not be degenerate (min == max would cause division issues)
-        if self.output_min == self.output_max {
-            return Err(ConfigError::InvalidOutputRange);
+        if self.calibration().invalid_center().is_some() {
+            errors.insert(ValidationErrors::INPUT_CENTER);
         }
 
-        // Validate hysteresis configuration
-        self.hysteresis
-            .validate()
-            .map_err(|_| ConfigError::InvalidHysteresis)?;
+        if !is_finite_span(
+            self.output_min.into_normalized(),
+            self.output_max.into_normalized(),
+        ) {
+            errors.insert(ValidationErrors::OUTPUT_RANGE);
+        }
 
-        // Validate filter configuration
-        self.filter
-            .validate()
-            .map_err(|_| ConfigError::InvalidFilter)?;
+        if self.hysteresis.validate_normalized().is_err() {
+            errors.insert(ValidationErrors::HYSTERESIS);
+        }
 
-        Ok(())
+        if self.filter.validate().is_err() {
+            errors.insert(ValidationErrors::FILTER);
+        }
+
+        if self.snap_zones.iter().any(|zone| zone.validate().is_err()) {
+            errors.insert(ValidationErrors::SNAP_ZONE);
+        }
+
+        #[cfg(feature = "grab-mode")]
+        if !(self.grab_margin >= 0.0 && self.grab_margin < 1.0) {
+            errors.insert(ValidationErrors::GRAB_MARGIN);
+        }
+
+        if self.unsorted_value().is_some() {
+            errors.insert(ValidationErrors::VALUES);
+        }
+
+        if let Some(rate) = self.slew_rate
+            && (rate.is_nan() || rate <= 0.0)
+        {
+            errors.insert(ValidationErrors::SLEW_RATE);
+        }
+
+        if !(self.end_margin >= 0.0 && self.end_margin < 0.5) {
+            errors.insert(ValidationErrors::END_MARGIN);
+        }
+
+        #[cfg(feature = "fine-adjust")]
+        if self
+            .fine_adjust
+            .is_some_and(|fine| fine.validate().is_err())
+        {
+            errors.insert(ValidationErrors::FINE_ADJUST);
+        }
+
+        errors
+    }
+
+    /// Index of the first value not greater than its predecessor.
+    fn unsorted_value(&self) -> Option<usize> {
+        // A lone NaN has no neighbour to compare unordered with
+        if self
+            .values
+            .first()
+            .is_some_and(|v| v.partial_cmp(v).is_none())
+        {
+            return Some(0);
+        }
+        self.values
+            .windows(2)
+            .position(|pair| pair[0].partial_cmp(&pair[1]) != Some(core::cmp::Ordering::Less))
+            .map(|i| i + 1)
     }
 
     /// Validate that no snap zones overlap.
@@ -77,12 +664,348 @@ where
     /// Call this during development if you want to ensure clean, non-overlapping zones.
     pub fn validate_snap_zones(&self) -> Result<(), ConfigError> {
         for (i, zone1) in self.snap_zones.iter().enumerate() {
-            for zone2 in self.snap_zones.iter().skip(i + 1) {
+            for (j, zone2) in self.snap_zones.iter().enumerate().skip(i + 1) {
                 if zone1.overlaps(zone2) {
-                    return Err(ConfigError::OverlappingSnapZones);
+                    return Err(ConfigError::OverlappingSnapZones {
+                        first: i,
+                        second: j,
+                    });
                 }
             }
         }
         Ok(())
     }
 }
+
+macro_rules! impl_default_config {
+    ($tin:ty, $input_max:expr, $desc:literal) => {
+        impl Config<'_, $tin, f32> {
+            #[doc = concat!("Default configuration: ", $desc, " → 0.0..1.0, no processing.")]
+            ///
+            #[doc = concat!("Usable in statics: `Config { input_max: ..., ..Config::<", stringify!($tin), ", f32>::DEFAULT }`.")]
+            pub const DEFAULT: Self = Self {
+                input_min: 0 as $tin,
+                input_max: $input_max,
+                input_center: None,
+                end_margin: 0.0,
+                invert_input: false,
+                output_min: 0.0,
+                output_max: 1.0,
+                hysteresis: HysteresisMode::none(),
+                curve: ResponseCurve::Linear,
+                filter: NoiseFilter::None,
+                snap_zones: &[],
+                stage_order: StageOrder::CurveFirst,
+                values: &[],
+                slew_rate: None,
+                #[cfg(feature = "grab-mode")]
+                grab_mode: GrabMode::None,
+                #[cfg(feature = "grab-mode")]
+                grab_margin: 0.0,
+                #[cfg(feature = "grab-mode")]
+                release_policy: ReleasePolicy::RequireMovement,
+                #[cfg(feature = "fine-adjust")]
+                fine_adjust: None,
+            };
+        }
+
+        impl Default for Config<'_, $tin, f32> {
+            fn default() -> Self {
+                Self::DEFAULT
+            }
+        }
+    };
+}
+
+impl_default_config!(u8, 255, "8-bit ADC (0..255)");
+impl_default_config!(u16, 4095, "12-bit ADC (0..4095)");
+impl_default_config!(f32, 1.0, "normalized input (0.0..1.0)");
+
+/// Fluent builder for `Config`.
+///
+/// Input and output ranges are required. Everything else defaults to
+/// no processing: linear curve, no filter, no hysteresis, no snap zones
+/// and no grab mode.
+pub struct ConfigBuilder<'a, TIn, TOut = TIn> {
+    input_range: Option<(TIn, TIn)>,
+
+    /// Bit width passed to `input_adc_bits()` if it didn't fit the input type,
+    /// reported by `build()`
+    invalid_adc_bits: Option<u32>,
+
+    input_center: Option<TIn>,
+    end_margin: Float,
+    invert_input: bool,
+    output_range: Option<(TOut, TOut)>,
+    hysteresis: HysteresisMode<Float>,
+    curve: ResponseCurve,
+    filter: NoiseFilter,
+    snap_zones: &'a [SnapZone<Float>],
+    stage_order: StageOrder,
+    values: &'a [TOut],
+    slew_rate: Option<Float>,
+
+    #[cfg(feature = "grab-mode")]
+    grab_mode: GrabMode,
+
+    #[cfg(feature = "grab-mode")]
+    grab_margin: Float,
+
+    #[cfg(feature = "grab-mode")]
+    release_policy: ReleasePolicy,
+
+    #[cfg(feature = "fine-adjust")]
+    fine_adjust: Option<FineAdjust>,
+}
+
+impl<'a, TIn: PrimInt, TOut> Config<'a, TIn, TOut> {
+    /// Start building a configuration for a `bits`-wide ADC.
+    ///
+    /// Shorthand for `Config::builder().input_adc_bits(bits)`.
+    pub fn from_adc_bits(bits: u32) -> ConfigBuilder<'a, TIn, TOut> {
+        ConfigBuilder::new().input_adc_bits(bits)
+    }
+}
+
+impl<'a, TIn, TOut> ConfigBuilder<'a, TIn, TOut> {
+    /// Create a builder with default processing settings and no ranges set.
+    pub const fn new() -> Self {
+        Self {
+            input_range: None,
+            invalid_adc_bits: None,
+            input_center: None,
+            end_margin: 0.0,
+            invert_input: false,
+            output_range: None,
+            hysteresis: HysteresisMode::none(),
+            curve: ResponseCurve::Linear,
+            filter: NoiseFilter::None,
+            snap_zones: &[],
+            stage_order: StageOrder::CurveFirst,
+            values: &[],
+            slew_rate: None,
+            #[cfg(feature = "grab-mode")]
+            grab_mode: GrabMode::None,
+            #[cfg(feature = "grab-mode")]
+            grab_margin: 0.0,
+            #[cfg(feature = "grab-mode")]
+            release_policy: ReleasePolicy::RequireMovement,
+            #[cfg(feature = "fine-adjust")]
+            fine_adjust: None,
+        }
+    }
+
+    pub fn input_range(mut self, min: TIn, max: TIn) -> Self {
+        self.input_range = Some((min, max));
+        self.invalid_adc_bits = None;
+        self
+    }
+
+    /// Map `center` to 0.5, see `Config::input_center`.
+    pub fn input_center(mut self, center: TIn) -> Self {
+        self.input_center = Some(center);
+        self
+    }
+
+    /// Ignore `margin` of the travel at each end, see `Config::end_margin`.
+    pub fn end_margin(mut self, margin: Float) -> Self {
+        self.end_margin = margin;
+        self
+    }
+
+    pub fn invert_input(mut self, invert_input: bool) -> Self {
+        self.invert_input = invert_input;
+        self
+    }
+
+    /// Set the input range, center, end margin and inversion from a stored
+    /// calibration.
+    pub fn calibration(mut self, calibration: CalibrationData<TIn>) -> Self {
+        self.input_range = Some((calibration.input_min, calibration.input_max));
+        self.invalid_adc_bits = None;
+        self.input_center = calibration.input_center;
+        self.end_margin = calibration.end_margin;
+        self.invert_input = calibration.invert_input;
+        self
+    }
+
+    pub fn output_range(mut self, min: TOut, max: TOut) -> Self {
+        self.output_range = Some((min, max));
+        self
+    }
+
+    pub fn hysteresis(mut self, hysteresis: HysteresisMode<Float>) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    pub fn curve(mut self, curve: ResponseCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    pub fn filter(mut self, filter: NoiseFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn snap_zones(mut self, snap_zones: &'a [SnapZone<Float>]) -> Self {
+        self.snap_zones = snap_zones;
+        self
+    }
+
+    pub fn stage_order(mut self, stage_order: StageOrder) -> Self {
+        self.stage_order = stage_order;
+        self
+    }
+
+    /// Output only these values (sorted ascending), e.g. BPM presets or channel numbers.
+    pub fn values(mut self, values: &'a [TOut]) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Ramp the output by at most `rate` output units per update.
+    pub fn slew_rate(mut self, rate: Float) -> Self {
+        self.slew_rate = Some(rate);
+        self
+    }
+
+    /// Requires `grab-mode` feature.
+    #[cfg(feature = "grab-mode")]
+    pub fn grab_mode(mut self, grab_mode: GrabMode) -> Self {
+        self.grab_mode = grab_mode;
+        self
+    }
+
+    /// Requires `grab-mode` feature.
+    #[cfg(feature = "grab-mode")]
+    pub fn grab_margin(mut self, grab_margin: Float) -> Self {
+        self.grab_margin = grab_margin;
+        self
+    }
+
+    /// Requires `grab-mode` feature.
+    #[cfg(feature = "grab-mode")]
+    pub fn release_policy(mut self, release_policy: ReleasePolicy) -> Self {
+        self.release_policy = release_policy;
+        self
+    }
+
+    /// Requires `fine-adjust` feature.
+    #[cfg(feature = "fine-adjust")]
+    pub fn fine_adjust(mut self, fine_adjust: FineAdjust) -> Self {
+        self.fine_adjust = Some(fine_adjust);
+        self
+    }
+}
+
+impl<'a, TIn: PrimInt, TOut> ConfigBuilder<'a, TIn, TOut> {
+    /// Set the input range to the full span of a `bits`-wide ADC.
+    ///
+    /// Unsigned types get `0..=(1 << bits) - 1`, signed types get
+    /// `-(1 << (bits - 1))..=(1 << (bits - 1)) - 1`.
+    ///
+    /// If `bits` is zero or wider than `TIn`, `build()` fails with
+    /// `InvalidAdcBits`.
+    pub fn input_adc_bits(mut self, bits: u32) -> Self {
+        let width = TIn::zero().count_zeros();
+        if bits == 0 || bits > width {
+            self.input_range = None;
+            self.invalid_adc_bits = Some(bits);
+            return self;
+        }
+
+        let shift = (width - bits) as usize;
+        self.input_range(TIn::min_value() >> shift, TIn::max_value() >> shift)
+    }
+}
+
+impl<'a, TIn, TOut: FromNormalized> ConfigBuilder<'a, TIn, TOut> {
+    /// Output `0.0..=100.0` with a linear curve.
+    pub fn output_percent(self) -> Self {
+        self.output_float_range(0.0, 100.0, ResponseCurve::Linear)
+    }
+
+    /// Output a gain in dB, e.g. `output_db(-60.0, 0.0)`.
+    ///
+    /// Uses a linear curve: the dB scale is already logarithmic, so a
+    /// logarithmic curve on top would compress the useful range.
+    pub fn output_db(self, min_db: Float, max_db: Float) -> Self {
+        self.output_float_range(min_db, max_db, ResponseCurve::Linear)
+    }
+
+    /// Output a 7-bit MIDI value `0..=127` with a linear curve.
+    pub fn output_midi7(self) -> Self {
+        self.output_float_range(0.0, 127.0, ResponseCurve::Linear)
+    }
+
+    /// Output a 14-bit MIDI value `0..=16383` with a linear curve, for `Cc14`
+    /// and `Nrpn`.
+    pub fn output_midi14(self) -> Self {
+        self.output_float_range(0.0, 16383.0, ResponseCurve::Linear)
+    }
+
+    fn output_float_range(self, min: Float, max: Float, curve: ResponseCurve) -> Self {
+        self.output_range(TOut::from_normalized(min), TOut::from_normalized(max))
+            .curve(curve)
+    }
+}
+
+impl<'a, TIn, TOut> ConfigBuilder<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized,
+{
+    /// Build and validate the configuration.
+    ///
+    /// Returns `MissingInputRange`/`MissingOutputRange` if a range was not set,
+    /// `InvalidAdcBits` if `input_adc_bits()` got a width that doesn't fit.
+    pub fn build(self) -> Result<Config<'a, TIn, TOut>, ConfigError> {
+        let config = self.assemble()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// The config as set, checking only that both ranges are present.
+    pub(crate) fn assemble(self) -> Result<Config<'a, TIn, TOut>, ConfigError> {
+        if let Some(bits) = self.invalid_adc_bits {
+            return Err(ConfigError::InvalidAdcBits { bits });
+        }
+        let (input_min, input_max) = self.input_range.ok_or(ConfigError::MissingInputRange)?;
+        let (output_min, output_max) = self.output_range.ok_or(ConfigError::MissingOutputRange)?;
+
+        let config = Config {
+            input_min,
+            input_max,
+            input_center: self.input_center,
+            end_margin: self.end_margin,
+            invert_input: self.invert_input,
+            output_min,
+            output_max,
+            hysteresis: self.hysteresis,
+            curve: self.curve,
+            filter: self.filter,
+            snap_zones: self.snap_zones,
+            stage_order: self.stage_order,
+            values: self.values,
+            slew_rate: self.slew_rate,
+            #[cfg(feature = "grab-mode")]
+            grab_mode: self.grab_mode,
+            #[cfg(feature = "grab-mode")]
+            grab_margin: self.grab_margin,
+            #[cfg(feature = "grab-mode")]
+            release_policy: self.release_policy,
+            #[cfg(feature = "fine-adjust")]
+            fine_adjust: self.fine_adjust,
+        };
+
+        Ok(config)
+    }
+}
+
+impl<TIn, TOut> Default for ConfigBuilder<'_, TIn, TOut> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,137 @@
+//! Conversion between user value types and the internal `Float` pipeline.
+//!
+//! Implemented for all primitive numeric types, and with the `fixed` feature
+//! for the `fixed` crate's types (e.g. `I16F16`, `U0F16`). Implement these for
+//! domain newtypes (e.g. `Decibels(f32)`, `Midi7(u8)`) to use them as `TIn`/`TOut`.
+
+use crate::float::Float;
+
+/// Convert a value into the `Float` domain used for normalization.
+pub trait IntoNormalized: Copy {
+    fn into_normalized(self) -> Float;
+}
+
+/// Convert a `Float` pipeline value back into the output type.
+///
+/// Receives the denormalized value (already mapped to the output range).
+pub trait FromNormalized: Copy {
+    fn from_normalized(value: Float) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl IntoNormalized for $t {
+            #[inline]
+            fn into_normalized(self) -> Float {
+                self as Float
+            }
+        }
+
+        /// Rounded to nearest (half up), so the output is monotonic and reaches
+        /// both ends of the range despite float error.
+        impl FromNormalized for $t {
+            #[inline]
+            fn from_normalized(value: Float) -> Self {
+                round_half_up(value) as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {$(
+        impl IntoNormalized for $t {
+            #[inline]
+            fn into_normalized(self) -> Float {
+                self as Float
+            }
+        }
+
+        impl FromNormalized for $t {
+            #[inline]
+            fn from_normalized(value: Float) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+impl_float!(f32, f64);
+
+/// Fixed-point types convert directly without an intermediate integer. Outputs
+/// round to nearest and saturate at the type's range; NaN becomes zero.
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed {
+    ($($t:ident),*) => {$(
+        impl<Frac> IntoNormalized for ::fixed::$t<Frac>
+        where
+            Self: ::fixed::traits::Fixed,
+        {
+            #[inline]
+            fn into_normalized(self) -> Float {
+                ::fixed::traits::Fixed::to_num(self)
+            }
+        }
+
+        impl<Frac> FromNormalized for ::fixed::$t<Frac>
+        where
+            Self: ::fixed::traits::Fixed,
+        {
+            #[inline]
+            fn from_normalized(value: Float) -> Self {
+                if value.is_nan() {
+                    return <Self as ::fixed::traits::Fixed>::ZERO;
+                }
+                ::fixed::traits::Fixed::saturating_from_num(value)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed!(
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
+    FixedU128
+);
+
+/// Round to the nearest integer, with halves rounded up. Needs no libm.
+#[inline]
+fn round_half_up(value: Float) -> Float {
+    // Above 2^52 every float is already an integer (and may not fit in i64)
+    const INTEGRAL: Float = 4_503_599_627_370_496.0;
+
+    if value.is_nan() || value.abs() >= INTEGRAL {
+        return value;
+    }
+
+    let shifted = value + 0.5;
+    let truncated = shifted as i64 as Float;
+    if truncated > shifted {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_round_half_up() {
+        assert_eq!(u8::from_normalized(127.49), 127);
+        assert_eq!(u8::from_normalized(127.5), 128);
+        assert_eq!(u8::from_normalized(254.99), 255);
+        assert_eq!(i16::from_normalized(-2.5), -2);
+        assert_eq!(i16::from_normalized(-2.51), -3);
+    }
+
+    #[test]
+    fn floats_pass_through() {
+        assert_eq!(f32::from_normalized(0.5), 0.5);
+        assert_eq!(f64::from_normalized(-2.25), -2.25);
+    }
+}
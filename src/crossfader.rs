@@ -0,0 +1,145 @@
+//! DJ-style crossfader with complementary gains.
+//!
+//! `Crossfader` wraps a `PotHead` and turns its normalized output (the fader
+//! position) into a pair of gains for channels A and B using a crossfade law.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// How the gains of A and B change across the fader travel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CrossfadeLaw {
+    /// Gains sum to 1.0 (-6 dB dip in the center)
+    Linear,
+
+    /// Powers sum to 1.0, so loudness stays constant across the travel
+    ///
+    /// Requires `std-math` feature.
+    #[cfg(feature = "std-math")]
+    EqualPower,
+
+    /// Both channels stay at full gain up to the center, then the far one fades out
+    ConstantGain,
+
+    /// Each channel fades fully within `width` of its end, for scratching
+    SharpCut { width: Float },
+}
+
+impl CrossfadeLaw {
+    /// Gains (A, B) at a fader position (0.0 = all A, 1.0 = all B).
+    pub fn gains(&self, position: Float) -> (Float, Float) {
+        let x = position.clamp(0.0, 1.0);
+
+        match *self {
+            CrossfadeLaw::Linear => (1.0 - x, x),
+
+            #[cfg(feature = "std-math")]
+            CrossfadeLaw::EqualPower => {
+                let angle = x * core::f64::consts::FRAC_PI_2 as Float;
+                // cos(π/2) rounds to a tiny negative value
+                (cos(angle).max(0.0), sin(angle))
+            }
+
+            CrossfadeLaw::ConstantGain => ((2.0 * (1.0 - x)).min(1.0), (2.0 * x).min(1.0)),
+
+            CrossfadeLaw::SharpCut { width } => {
+                (((1.0 - x) / width).min(1.0), (x / width).min(1.0))
+            }
+        }
+    }
+
+    pub const fn validate(&self) -> Result<(), &'static str> {
+        if let CrossfadeLaw::SharpCut { width } = self
+            && (width.is_nan() || *width <= 0.0 || *width > 0.5)
+        {
+            return Err("SharpCut width must be in range (0.0, 0.5]");
+        }
+        Ok(())
+    }
+}
+
+/// Crossfader producing complementary gains from one fader.
+#[derive(Clone)]
+pub struct Crossfader<'a, TIn, TOut = TIn> {
+    pot: PotHead<'a, TIn, TOut>,
+    law: CrossfadeLaw,
+}
+
+impl<'a, TIn, TOut> Crossfader<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// The fader position is the pot's normalized output, so the config's filter,
+    /// curve, hysteresis and snap zones (e.g. a center detent) all apply.
+    pub fn new(config: Config<'a, TIn, TOut>, law: CrossfadeLaw) -> Result<Self, ConfigError> {
+        if let Err(reason) = law.validate() {
+            return Err(ConfigError::InvalidCrossfadeLaw { reason });
+        }
+
+        Ok(Self {
+            pot: PotHead::new(config)?,
+            law,
+        })
+    }
+
+    /// Process one fader reading and return the gains (A, B), each 0.0..=1.0.
+    pub fn update(&mut self, input: TIn) -> (Float, Float) {
+        self.pot.update(input);
+        self.gains()
+    }
+
+    /// Gains (A, B) at the current position, without processing a new sample.
+    pub fn gains(&self) -> (Float, Float) {
+        self.law.gains(self.position())
+    }
+
+    /// Fader position, 0.0 (all A) to 1.0 (all B).
+    pub fn position(&self) -> Float {
+        self.pot.last_output_normalized()
+    }
+
+    pub fn law(&self) -> CrossfadeLaw {
+        self.law
+    }
+
+    /// Change the law with validation, e.g. from a curve switch on the mixer.
+    pub fn set_law(&mut self, law: CrossfadeLaw) -> Result<(), ConfigError> {
+        if let Err(reason) = law.validate() {
+            return Err(ConfigError::InvalidCrossfadeLaw { reason });
+        }
+        self.law = law;
+        Ok(())
+    }
+
+    pub fn pot(&self) -> &PotHead<'a, TIn, TOut> {
+        &self.pot
+    }
+
+    pub fn pot_mut(&mut self) -> &mut PotHead<'a, TIn, TOut> {
+        &mut self.pot
+    }
+}
+
+#[cfg(all(feature = "std-math", not(feature = "f64")))]
+fn sin(x: Float) -> Float {
+    libm::sinf(x)
+}
+
+#[cfg(all(feature = "std-math", feature = "f64"))]
+fn sin(x: Float) -> Float {
+    libm::sin(x)
+}
+
+#[cfg(all(feature = "std-math", not(feature = "f64")))]
+fn cos(x: Float) -> Float {
+    libm::cosf(x)
+}
+
+#[cfg(all(feature = "std-math", feature = "f64"))]
+fn cos(x: Float) -> Float {
+    libm::cos(x)
+}
@@ -0,0 +1,166 @@
+//! CSV recording and playback for offline tuning.
+//!
+//! `Recorder` wraps `update()` and writes the raw input and the value after
+//! every pipeline stage as one CSV row per sample. `read_samples()` loads the
+//! raw column of a capture back, and `replay()` runs it through any `Config`,
+//! so filters can be tuned against a real ADC trace on the desk with results
+//! that repeat exactly from run to run.
+
+use std::io::{self, BufRead, Write};
+use std::vec::Vec;
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// Columns written by `Recorder`. Stage values are normalized (0.0-1.0);
+/// `raw` and `output` are in input and output units.
+pub const HEADER: &str =
+    "sample,raw,normalized,filtered,curved,hysteresis,snapped,output_normalized,output";
+
+/// Why a recording couldn't be read, written or replayed.
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+
+    /// A line (1-based) whose raw value isn't a number.
+    Parse {
+        line: usize,
+    },
+
+    Config(ConfigError),
+}
+
+impl core::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "CSV I/O failed: {}", err),
+            CsvError::Parse { line } => write!(f, "line {}: raw value is not a number", line),
+            CsvError::Config(err) => write!(f, "invalid config: {}", err),
+        }
+    }
+}
+
+impl core::error::Error for CsvError {}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+impl From<ConfigError> for CsvError {
+    fn from(err: ConfigError) -> Self {
+        CsvError::Config(err)
+    }
+}
+
+/// Writes one CSV row per `update()`.
+pub struct Recorder<W: Write> {
+    writer: W,
+    sample: u64,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Start a recording, writing the header.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writeln!(writer, "{}", HEADER)?;
+        Ok(Self { writer, sample: 0 })
+    }
+
+    /// `pot.update(raw)`, recording the input and every stage.
+    ///
+    /// A NaN input is recorded as is; the stages repeat the last update, since
+    /// `update()` ignores it.
+    pub fn update<TIn, TOut>(
+        &mut self,
+        pot: &mut PotHead<'_, TIn, TOut>,
+        raw: TIn,
+    ) -> io::Result<TOut>
+    where
+        TIn: Copy + PartialOrd + IntoNormalized,
+        TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+    {
+        let output = pot.update(raw);
+        let taps = pot.taps();
+
+        writeln!(
+            self.writer,
+            "{},{},{},{},{},{},{},{},{}",
+            self.sample,
+            raw.into_normalized(),
+            taps.normalized,
+            taps.filtered,
+            taps.curved,
+            taps.hysteresis,
+            taps.snapped,
+            taps.output,
+            output.into_normalized(),
+        )?;
+        self.sample += 1;
+
+        Ok(output)
+    }
+
+    /// Samples recorded so far.
+    pub fn samples(&self) -> u64 {
+        self.sample
+    }
+
+    /// Finish the recording and return the writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Raw inputs from a CSV: the `raw` column if the first line is a header
+/// naming one, otherwise the first column. Blank lines are skipped.
+///
+/// Reads `Recorder` output as well as plain one-value-per-line ADC dumps.
+pub fn read_samples<TIn: FromNormalized>(reader: impl BufRead) -> Result<Vec<TIn>, CsvError> {
+    let mut samples = Vec::new();
+    let mut column = 0;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut fields = line.split(',').map(str::trim);
+        let field = fields.clone().nth(column).unwrap_or("");
+        match field.parse::<Float>() {
+            Ok(value) => samples.push(TIn::from_normalized(value)),
+            Err(_) if index == 0 => {
+                column = fields.position(|name| name == "raw").unwrap_or(0);
+            }
+            Err(_) => return Err(CsvError::Parse { line: index + 1 }),
+        }
+    }
+
+    Ok(samples)
+}
+
+/// Run `samples` through a fresh pot built from `config`, recording every
+/// stage to `writer`. Returns the writer.
+pub fn replay<TIn, TOut, W: Write>(
+    config: Config<'_, TIn, TOut>,
+    samples: &[TIn],
+    writer: W,
+) -> Result<W, CsvError>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    let mut pot = PotHead::new(config)?;
+    let mut recorder = Recorder::new(writer)?;
+
+    for &sample in samples {
+        recorder.update(&mut pot, sample)?;
+    }
+
+    Ok(recorder.into_inner()?)
+}
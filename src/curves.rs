@@ -2,8 +2,11 @@
 //!
 //! Transforms normalized input (0.0..1.0) through different response curves.
 
+use crate::float::Float;
+
 /// Response curve types for potentiometer output.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ResponseCurve {
     /// Linear response (1:1 mapping).
     Linear,
@@ -13,23 +16,68 @@ pub enum ResponseCurve {
     /// Requires `std-math` feature and `libm` dependency.
     #[cfg(feature = "std-math")]
     Logarithmic,
+
+    /// Logarithmic response from a compile-time lookup table, within 1e-4 of
+    /// `Logarithmic` without an `expf` call per sample.
+    ///
+    /// Requires `log-lut` feature (no `libm` needed).
+    #[cfg(feature = "log-lut")]
+    LogarithmicLut,
 }
 
 impl ResponseCurve {
+    /// Short name for logs and UIs.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ResponseCurve::Linear => "Linear",
+
+            #[cfg(feature = "std-math")]
+            ResponseCurve::Logarithmic => "Logarithmic",
+
+            #[cfg(feature = "log-lut")]
+            ResponseCurve::LogarithmicLut => "LogarithmicLut",
+        }
+    }
+
     /// Apply the response curve to a normalized value (0.0..1.0).
     ///
     /// Returns the transformed value, also in the range 0.0..1.0.
     #[inline]
-    pub fn apply(&self, normalized: f32) -> f32 {
+    pub fn apply(&self, normalized: Float) -> Float {
         match self {
             ResponseCurve::Linear => normalized,
 
             #[cfg(feature = "std-math")]
             ResponseCurve::Logarithmic => apply_logarithmic(normalized),
+
+            #[cfg(feature = "log-lut")]
+            ResponseCurve::LogarithmicLut => apply_logarithmic_lut(normalized),
+        }
+    }
+
+    /// Inverse of `apply()`: the input that produces `curved`.
+    ///
+    /// Used to map an externally set output back into pre-curve space.
+    #[inline]
+    pub fn inverse(&self, curved: Float) -> Float {
+        match self {
+            ResponseCurve::Linear => curved,
+
+            #[cfg(feature = "std-math")]
+            ResponseCurve::Logarithmic => invert_logarithmic(curved),
+
+            #[cfg(feature = "log-lut")]
+            ResponseCurve::LogarithmicLut => invert_logarithmic_lut(curved),
         }
     }
 }
 
+#[cfg(all(feature = "std-math", not(feature = "f64")))]
+const E3_MINUS_1: Float = 19.085_537; // e^3 - 1 precomputed
+
+#[cfg(all(feature = "std-math", feature = "f64"))]
+const E3_MINUS_1: Float = 19.085_536_923_187_668; // e^3 - 1 precomputed
+
 /// Apply logarithmic (audio taper) curve.
 ///
 /// Uses exponential function to create logarithmic response:
@@ -40,19 +88,104 @@ impl ResponseCurve {
 /// - Higher values compress (coarse control at high volumes)
 #[cfg(feature = "std-math")]
 #[inline]
-fn apply_logarithmic(normalized: f32) -> f32 {
-    const E3_MINUS_1: f32 = 19.085_537; // e^3 - 1 precomputed
-
+fn apply_logarithmic(normalized: Float) -> Float {
     // Clamp to valid range to prevent edge cases
     let x = normalized.clamp(0.0, 1.0);
 
     // Compute e^(3x) using libm
+    #[cfg(not(feature = "f64"))]
     let exp_3x = libm::expf(3.0 * x);
 
+    #[cfg(feature = "f64")]
+    let exp_3x = libm::exp(3.0 * x);
+
     // Apply formula: (e^(3x) - 1) / (e^3 - 1)
     (exp_3x - 1.0) / E3_MINUS_1
 }
 
+/// Invert the logarithmic curve: x = ln(y * (e^3 - 1) + 1) / 3
+#[cfg(feature = "std-math")]
+#[inline]
+fn invert_logarithmic(curved: Float) -> Float {
+    let y = curved.clamp(0.0, 1.0);
+
+    #[cfg(not(feature = "f64"))]
+    let ln = libm::logf(y * E3_MINUS_1 + 1.0);
+
+    #[cfg(feature = "f64")]
+    let ln = libm::log(y * E3_MINUS_1 + 1.0);
+
+    (ln / 3.0).clamp(0.0, 1.0)
+}
+
+/// Segments in `LOG_TABLE`. Linear interpolation error is at most
+/// `f''/8 / SEGMENTS^2`, about 7e-5 for 128 segments.
+#[cfg(feature = "log-lut")]
+const LOG_SEGMENTS: usize = 128;
+
+/// `(e^(3x) - 1) / (e^3 - 1)` at `LOG_SEGMENTS + 1` evenly spaced points.
+#[cfg(feature = "log-lut")]
+static LOG_TABLE: [Float; LOG_SEGMENTS + 1] = log_table();
+
+#[cfg(feature = "log-lut")]
+const fn log_table() -> [Float; LOG_SEGMENTS + 1] {
+    let e3_minus_1 = const_exp(3.0) - 1.0;
+    let mut table = [0.0; LOG_SEGMENTS + 1];
+    let mut i = 0;
+    while i <= LOG_SEGMENTS {
+        let x = i as f64 / LOG_SEGMENTS as f64;
+        table[i] = ((const_exp(3.0 * x) - 1.0) / e3_minus_1) as Float;
+        i += 1;
+    }
+    table
+}
+
+/// `e^x` for `0 <= x <= 3` by Taylor series, in `f64` for table generation.
+#[cfg(feature = "log-lut")]
+const fn const_exp(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut n = 1;
+    while n < 40 {
+        term = term * x / n as f64;
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+/// Logarithmic curve by linear interpolation in `LOG_TABLE`.
+#[cfg(feature = "log-lut")]
+#[inline]
+fn apply_logarithmic_lut(normalized: Float) -> Float {
+    let position = normalized.clamp(0.0, 1.0) * LOG_SEGMENTS as Float;
+    let index = (position as usize).min(LOG_SEGMENTS - 1);
+
+    // `get()` rather than indexing keeps this free of bounds-check panics
+    match (LOG_TABLE.get(index), LOG_TABLE.get(index + 1)) {
+        (Some(&a), Some(&b)) => a + (b - a) * (position - index as Float),
+        _ => normalized,
+    }
+}
+
+/// Exact inverse of `apply_logarithmic_lut()`: find the segment by binary
+/// search and interpolate back.
+#[cfg(feature = "log-lut")]
+fn invert_logarithmic_lut(curved: Float) -> Float {
+    let y = curved.clamp(0.0, 1.0);
+    let index = LOG_TABLE[..LOG_SEGMENTS]
+        .partition_point(|&value| value <= y)
+        .saturating_sub(1);
+
+    match (LOG_TABLE.get(index), LOG_TABLE.get(index + 1)) {
+        (Some(&a), Some(&b)) => {
+            let frac = (y - a) / (b - a);
+            ((index as Float + frac) / LOG_SEGMENTS as Float).clamp(0.0, 1.0)
+        }
+        _ => y,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +257,15 @@ mod tests {
 
         assert_eq!(curve1, curve2);
     }
+
+    #[cfg(feature = "std-math")]
+    #[test]
+    fn test_inverse_round_trip() {
+        for curve in [ResponseCurve::Linear, ResponseCurve::Logarithmic] {
+            for x in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+                let back = curve.inverse(curve.apply(x));
+                assert!((back - x).abs() < 1e-5, "{:?} {} -> {}", curve, x, back);
+            }
+        }
+    }
 }
@@ -0,0 +1,124 @@
+//! Dual-gang (stereo) pot processing.
+//!
+//! `DualPotHead` runs both wipers of a ganged pot through the same config,
+//! combines them into one output, and tracks how far the gangs disagree so a
+//! worn pot can be flagged.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// How the two gangs are combined into one output.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GangCombine {
+    /// Mean of both gangs
+    #[default]
+    Average,
+
+    /// Lower of both gangs, e.g. so a worn gang can never make a volume louder
+    Min,
+}
+
+/// Ganged pot processor with mismatch detection.
+#[derive(Clone)]
+pub struct DualPotHead<'a, TIn, TOut = TIn> {
+    gangs: [PotHead<'a, TIn, TOut>; 2],
+    combine: GangCombine,
+    tolerance: Float,
+    mismatch: Float,
+    peak_mismatch: Float,
+    last_output: Float,
+}
+
+impl<'a, TIn, TOut> DualPotHead<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// `tolerance` is the normalized difference (0.0..=1.0) between the gangs'
+    /// outputs above which `is_mismatched()` reports true.
+    pub fn new(
+        config: Config<'a, TIn, TOut>,
+        combine: GangCombine,
+        tolerance: Float,
+    ) -> Result<Self, ConfigError> {
+        if !(0.0..=1.0).contains(&tolerance) {
+            return Err(ConfigError::InvalidTolerance { tolerance });
+        }
+
+        let pot = PotHead::new(config)?;
+        Ok(Self {
+            gangs: [pot.clone(), pot],
+            combine,
+            tolerance,
+            mismatch: 0.0,
+            peak_mismatch: 0.0,
+            last_output: 0.0,
+        })
+    }
+
+    pub fn config(&self) -> &Config<'a, TIn, TOut> {
+        self.gangs[0].config()
+    }
+
+    /// Process one reading from each gang and return the combined output.
+    pub fn update(&mut self, a: TIn, b: TIn) -> TOut {
+        let [first, second] = &mut self.gangs;
+        first.update(a);
+        second.update(b);
+
+        let a = first.last_output_normalized();
+        let b = second.last_output_normalized();
+
+        self.mismatch = (a - b).abs();
+        if self.mismatch > self.peak_mismatch {
+            self.peak_mismatch = self.mismatch;
+        }
+
+        self.last_output = match self.combine {
+            GangCombine::Average => (a + b) * 0.5,
+            GangCombine::Min => a.min(b),
+        };
+
+        self.last_output()
+    }
+
+    /// Most recent combined output, without processing new samples.
+    ///
+    /// With `Config::values`, the entry nearest to the combined value.
+    pub fn last_output(&self) -> TOut {
+        self.gangs[0].output_for(self.last_output)
+    }
+
+    /// Normalized difference between the gangs after the last update.
+    pub fn mismatch(&self) -> Float {
+        self.mismatch
+    }
+
+    /// Largest mismatch seen since creation or `reset()`, for wear tracking.
+    pub fn peak_mismatch(&self) -> Float {
+        self.peak_mismatch
+    }
+
+    /// Returns true if the gangs disagreed by more than the tolerance in the last update.
+    pub fn is_mismatched(&self) -> bool {
+        self.mismatch > self.tolerance
+    }
+
+    /// The individual gangs, e.g. to read each channel for a balance display.
+    pub fn gangs(&self) -> &[PotHead<'a, TIn, TOut>; 2] {
+        &self.gangs
+    }
+
+    /// Reset both gangs and the mismatch tracking.
+    pub fn reset(&mut self) {
+        for gang in &mut self.gangs {
+            gang.reset();
+        }
+        self.mismatch = 0.0;
+        self.peak_mismatch = 0.0;
+        self.last_output = 0.0;
+    }
+}
@@ -0,0 +1,204 @@
+//! Relative rotary encoder processing.
+//!
+//! `EncoderHead` accumulates signed detent deltas into a position and runs it
+//! through the same pipeline as `PotHead`, so output ranges, curves, snap zones,
+//! slew limiting and value tables work identically. The config's input range is
+//! the position range in detents, e.g. `input_range(0.0, 96.0)` for 96 detents
+//! from `output_min` to `output_max`.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+#[cfg(feature = "grab-mode")]
+use crate::grab_mode::GrabMode;
+
+/// Speed-dependent scaling of detent deltas.
+///
+/// Speed is the number of detents reported in one update, so poll at a steady rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Acceleration {
+    /// Every detent moves one step
+    None,
+
+    /// Each detent moves `1 + gain * (speed - 1)` steps, at most `max`
+    Linear { gain: Float, max: Float },
+
+    /// Each detent moves `1 + gain * (speed - 1)²` steps, at most `max`
+    Quadratic { gain: Float, max: Float },
+}
+
+impl Acceleration {
+    /// Steps per detent at `speed` detents per update.
+    #[inline]
+    pub fn multiplier(&self, speed: Float) -> Float {
+        let extra = speed - 1.0;
+        let (scaled, max) = match *self {
+            Acceleration::None => return 1.0,
+            Acceleration::Linear { gain, max } => (1.0 + gain * extra, max),
+            Acceleration::Quadratic { gain, max } => (1.0 + gain * extra * extra, max),
+        };
+        if scaled > max { max } else { scaled }
+    }
+
+    pub const fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            Acceleration::None => Ok(()),
+            Acceleration::Linear { gain, max } | Acceleration::Quadratic { gain, max } => {
+                if gain.is_nan() || *gain < 0.0 {
+                    return Err("acceleration gain must be >= 0.0");
+                }
+                if max.is_nan() || *max < 1.0 {
+                    return Err("acceleration max must be >= 1.0");
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// What happens when the position reaches the end of the range.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EncoderEdge {
+    /// Stop at the ends
+    #[default]
+    Clamp,
+
+    /// Continue from the opposite end (e.g. hue, pan, menu selection)
+    Wrap,
+}
+
+/// Rotary encoder processor with acceleration.
+///
+/// Grab mode is always disabled: an encoder has no absolute position, so it is
+/// always grabbed. `set_output()` moves the position to match instead.
+#[derive(Clone)]
+pub struct EncoderHead<'a, TOut> {
+    pot: PotHead<'a, Float, TOut>,
+    position: Float,
+    acceleration: Acceleration,
+    edge: EncoderEdge,
+}
+
+impl<TOut> core::fmt::Debug for EncoderHead<'_, TOut>
+where
+    TOut: Copy + PartialOrd + IntoNormalized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EncoderHead")
+            .field("pot", &self.pot)
+            .field("position", &self.position)
+            .field("acceleration", &self.acceleration)
+            .field("edge", &self.edge)
+            .finish()
+    }
+}
+
+impl<'a, TOut> EncoderHead<'a, TOut>
+where
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Create an encoder at `input_min`, without acceleration and clamping at the ends.
+    pub fn new(config: Config<'a, Float, TOut>) -> Result<Self, ConfigError> {
+        #[cfg(feature = "grab-mode")]
+        let config = Config {
+            grab_mode: GrabMode::None,
+            ..config
+        };
+
+        Ok(Self {
+            pot: PotHead::new(config)?,
+            position: config.input_min,
+            acceleration: Acceleration::None,
+            edge: EncoderEdge::Clamp,
+        })
+    }
+
+    /// Change the acceleration with validation.
+    pub fn set_acceleration(&mut self, acceleration: Acceleration) -> Result<(), ConfigError> {
+        if let Err(reason) = acceleration.validate() {
+            return Err(ConfigError::InvalidAcceleration { reason });
+        }
+        self.acceleration = acceleration;
+        Ok(())
+    }
+
+    pub fn set_edge(&mut self, edge: EncoderEdge) {
+        self.edge = edge;
+    }
+
+    /// Process the detents turned since the last update (positive increases the position).
+    pub fn update(&mut self, delta: i32) -> TOut {
+        let speed = delta.unsigned_abs() as Float;
+        let step = delta as Float * self.acceleration.multiplier(speed);
+        self.position = self.limit(self.position + step);
+
+        self.pot.update(self.position)
+    }
+
+    /// Force the output to an externally set value and move the position to match,
+    /// so the next turn continues from there.
+    pub fn set_output(&mut self, value: TOut) {
+        self.pot.set_output(value);
+
+        let config = self.pot.config();
+        let pre_curve = config.curve.inverse(self.pot.last_output_normalized());
+        self.position = config.input_min + pre_curve * (config.input_max - config.input_min);
+    }
+
+    /// Current position in detents.
+    pub fn position(&self) -> Float {
+        self.position
+    }
+
+    pub fn last_output(&self) -> TOut {
+        self.pot.last_output()
+    }
+
+    pub fn config(&self) -> &Config<'a, Float, TOut> {
+        self.pot.config()
+    }
+
+    /// The underlying pipeline, for queries like `is_dirty()` or `velocity()`.
+    pub fn pot(&self) -> &PotHead<'a, Float, TOut> {
+        &self.pot
+    }
+
+    /// Reset processing state and return the position to `input_min`.
+    pub fn reset(&mut self) {
+        self.pot.reset();
+        self.position = self.pot.config().input_min;
+    }
+
+    fn limit(&self, position: Float) -> Float {
+        let (min, max) = (self.pot.config().input_min, self.pot.config().input_max);
+        let (lo, hi) = if min < max { (min, max) } else { (max, min) };
+
+        match self.edge {
+            EncoderEdge::Clamp => {
+                if position < lo {
+                    lo
+                } else if position > hi {
+                    hi
+                } else {
+                    position
+                }
+            }
+            EncoderEdge::Wrap => {
+                let span = hi - lo;
+                let mut offset = (position - lo) % span;
+                if offset < 0.0 {
+                    offset += span;
+                }
+                // Rounding can land exactly on the span; that is the start again
+                if offset >= span {
+                    offset = 0.0;
+                }
+                lo + offset
+            }
+        }
+    }
+}
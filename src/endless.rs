@@ -0,0 +1,226 @@
+//! Endless (360°) pot processing.
+//!
+//! `EndlessPotHead` treats the input range as one full turn. Each reading is
+//! unwrapped by taking the shortest path from the previous angle, so the filter
+//! and hysteresis see a continuous position and never jump across the 0/1
+//! seam. The output wraps at 0/1, and the accumulated motion is available for
+//! applications that want relative movement instead of an absolute angle.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::filters::FilterState;
+use crate::float::Float;
+use crate::hysteresis::{HysteresisMode, HysteresisState};
+use crate::scaling::{InputScaling, OutputScaling};
+
+#[cfg(feature = "grab-mode")]
+use crate::grab_mode::GrabMode;
+
+/// Continuous-rotation pot processor.
+///
+/// Uses the config's ranges, `input_center`, `end_margin`, `invert_input`,
+/// filter, hysteresis and curve, normalized like `PotHead`. Snap zones, grab
+/// mode, values, slew limiting and fine adjustment are not supported; `new()`
+/// rejects them with `UnsupportedSetting`, so use `PotHead` for those.
+/// `SchmittTrigger` hysteresis is rejected, since a wrapping position has no
+/// fixed thresholds.
+#[derive(Clone)]
+pub struct EndlessPotHead<'a, TIn, TOut = TIn> {
+    config: Config<'a, TIn, TOut>,
+    input_scaling: InputScaling,
+    output_scaling: OutputScaling,
+    filter: FilterState,
+    hysteresis: HysteresisState<Float>,
+    last_angle: Option<Float>,
+
+    /// Whole turns taken out of `unwrapped` and `position`, which stay within
+    /// a turn of zero so their resolution doesn't coarsen over time
+    turns: i32,
+    unwrapped: Float,
+    position: Float,
+    delta: Float,
+    motion: Float,
+    last_output: Float,
+}
+
+impl<'a, TIn, TOut> EndlessPotHead<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    pub fn new(config: Config<'a, TIn, TOut>) -> Result<Self, ConfigError> {
+        config.validate()?;
+
+        if let HysteresisMode::SchmittTrigger { .. } = config.hysteresis {
+            return Err(ConfigError::InvalidHysteresis {
+                reason: "SchmittTrigger is not supported on endless pots",
+            });
+        }
+
+        if let Some(reason) = unsupported(&config) {
+            return Err(ConfigError::UnsupportedSetting { reason });
+        }
+
+        Ok(Self {
+            config,
+            input_scaling: InputScaling::new(&config),
+            output_scaling: OutputScaling::new(&config),
+            filter: FilterState::new(&config.filter),
+            hysteresis: HysteresisState::default(),
+            last_angle: None,
+            turns: 0,
+            unwrapped: 0.0,
+            position: 0.0,
+            delta: 0.0,
+            motion: 0.0,
+            last_output: 0.0,
+        })
+    }
+
+    pub fn config(&self) -> &Config<'a, TIn, TOut> {
+        &self.config
+    }
+
+    /// Process one angle reading and return the wrapped output.
+    ///
+    /// A NaN or infinite input has no angle; it is ignored and the last output returned.
+    pub fn update(&mut self, input: TIn) -> TOut {
+        let input_f = input.into_normalized();
+        if !input_f.is_finite() {
+            return self.last_output();
+        }
+        let angle = wrap(self.input_scaling.normalize(input_f));
+
+        let first = self.last_angle.is_none();
+        match self.last_angle {
+            None => {
+                self.unwrapped = angle;
+                self.config.hysteresis.seed(angle, &mut self.hysteresis);
+            }
+            Some(last) => self.unwrapped += shortest_path(angle - last),
+        }
+        self.last_angle = Some(angle);
+
+        let filtered = self.filter.apply(&self.config.filter, self.unwrapped);
+        let held = self.config.hysteresis.apply(filtered, &mut self.hysteresis);
+
+        // The first reading sets the starting position without counting as motion
+        if first {
+            self.position = held;
+        }
+
+        self.delta = held - self.position;
+        self.motion += self.delta;
+        self.position = held;
+
+        self.last_output = self.config.curve.apply(wrap(held));
+
+        // Truncates towards zero, so both stay within (-1.0, 1.0) turns
+        let whole = self.unwrapped as i32;
+        if whole != 0 {
+            self.rebase(whole);
+        }
+
+        self.last_output()
+    }
+
+    /// Most recent output, without processing a new sample.
+    pub fn last_output(&self) -> TOut {
+        self.output_scaling.output(
+            self.last_output,
+            self.config.output_min,
+            self.config.output_max,
+        )
+    }
+
+    /// Processed position in turns, without wrapping (e.g. 2.25 after another two
+    /// turns from a quarter). The fractional part is the current angle.
+    pub fn position(&self) -> Float {
+        self.turns as Float + self.position
+    }
+
+    /// Processed motion during the last update, in turns (positive towards `input_max`).
+    pub fn delta(&self) -> Float {
+        self.delta
+    }
+
+    /// Processed motion since the last call, in turns.
+    pub fn take_motion(&mut self) -> Float {
+        let motion = self.motion;
+        self.motion = 0.0;
+        motion
+    }
+
+    /// Reset all processing state; the next reading starts a new position.
+    pub fn reset(&mut self) {
+        self.filter = FilterState::new(&self.config.filter);
+        self.hysteresis = HysteresisState::default();
+        self.last_angle = None;
+        self.turns = 0;
+        self.unwrapped = 0.0;
+        self.position = 0.0;
+        self.delta = 0.0;
+        self.motion = 0.0;
+        self.last_output = 0.0;
+    }
+}
+
+impl<TIn, TOut> EndlessPotHead<'_, TIn, TOut> {
+    /// Take `turns` whole turns out of the unwrapped position and everything
+    /// derived from it. The angle is unchanged.
+    fn rebase(&mut self, turns: i32) {
+        let offset = turns as Float;
+        self.turns = self.turns.wrapping_add(turns);
+        self.unwrapped -= offset;
+        self.position -= offset;
+        self.filter.shift(offset);
+        self.hysteresis.last_output -= offset;
+    }
+}
+
+/// Why `config` can't run on an endless pot: stages that need a fixed
+/// position or a bounded output.
+fn unsupported<TIn, TOut>(config: &Config<'_, TIn, TOut>) -> Option<&'static str> {
+    if !config.snap_zones.is_empty() {
+        return Some("snap zones are not supported on endless pots");
+    }
+    if !config.values.is_empty() {
+        return Some("values are not supported on endless pots");
+    }
+    if config.slew_rate.is_some() {
+        return Some("slew_rate is not supported on endless pots");
+    }
+
+    #[cfg(feature = "grab-mode")]
+    if config.grab_mode != GrabMode::None {
+        return Some("grab mode is not supported on endless pots");
+    }
+
+    #[cfg(feature = "fine-adjust")]
+    if config.fine_adjust.is_some() {
+        return Some("fine adjustment is not supported on endless pots");
+    }
+
+    None
+}
+
+/// Map a change in angle to the shorter way round, in -0.5..=0.5 turns.
+pub(crate) fn shortest_path(delta: Float) -> Float {
+    if delta > 0.5 {
+        delta - 1.0
+    } else if delta < -0.5 {
+        delta + 1.0
+    } else {
+        delta
+    }
+}
+
+/// Fractional part of `turns`, in 0.0..1.0.
+pub(crate) fn wrap(turns: Float) -> Float {
+    let mut angle = turns % 1.0;
+    if angle < 0.0 {
+        angle += 1.0;
+    }
+    // Rounding can land exactly on 1.0; that is the start again
+    if angle >= 1.0 { 0.0 } else { angle }
+}
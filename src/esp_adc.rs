@@ -0,0 +1,52 @@
+//! ESP32 ADC input.
+//!
+//! The ESP32 ADCs are non-linear, especially near the ends of each attenuation
+//! range. esp-hal's calibrated read path (`AdcCalCurve`) corrects this and
+//! returns millivolts; `input_esp32c3_millivolts()` sets the input range to the
+//! span those readings cover and adds filtering and hysteresis for the
+//! ESP32-C3's ADC noise.
+
+use crate::config::ConfigBuilder;
+use crate::filters::NoiseFilter;
+use crate::hysteresis::HysteresisMode;
+
+/// ADC input attenuation, matching esp-hal's `Attenuation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EspAttenuation {
+    Db0,
+    Db2_5,
+    Db6,
+
+    /// Widest range, the usual choice for a pot
+    #[default]
+    Db11,
+}
+
+impl EspAttenuation {
+    /// Calibrated millivolt span the ESP32-C3 datasheet recommends for this
+    /// attenuation. Readings beyond it lose accuracy.
+    pub const fn esp32c3_millivolts(&self) -> (u16, u16) {
+        match self {
+            EspAttenuation::Db0 => (0, 750),
+            EspAttenuation::Db2_5 => (0, 1050),
+            EspAttenuation::Db6 => (0, 1300),
+            EspAttenuation::Db11 => (0, 2500),
+        }
+    }
+}
+
+impl<'a, TOut> ConfigBuilder<'a, u16, TOut> {
+    /// Input from esp-hal's calibrated millivolt readings on an ESP32-C3.
+    ///
+    /// Also sets an EMA filter (alpha 0.2) and a 1% change threshold, which
+    /// keep the output steady against the ADC's noise of a few LSB while
+    /// tracking a turning pot without visible lag. Call `.filter()` or
+    /// `.hysteresis()` afterwards to override.
+    pub fn input_esp32c3_millivolts(self, attenuation: EspAttenuation) -> Self {
+        let (min, max) = attenuation.esp32c3_millivolts();
+        self.input_range(min, max)
+            .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.2 })
+            .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+    }
+}
@@ -0,0 +1,102 @@
+//! Structured events reported by `PotHead::update_with_events()`.
+
+use crate::fault::Fault;
+use crate::hysteresis::SchmittState;
+
+/// A single event from one update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UpdateEvent {
+    /// Output value changed.
+    Changed,
+
+    /// Value entered the snap zone at this index.
+    EnteredSnapZone(usize),
+
+    /// Value left the snap zone at this index.
+    LeftSnapZone(usize),
+
+    /// Pot grabbed the virtual value (Pickup/PassThrough only).
+    Grabbed,
+
+    /// Schmitt trigger switched to this state.
+    SchmittEdge(SchmittState),
+
+    /// A fault was detected that wasn't active in the previous update.
+    Fault(Fault),
+}
+
+/// Everything that happened during one update.
+///
+/// Fields are plain data, so a combination can be checked directly;
+/// `iter()` yields the same information as `UpdateEvent`s to match on.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UpdateEvents {
+    pub changed: bool,
+    pub entered_zone: Option<usize>,
+    pub left_zone: Option<usize>,
+
+    /// Always `false` without the `grab-mode` feature.
+    pub grabbed: bool,
+
+    pub schmitt_edge: Option<SchmittState>,
+
+    /// Newly detected fault. Always `None` without the `fault-detection` feature.
+    pub fault: Option<Fault>,
+}
+
+impl UpdateEvents {
+    /// Returns true if nothing happened.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Iterate over the events in pipeline order (zone exits before entries).
+    pub fn iter(&self) -> impl Iterator<Item = UpdateEvent> {
+        [
+            self.changed.then_some(UpdateEvent::Changed),
+            self.left_zone.map(UpdateEvent::LeftSnapZone),
+            self.entered_zone.map(UpdateEvent::EnteredSnapZone),
+            self.grabbed.then_some(UpdateEvent::Grabbed),
+            self.schmitt_edge.map(UpdateEvent::SchmittEdge),
+            self.fault.map(UpdateEvent::Fault),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_events() {
+        let events = UpdateEvents::default();
+        assert!(events.is_empty());
+        assert_eq!(events.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_order() {
+        let events = UpdateEvents {
+            changed: true,
+            entered_zone: Some(1),
+            left_zone: Some(0),
+            grabbed: false,
+            schmitt_edge: Some(SchmittState::High),
+            fault: None,
+        };
+
+        let mut iter = events.iter();
+        assert_eq!(iter.next(), Some(UpdateEvent::Changed));
+        assert_eq!(iter.next(), Some(UpdateEvent::LeftSnapZone(0)));
+        assert_eq!(iter.next(), Some(UpdateEvent::EnteredSnapZone(1)));
+        assert_eq!(
+            iter.next(),
+            Some(UpdateEvent::SchmittEdge(SchmittState::High))
+        );
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,74 @@
+//! Builder code export for the tuning bindings.
+//!
+//! Tools that tune a pot interactively (`wasm`, `python`) hand the result back
+//! as `Config::builder()` source to paste into firmware.
+
+use std::format;
+use std::string::String;
+
+use crate::config::Config;
+use crate::filters::NoiseFilter;
+use crate::float::Float;
+use crate::hysteresis::HysteresisMode;
+
+/// `Config::builder()` code reproducing `config`'s processing settings.
+pub(crate) fn builder_code(config: &Config<'_, Float, Float>) -> String {
+    let mut code = format!(
+        "Config::builder()\n    .input_range({:?}, {:?})\n    .output_range({:?}, {:?})\n",
+        config.input_min, config.input_max, config.output_min, config.output_max
+    );
+
+    if let Some(center) = config.input_center {
+        code += &format!("    .input_center({:?})\n", center);
+    }
+
+    if config.end_margin > 0.0 {
+        code += &format!("    .end_margin({:?})\n", config.end_margin);
+    }
+
+    if config.invert_input {
+        code += "    .invert_input(true)\n";
+    }
+
+    code += &format!("    .curve(ResponseCurve::{})\n", config.curve.name());
+
+    let filter = match config.filter {
+        NoiseFilter::None => String::from("NoiseFilter::None"),
+        NoiseFilter::ExponentialMovingAverage { alpha } => {
+            format!(
+                "NoiseFilter::ExponentialMovingAverage {{ alpha: {:?} }}",
+                alpha
+            )
+        }
+        #[cfg(feature = "moving-average")]
+        NoiseFilter::MovingAverage { window_size } => {
+            format!(
+                "NoiseFilter::MovingAverage {{ window_size: {} }}",
+                window_size
+            )
+        }
+    };
+    code += &format!("    .filter({})\n", filter);
+
+    let hysteresis = match config.hysteresis {
+        HysteresisMode::None(_) => String::from("HysteresisMode::none()"),
+        HysteresisMode::ChangeThreshold { threshold } => {
+            format!(
+                "HysteresisMode::ChangeThreshold {{ threshold: {:?} }}",
+                threshold
+            )
+        }
+        HysteresisMode::SchmittTrigger { rising, falling } => format!(
+            "HysteresisMode::SchmittTrigger {{ rising: {:?}, falling: {:?} }}",
+            rising, falling
+        ),
+    };
+    code += &format!("    .hysteresis({})\n", hysteresis);
+
+    if let Some(rate) = config.slew_rate {
+        code += &format!("    .slew_rate({:?})\n", rate);
+    }
+
+    code += "    .build()";
+    code
+}
@@ -0,0 +1,86 @@
+//! Broken-wiper and rail fault detection.
+//!
+//! A broken wiper or open ground typically reads hard 0 or full scale, or
+//! jumps between the rails from one sample to the next. `FaultDetection`
+//! describes what counts as implausible; `PotHead::fault()` reports it so the
+//! application can fail safe instead of acting on the reading.
+
+#[cfg(feature = "fault-detection")]
+use core::cmp::Ordering;
+
+#[cfg(feature = "fault-detection")]
+use crate::config::ConfigError;
+#[cfg(feature = "fault-detection")]
+use crate::float::Float;
+
+/// What `PotHead::fault()` detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Fault {
+    /// Input sustained at or below `FaultDetection::low_rail`.
+    LowRail,
+
+    /// Input sustained at or above `FaultDetection::high_rail`.
+    HighRail,
+
+    /// Input moved further than `FaultDetection::max_step` in one update.
+    Jump,
+}
+
+/// Fault detection settings for `PotHead::set_fault_detection()`.
+///
+/// Set the rails just outside the pot's calibrated input range, where a
+/// healthy pot never reads: a pot turned fully down rests at `input_min`, not
+/// on the ADC rail. Requires `fault-detection` feature.
+#[cfg(feature = "fault-detection")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FaultDetection<TIn> {
+    /// Raw readings at or below this are on the low rail
+    pub low_rail: TIn,
+
+    /// Raw readings at or above this are on the high rail
+    pub high_rail: TIn,
+
+    /// Consecutive rail readings before reporting a rail fault (zero is treated
+    /// as one)
+    pub rail_samples: u16,
+
+    /// Largest plausible input change per update, as a fraction of the input
+    /// range. `None` disables jump detection.
+    pub max_step: Option<Float>,
+
+    /// Ignore samples while a fault is active, holding the last output
+    pub hold_output: bool,
+}
+
+#[cfg(feature = "fault-detection")]
+impl<TIn: PartialOrd> FaultDetection<TIn> {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let reason = if self.low_rail.partial_cmp(&self.high_rail) != Some(Ordering::Less) {
+            "low_rail must be below high_rail"
+        } else if let Some(step) = self.max_step
+            && (step.is_nan() || step <= 0.0)
+        {
+            "max_step must be > 0.0"
+        } else {
+            return Ok(());
+        };
+        Err(ConfigError::InvalidFaultDetection { reason })
+    }
+}
+
+/// Fault detection progress.
+#[cfg(feature = "fault-detection")]
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct FaultState {
+    /// Rail the previous samples were on, and for how many in a row
+    pub rail: Option<Fault>,
+    pub rail_count: u16,
+
+    /// Previous raw input, as `Float`
+    pub last_input: Option<Float>,
+
+    /// Fault from the last update
+    pub fault: Option<Fault>,
+}
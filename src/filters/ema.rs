@@ -1,7 +1,10 @@
+use crate::float::Float;
+
 /// Exponential Moving Average filter state
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct EmaFilter {
-    previous: f32,
+    previous: Float,
     initialized: bool,
 }
 
@@ -17,13 +20,8 @@ impl EmaFilter {
     /// Apply EMA filter: output = alpha * input + (1 - alpha) * previous
     ///
     /// First call initializes the filter to the input value.
-    pub fn apply(&mut self, input: f32, alpha: f32) -> f32 {
-        debug_assert!(
-            alpha > 0.0 && alpha <= 1.0,
-            "EMA alpha must be in range (0.0, 1.0], got {}",
-            alpha
-        );
-
+    /// `alpha` is checked by config validation, not here, so this never panics.
+    pub fn apply(&mut self, input: Float, alpha: Float) -> Float {
         if !self.initialized {
             self.previous = input;
             self.initialized = true;
@@ -35,6 +33,12 @@ impl EmaFilter {
         output
     }
 
+    /// Move the filter state by `offset`, as if every past input had been
+    /// `offset` lower.
+    pub fn shift(&mut self, offset: Float) {
+        self.previous -= offset;
+    }
+
     /// Reset filter state
     pub fn reset(&mut self) {
         self.initialized = false;
@@ -1,6 +1,6 @@
 /// Noise filtering implementations
 ///
-/// Filters smooth noisy ADC readings. All filtering happens in normalized f32 space.
+/// Filters smooth noisy ADC readings. All filtering happens in normalized `Float` space.
 mod ema;
 
 #[cfg(feature = "moving-average")]
@@ -8,11 +8,14 @@ mod moving_avg;
 
 pub use ema::EmaFilter;
 
+use crate::float::Float;
+
 #[cfg(feature = "moving-average")]
 pub use moving_avg::MovingAvgFilter;
 
 /// Noise filter configuration
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum NoiseFilter {
     /// No filtering applied
     None,
@@ -20,7 +23,7 @@ pub enum NoiseFilter {
     /// Exponential moving average: output = alpha * input + (1 - alpha) * previous
     /// Lower alpha = more smoothing, higher = more responsive
     /// Requires: 0.0 < alpha <= 1.0
-    ExponentialMovingAverage { alpha: f32 },
+    ExponentialMovingAverage { alpha: Float },
 
     /// Simple moving average over N samples
     /// Window size configured at filter creation
@@ -30,13 +33,24 @@ pub enum NoiseFilter {
 }
 
 impl NoiseFilter {
+    /// Short name for logs and UIs.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            NoiseFilter::None => "None",
+            NoiseFilter::ExponentialMovingAverage { .. } => "EMA",
+
+            #[cfg(feature = "moving-average")]
+            NoiseFilter::MovingAverage { .. } => "MovingAverage",
+        }
+    }
+
     /// Validate filter configuration at compile time
     pub const fn validate(&self) -> Result<(), &'static str> {
         match self {
             NoiseFilter::None => Ok(()),
 
             NoiseFilter::ExponentialMovingAverage { alpha } => {
-                if *alpha <= 0.0 || *alpha > 1.0 {
+                if alpha.is_nan() || *alpha <= 0.0 || *alpha > 1.0 {
                     return Err("EMA alpha must be in range (0.0, 1.0]");
                 }
                 Ok(())
@@ -55,3 +69,62 @@ impl NoiseFilter {
         }
     }
 }
+
+/// Filter state for processors that run their own pipeline (`EndlessPotHead`,
+/// `JoystickHead`). `PotHead` keeps its filters in `State` instead.
+#[derive(Debug, Clone)]
+pub(crate) struct FilterState {
+    ema: Option<EmaFilter>,
+
+    #[cfg(feature = "moving-average")]
+    ma: Option<MovingAvgFilter>,
+}
+
+impl FilterState {
+    pub(crate) fn new(filter: &NoiseFilter) -> Self {
+        Self {
+            ema: match filter {
+                NoiseFilter::ExponentialMovingAverage { .. } => Some(EmaFilter::new()),
+                _ => None,
+            },
+
+            #[cfg(feature = "moving-average")]
+            ma: match filter {
+                NoiseFilter::MovingAverage { window_size } => {
+                    Some(MovingAvgFilter::new(*window_size))
+                }
+                _ => None,
+            },
+        }
+    }
+
+    pub(crate) fn apply(&mut self, filter: &NoiseFilter, value: Float) -> Float {
+        match filter {
+            NoiseFilter::None => value,
+
+            NoiseFilter::ExponentialMovingAverage { alpha } => match &mut self.ema {
+                Some(ema) => ema.apply(value, *alpha),
+                None => value,
+            },
+
+            #[cfg(feature = "moving-average")]
+            NoiseFilter::MovingAverage { .. } => match &mut self.ma {
+                Some(ma) => ma.apply(value),
+                None => value,
+            },
+        }
+    }
+
+    /// Move the state by `offset`, so a caller can re-base its positions
+    /// without the filter seeing a jump.
+    pub(crate) fn shift(&mut self, offset: Float) {
+        if let Some(ema) = &mut self.ema {
+            ema.shift(offset);
+        }
+
+        #[cfg(feature = "moving-average")]
+        if let Some(ma) = &mut self.ma {
+            ma.shift(offset);
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use heapless::Vec;
 
+use crate::float::Float;
+
 /// Simple moving average filter state
 ///
 /// Maintains a circular buffer of past samples. RAM cost: window_size * 4 bytes.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct MovingAvgFilter {
-    buffer: Vec<f32, 32>, // Max window size of 32
+    buffer: Vec<Float, 32>, // Max window size of 32
     window_size: usize,
     index: usize,
     count: usize,
@@ -14,13 +17,13 @@ pub struct MovingAvgFilter {
 impl MovingAvgFilter {
     /// Create new moving average filter
     ///
-    /// window_size must be > 0 and <= 32
+    /// window_size is clamped to 1..=32 (validation rejects other sizes)
     pub fn new(window_size: usize) -> Self {
-        debug_assert!(window_size > 0 && window_size <= 32);
+        let window_size = window_size.clamp(1, 32);
 
         let mut buffer = Vec::new();
         // Pre-fill buffer with zeros
-        for _ in 0..window_size.min(32) {
+        for _ in 0..window_size {
             let _ = buffer.push(0.0);
         }
 
@@ -36,10 +39,15 @@ impl MovingAvgFilter {
     ///
     /// Averages the last window_size samples. Until buffer is full,
     /// averages all samples received so far.
-    pub fn apply(&mut self, input: f32) -> f32 {
-        // Store input in circular buffer
-        self.buffer[self.index] = input;
-        self.index = (self.index + 1) % self.window_size;
+    pub fn apply(&mut self, input: Float) -> Float {
+        // Store input in circular buffer (no indexing, so no bounds-check panic)
+        if let Some(slot) = self.buffer.get_mut(self.index) {
+            *slot = input;
+        }
+        self.index += 1;
+        if self.index >= self.window_size {
+            self.index = 0;
+        }
 
         // Track how many samples we've seen
         if self.count < self.window_size {
@@ -47,8 +55,16 @@ impl MovingAvgFilter {
         }
 
         // Calculate average of samples collected so far
-        let sum: f32 = self.buffer.iter().take(self.count).sum();
-        sum / self.count as f32
+        let sum: Float = self.buffer.iter().take(self.count).sum();
+        sum / self.count as Float
+    }
+
+    /// Move the filter state by `offset`, as if every past input had been
+    /// `offset` lower.
+    pub fn shift(&mut self, offset: Float) {
+        for val in self.buffer.iter_mut() {
+            *val -= offset;
+        }
     }
 
     /// Reset filter state
@@ -102,7 +118,7 @@ mod tests {
         let mut filter = MovingAvgFilter::new(4);
 
         let samples = [1.0, 1.1, 0.9, 1.0];
-        let mut outputs: Vec<f32, 4> = Vec::new();
+        let mut outputs: Vec<Float, 4> = Vec::new();
 
         for &sample in &samples {
             let _ = outputs.push(filter.apply(sample));
@@ -0,0 +1,109 @@
+//! Velocity-dependent fine adjustment.
+//!
+//! Slow turns move the output by a fraction of the knob movement, so a small
+//! change can be dialed in without steady hands. Fast turns move it at full
+//! rate. Like pointer acceleration in reverse.
+
+use crate::float::Float;
+
+/// Scales knob movement down while the pot turns slowly.
+///
+/// The gain goes linearly from `gain` at rest to 1.0 at `coarse_speed` and above.
+/// Movement is scaled towards the end of travel it heads for, so knob and output
+/// reach the stops together and the full output range stays reachable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FineAdjust {
+    /// Fraction of the movement applied while the pot turns slowly, in (0.0, 1.0]
+    pub gain: Float,
+
+    /// Filtered movement per update (normalized, as `velocity()` before
+    /// `update_timed()` rescales it) at and above which movement maps 1:1
+    pub coarse_speed: Float,
+}
+
+impl FineAdjust {
+    pub const fn new(gain: Float, coarse_speed: Float) -> Self {
+        Self { gain, coarse_speed }
+    }
+
+    /// Fraction of the movement applied at `speed` normalized units per update.
+    #[inline]
+    pub fn gain_at(&self, speed: Float) -> Float {
+        let coarse = speed.abs() / self.coarse_speed;
+        let coarse = if coarse > 1.0 { 1.0 } else { coarse };
+        self.gain + (1.0 - self.gain) * coarse
+    }
+
+    pub const fn validate(&self) -> Result<(), &'static str> {
+        if !(self.gain > 0.0 && self.gain <= 1.0) {
+            return Err("fine adjust gain must be in range (0.0, 1.0]");
+        }
+        if !(self.coarse_speed > 0.0 && self.coarse_speed.is_finite()) {
+            return Err("fine adjust coarse_speed must be > 0.0 and finite");
+        }
+        Ok(())
+    }
+
+    /// Move `position` along with the knob going from `last` to `value`.
+    ///
+    /// Both ends of travel pin the output, so a knob at a stop is at that end.
+    #[inline]
+    pub(crate) fn step(&self, position: Float, last: Float, value: Float, speed: Float) -> Float {
+        if value >= 1.0 {
+            return 1.0;
+        }
+        if value <= 0.0 {
+            return 0.0;
+        }
+
+        let delta = value - last;
+        let gain = self.gain_at(speed);
+        let moved = if delta > 0.0 {
+            position + gain * delta * (1.0 - position) / (1.0 - last)
+        } else if delta < 0.0 {
+            position + gain * delta * position / last
+        } else {
+            position
+        };
+        moved.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gain_ramps_to_one() {
+        let fine = FineAdjust::new(0.1, 0.01);
+        assert_eq!(fine.gain_at(0.0), 0.1);
+        assert!((fine.gain_at(-0.005) - 0.55).abs() < 1e-6);
+        assert_eq!(fine.gain_at(0.02), 1.0);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(FineAdjust::new(0.1, 0.01).validate().is_ok());
+        assert!(FineAdjust::new(1.0, 0.01).validate().is_ok());
+        assert!(FineAdjust::new(0.0, 0.01).validate().is_err());
+        assert!(FineAdjust::new(1.5, 0.01).validate().is_err());
+        assert!(FineAdjust::new(Float::NAN, 0.01).validate().is_err());
+        assert!(FineAdjust::new(0.1, 0.0).validate().is_err());
+        assert!(FineAdjust::new(0.1, Float::INFINITY).validate().is_err());
+    }
+
+    #[test]
+    fn test_full_gain_without_offset_is_one_to_one() {
+        let fine = FineAdjust::new(0.1, 0.01);
+        let moved = fine.step(0.3, 0.3, 0.5, 0.2);
+        assert!((moved - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_stops_pin_the_output() {
+        let fine = FineAdjust::new(0.1, 0.01);
+        assert_eq!(fine.step(0.4, 0.9, 1.0, 0.0), 1.0);
+        assert_eq!(fine.step(0.4, 0.1, 0.0, 0.0), 0.0);
+    }
+}
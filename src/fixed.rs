@@ -0,0 +1,217 @@
+//! Integer-only pipeline for targets without an FPU.
+//!
+//! `FixedPotHead` runs normalize → curve → hysteresis → snap zones entirely in
+//! Q16.16 fixed point (`i32`, where `ONE` = 1.0), so no soft-float routines run
+//! on the hot path. Curves are piecewise-linear lookup tables. Filtering and
+//! grab mode are not supported; use `PotHead` for those.
+
+use crate::config::ConfigError;
+use crate::float::Float;
+use crate::snap_zones::{SnapZone, SnapZoneType};
+
+/// Q16.16 representation of 1.0.
+pub const ONE: i32 = 1 << 16;
+
+/// `num / den` as a Q16.16 value, e.g. `ratio(1, 100)` for a 1% threshold.
+pub const fn ratio(num: i32, den: i32) -> i32 {
+    ((num as i64 * ONE as i64) / den as i64) as i32
+}
+
+/// Logarithmic (audio taper) curve sampled at 17 points, matching
+/// `ResponseCurve::Logarithmic` to within 0.5%.
+pub static LOG_LUT: [i32; 17] = [
+    0, 708, 1562, 2593, 3836, 5335, 7143, 9324, 11955, 15129, 18957, 23575, 29145, 35864, 43968,
+    53744, 65536,
+];
+
+/// Response curve in Q16.16.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FixedCurve {
+    /// Linear response (1:1 mapping)
+    Linear,
+
+    /// Table of Q16.16 outputs evenly spaced over 0..=ONE, linearly interpolated.
+    Lut(&'static [i32]),
+}
+
+impl FixedCurve {
+    /// Apply the curve to a normalized Q16.16 value (0..=ONE).
+    #[inline]
+    pub fn apply(&self, normalized: i32) -> i32 {
+        match self {
+            FixedCurve::Linear => normalized,
+            FixedCurve::Lut(table) => {
+                let segments = table.len().saturating_sub(1) as i64;
+                let position = normalized.clamp(0, ONE) as i64 * segments;
+                let index = (position >> 16) as usize;
+
+                // `get()` rather than indexing keeps this free of bounds-check panics
+                match (table.get(index), table.get(index + 1)) {
+                    (Some(&a), Some(&b)) => {
+                        let frac = position & 0xFFFF;
+                        let (a, b) = (a as i64, b as i64);
+                        (a + (((b - a) * frac) >> 16)) as i32
+                    }
+                    (Some(&last), None) => last,
+                    _ => normalized,
+                }
+            }
+        }
+    }
+
+    pub const fn validate(&self) -> Result<(), &'static str> {
+        if let FixedCurve::Lut(table) = self
+            && table.len() < 2
+        {
+            return Err("curve table needs at least 2 entries");
+        }
+        Ok(())
+    }
+}
+
+/// Configuration for `FixedPotHead`. Thresholds and snap zones are Q16.16.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FixedConfig<'a> {
+    pub input_min: i32,
+    pub input_max: i32,
+    pub output_min: i32,
+    pub output_max: i32,
+    pub curve: FixedCurve,
+
+    /// Ignore changes of this size or smaller (0 disables hysteresis)
+    pub change_threshold: i32,
+
+    pub snap_zones: &'a [SnapZone<i32>],
+}
+
+impl FixedConfig<'_> {
+    /// Runtime validation, checking the same rules as `Config::validate()`.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.input_min == self.input_max {
+            return Err(ConfigError::InvalidInputRange {
+                min: self.input_min as Float,
+                max: self.input_max as Float,
+            });
+        }
+
+        if self.output_min == self.output_max {
+            return Err(ConfigError::InvalidOutputRange {
+                min: self.output_min as Float,
+                max: self.output_max as Float,
+            });
+        }
+
+        if let Err(reason) = self.curve.validate() {
+            return Err(ConfigError::InvalidCurve { reason });
+        }
+
+        if self.change_threshold < 0 {
+            return Err(ConfigError::InvalidHysteresis {
+                reason: "ChangeThreshold threshold must be >= 0.0",
+            });
+        }
+
+        for (index, zone) in self.snap_zones.iter().enumerate() {
+            if zone.threshold < 0 {
+                return Err(ConfigError::InvalidSnapZone {
+                    index,
+                    threshold: zone.threshold as Float / ONE as Float,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Potentiometer processor using only integer arithmetic.
+///
+/// ```ignore
+/// static CONFIG: FixedConfig = FixedConfig {
+///     input_min: 0,
+///     input_max: 4095,
+///     output_min: 0,
+///     output_max: 127,
+///     curve: FixedCurve::Lut(&LOG_LUT),
+///     change_threshold: ratio(1, 100),
+///     snap_zones: &[],
+/// };
+///
+/// let mut pot = FixedPotHead::new(&CONFIG)?;
+/// let midi = pot.update(raw_adc as i32);
+/// ```
+pub struct FixedPotHead<'a> {
+    config: &'a FixedConfig<'a>,
+    last_hysteresis: i32,
+    last_output: i32,
+}
+
+impl<'a> FixedPotHead<'a> {
+    pub fn new(config: &'a FixedConfig<'a>) -> Result<Self, ConfigError> {
+        config.validate()?;
+
+        Ok(Self {
+            config,
+            last_hysteresis: 0,
+            last_output: 0,
+        })
+    }
+
+    pub fn config(&self) -> &FixedConfig<'a> {
+        self.config
+    }
+
+    pub fn update(&mut self, input: i32) -> i32 {
+        let normalized = self.normalize_input(input);
+        let curved = self.config.curve.apply(normalized);
+        let held = self.apply_hysteresis(curved);
+        self.last_output = self.apply_snap_zones(held);
+
+        self.last_output()
+    }
+
+    /// Most recent output, without processing a new sample.
+    pub fn last_output(&self) -> i32 {
+        let min = self.config.output_min as i64;
+        let span = self.config.output_max as i64 - min;
+        let scaled = span.saturating_mul(self.last_output as i64);
+        (min + (scaled.saturating_add(ONE as i64 >> 1) >> 16)) as i32
+    }
+
+    /// Most recent output as Q16.16 (0..=ONE).
+    pub fn last_output_normalized(&self) -> i32 {
+        self.last_output
+    }
+
+    fn normalize_input(&self, input: i32) -> i32 {
+        let min = self.config.input_min as i64;
+        let max = self.config.input_max as i64;
+
+        // Descending ranges are allowed, so clamp to whichever end is lower
+        let clamped = (input as i64).clamp(min.min(max), min.max(max));
+
+        // Validation ensures max != min; checked_div keeps this panic-free regardless
+        ((clamped - min) << 16).checked_div(max - min).unwrap_or(0) as i32
+    }
+
+    fn apply_hysteresis(&mut self, value: i32) -> i32 {
+        if value.abs_diff(self.last_hysteresis) > self.config.change_threshold.unsigned_abs() {
+            self.last_hysteresis = value;
+        }
+        self.last_hysteresis
+    }
+
+    fn apply_snap_zones(&self, value: i32) -> i32 {
+        for zone in self.config.snap_zones {
+            if zone.contains(value) {
+                return match zone.zone_type {
+                    SnapZoneType::Snap => zone.target,
+                    SnapZoneType::Dead => self.last_output,
+                };
+            }
+        }
+        value
+    }
+}
@@ -0,0 +1,10 @@
+//! Internal processing float type.
+//!
+//! The normalized pipeline runs in `f32` by default. The `f64` feature switches
+//! it to `f64` for high-resolution ADCs where `f32` quantization becomes visible.
+
+#[cfg(not(feature = "f64"))]
+pub type Float = f32;
+
+#[cfg(feature = "f64")]
+pub type Float = f64;
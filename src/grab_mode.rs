@@ -4,9 +4,11 @@
 //! (e.g., after automation or preset change), grab modes prevent jarring jumps.
 
 /// Grab mode determines how pot position synchronizes with virtual parameter value.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum GrabMode {
     /// Disabled - pot position immediately controls output (may cause jumps).
+    #[default]
     None,
 
     /// Pickup mode - catches when pot crosses virtual value from below.
@@ -18,12 +20,29 @@ pub enum GrabMode {
     PassThrough,
 }
 
-impl Default for GrabMode {
-    fn default() -> Self {
-        Self::None
+impl GrabMode {
+    /// Short name for logs and UIs.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            GrabMode::None => "None",
+            GrabMode::Pickup => "Pickup",
+            GrabMode::PassThrough => "PassThrough",
+        }
     }
 }
 
+/// Determines how a pot re-grabs after `PotHead::release()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReleasePolicy {
+    /// Pot must move across the virtual value to re-grab.
+    #[default]
+    RequireMovement,
+
+    /// Next update re-grabs if the pot is still at the released position (within `grab_margin`).
+    GrabInPlace,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,6 +52,11 @@ mod tests {
         assert_eq!(GrabMode::default(), GrabMode::None);
     }
 
+    #[test]
+    fn test_release_policy_default() {
+        assert_eq!(ReleasePolicy::default(), ReleasePolicy::RequireMovement);
+    }
+
     #[test]
     fn test_grab_mode_equality() {
         assert_eq!(GrabMode::None, GrabMode::None);
@@ -1,7 +1,10 @@
 use core::marker::PhantomData;
 
+use crate::float::Float;
+
 /// Schmitt trigger output state
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SchmittState {
     Low,
     High,
@@ -10,6 +13,7 @@ pub enum SchmittState {
 /// Hysteresis modes for noise reduction and oscillation prevention.
 /// Operates on normalized values (0.0-1.0) in the processing pipeline.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HysteresisMode<T> {
     /// No hysteresis applied
     None(PhantomData<T>),
@@ -22,8 +26,9 @@ pub enum HysteresisMode<T> {
 }
 
 /// State for hysteresis processing.
-/// Type parameter T matches the normalized value type (typically f32).
+/// Type parameter T matches the normalized value type (typically `Float`).
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct HysteresisState<T> {
     pub last_output: T,
     pub schmitt_state: SchmittState,
@@ -45,6 +50,15 @@ impl<T> HysteresisMode<T> {
     pub const fn none() -> Self {
         HysteresisMode::None(PhantomData)
     }
+
+    /// Short name for logs and UIs.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            HysteresisMode::None(_) => "None",
+            HysteresisMode::ChangeThreshold { .. } => "ChangeThreshold",
+            HysteresisMode::SchmittTrigger { .. } => "SchmittTrigger",
+        }
+    }
 }
 
 impl<T> HysteresisMode<T>
@@ -94,6 +108,20 @@ where
         }
     }
 
+    /// Seed the state so `value` becomes the current reference,
+    /// e.g. after the output was set externally.
+    pub fn seed(&self, value: T, state: &mut HysteresisState<T>) {
+        state.last_output = value;
+
+        if let HysteresisMode::SchmittTrigger { rising, falling } = self {
+            if value >= *rising {
+                state.schmitt_state = SchmittState::High;
+            } else if value <= *falling {
+                state.schmitt_state = SchmittState::Low;
+            }
+        }
+    }
+
     pub fn validate(&self) -> Result<(), &'static str> {
         match self {
             HysteresisMode::None(_) => Ok(()),
@@ -101,7 +129,8 @@ where
             HysteresisMode::ChangeThreshold { .. } => Ok(()),
 
             HysteresisMode::SchmittTrigger { rising, falling } => {
-                if rising <= falling {
+                // Unordered (NaN) thresholds fail too
+                if rising.partial_cmp(falling) != Some(core::cmp::Ordering::Greater) {
                     Err("Schmitt trigger: rising threshold must be greater than falling threshold")
                 } else {
                     Ok(())
@@ -110,3 +139,34 @@ where
         }
     }
 }
+
+impl HysteresisMode<Float> {
+    /// Const validation for normalized hysteresis as used in `Config`.
+    ///
+    /// In addition to `validate()`, rejects negative thresholds.
+    pub const fn validate_normalized(&self) -> Result<(), &'static str> {
+        match self {
+            HysteresisMode::None(_) => Ok(()),
+
+            HysteresisMode::ChangeThreshold { threshold } => {
+                if threshold.is_nan() || *threshold < 0.0 {
+                    return Err("Change threshold must be >= 0.0");
+                }
+                Ok(())
+            }
+
+            HysteresisMode::SchmittTrigger { rising, falling } => {
+                if rising.is_nan() || falling.is_nan() || *rising <= *falling {
+                    return Err(
+                        "Schmitt trigger: rising threshold must be greater than falling threshold",
+                    );
+                }
+                // The output holds at a threshold, so both must be valid outputs
+                if *falling < 0.0 || *rising > 1.0 {
+                    return Err("Schmitt trigger: thresholds must be in range [0.0, 1.0]");
+                }
+                Ok(())
+            }
+        }
+    }
+}
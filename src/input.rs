@@ -0,0 +1,19 @@
+//! Input errors reported by `PotHead::try_update()`.
+
+/// Why an input sample was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InputError {
+    /// The sample converted to NaN, e.g. from a failed sensor conversion.
+    NotANumber,
+}
+
+impl core::fmt::Display for InputError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InputError::NotANumber => f.write_str("input is NaN"),
+        }
+    }
+}
+
+impl core::error::Error for InputError {}
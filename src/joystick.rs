@@ -0,0 +1,242 @@
+//! Two-axis joystick processing.
+//!
+//! `JoystickHead` processes an (x, y) pair as one stick: each axis is
+//! normalized, filtered and centered to -1.0..1.0, then a 2D dead zone and
+//! per-axis curves are applied before mapping to each axis' output range.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::filters::FilterState;
+use crate::float::Float;
+use crate::scaling::{InputScaling, OutputScaling};
+
+/// Dead zone around the stick's rest position, as a fraction of full deflection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum JoystickDeadZone {
+    /// No dead zone
+    None,
+
+    /// Each axis ignores deflection up to `width` independently, so moving
+    /// along one axis never leaks into the other
+    Cross { width: Float },
+
+    /// Deflection up to `radius` in any direction is ignored. Also limits the
+    /// output to the unit circle, so diagonals are no faster than straight moves.
+    ///
+    /// Requires `std-math` feature.
+    #[cfg(feature = "std-math")]
+    Radial { radius: Float },
+}
+
+impl JoystickDeadZone {
+    pub const fn validate(&self) -> Result<(), ConfigError> {
+        let size = match self {
+            JoystickDeadZone::None => return Ok(()),
+            JoystickDeadZone::Cross { width } => *width,
+
+            #[cfg(feature = "std-math")]
+            JoystickDeadZone::Radial { radius } => *radius,
+        };
+
+        if size.is_nan() || size < 0.0 || size >= 1.0 {
+            return Err(ConfigError::InvalidDeadZone { size });
+        }
+        Ok(())
+    }
+
+    fn apply(&self, x: Float, y: Float) -> (Float, Float) {
+        match *self {
+            JoystickDeadZone::None => (x, y),
+            JoystickDeadZone::Cross { width } => (rescale(x, width), rescale(y, width)),
+
+            #[cfg(feature = "std-math")]
+            JoystickDeadZone::Radial { radius } => {
+                let magnitude = hypot(x, y);
+                if magnitude <= radius {
+                    return (0.0, 0.0);
+                }
+                let scaled = ((magnitude - radius) / (1.0 - radius)).min(1.0);
+                let scale = scaled / magnitude;
+                (x * scale, y * scale)
+            }
+        }
+    }
+}
+
+/// Joystick processor with a 2D dead zone.
+///
+/// Each axis uses its own config's ranges, `invert_input`, filter and curve.
+/// The rest position is the middle of the input range. Curves are applied
+/// symmetrically around the center. Hysteresis, snap zones, grab mode, values
+/// and slew limiting are not used.
+#[derive(Clone)]
+pub struct JoystickHead<'a, TIn, TOut = TIn> {
+    x_config: Config<'a, TIn, TOut>,
+    y_config: Config<'a, TIn, TOut>,
+    x_scaling: InputScaling,
+    y_scaling: InputScaling,
+    x_output: OutputScaling,
+    y_output: OutputScaling,
+    dead_zone: JoystickDeadZone,
+    x_filter: FilterState,
+    y_filter: FilterState,
+    x_raw: Float,
+    y_raw: Float,
+    x: Float,
+    y: Float,
+}
+
+impl<'a, TIn, TOut> JoystickHead<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    pub fn new(
+        x_config: Config<'a, TIn, TOut>,
+        y_config: Config<'a, TIn, TOut>,
+        dead_zone: JoystickDeadZone,
+    ) -> Result<Self, ConfigError> {
+        x_config.validate()?;
+        y_config.validate()?;
+        dead_zone.validate()?;
+
+        Ok(Self {
+            x_config,
+            y_config,
+            x_scaling: InputScaling::new(&x_config),
+            y_scaling: InputScaling::new(&y_config),
+            x_output: OutputScaling::new(&x_config),
+            y_output: OutputScaling::new(&y_config),
+            dead_zone,
+            x_filter: FilterState::new(&x_config.filter),
+            y_filter: FilterState::new(&y_config.filter),
+            x_raw: 0.0,
+            y_raw: 0.0,
+            x: 0.0,
+            y: 0.0,
+        })
+    }
+
+    pub fn x_config(&self) -> &Config<'a, TIn, TOut> {
+        &self.x_config
+    }
+
+    pub fn y_config(&self) -> &Config<'a, TIn, TOut> {
+        &self.y_config
+    }
+
+    /// Process one reading per axis and return the (x, y) outputs.
+    ///
+    /// A NaN reading leaves that axis at its previous position.
+    pub fn update(&mut self, x: TIn, y: TIn) -> (TOut, TOut) {
+        if let Some(x) = center(&self.x_scaling, x) {
+            self.x_raw = self.x_filter.apply(&self.x_config.filter, x);
+        }
+        if let Some(y) = center(&self.y_scaling, y) {
+            self.y_raw = self.y_filter.apply(&self.y_config.filter, y);
+        }
+
+        let (x, y) = self.dead_zone.apply(self.x_raw, self.y_raw);
+        self.x = bipolar_curve(&self.x_config, x);
+        self.y = bipolar_curve(&self.y_config, y);
+
+        self.last_output()
+    }
+
+    /// Most recent (x, y) outputs, without processing new samples.
+    pub fn last_output(&self) -> (TOut, TOut) {
+        (
+            denormalize(&self.x_output, &self.x_config, self.x),
+            denormalize(&self.y_output, &self.y_config, self.y),
+        )
+    }
+
+    /// Processed deflection per axis, in -1.0..=1.0 with 0.0 at rest.
+    pub fn centered(&self) -> (Float, Float) {
+        (self.x, self.y)
+    }
+
+    /// Distance of the processed deflection from rest. 0.0 at rest and 1.0 at
+    /// full deflection along an axis (up to √2 in the corners without a radial dead zone).
+    #[cfg(feature = "std-math")]
+    pub fn magnitude(&self) -> Float {
+        hypot(self.x, self.y)
+    }
+
+    /// Direction of the processed deflection in radians, counter-clockwise
+    /// from +x, in -π..=π. 0.0 at rest.
+    #[cfg(feature = "std-math")]
+    pub fn angle(&self) -> Float {
+        atan2(self.y, self.x)
+    }
+
+    /// Reset filter state and return the stick to rest.
+    pub fn reset(&mut self) {
+        self.x_filter = FilterState::new(&self.x_config.filter);
+        self.y_filter = FilterState::new(&self.y_config.filter);
+        self.x_raw = 0.0;
+        self.y_raw = 0.0;
+        self.x = 0.0;
+        self.y = 0.0;
+    }
+}
+
+/// Normalize to -1.0..=1.0 around `input_center`, or the middle of the input
+/// range without one. `None` for NaN.
+fn center<TIn: IntoNormalized>(scaling: &InputScaling, input: TIn) -> Option<Float> {
+    let input_f = input.into_normalized();
+    if input_f.is_nan() {
+        return None;
+    }
+
+    Some(scaling.normalize(input_f) * 2.0 - 1.0)
+}
+
+/// Apply the axis curve to the deflection, mirrored around the center.
+fn bipolar_curve<TIn, TOut>(config: &Config<'_, TIn, TOut>, value: Float) -> Float {
+    let curved = config.curve.apply(value.abs());
+    if value < 0.0 { -curved } else { curved }
+}
+
+/// Map a deflection in -1.0..=1.0 to the axis' output range.
+fn denormalize<TIn, TOut>(
+    scaling: &OutputScaling,
+    config: &Config<'_, TIn, TOut>,
+    centered: Float,
+) -> TOut
+where
+    TOut: Copy + FromNormalized,
+{
+    scaling.output((centered + 1.0) * 0.5, config.output_min, config.output_max)
+}
+
+/// Zero within `width`, then scaled so full deflection stays 1.0.
+fn rescale(value: Float, width: Float) -> Float {
+    let magnitude = value.abs();
+    if magnitude <= width {
+        return 0.0;
+    }
+    let scaled = (magnitude - width) / (1.0 - width);
+    if value < 0.0 { -scaled } else { scaled }
+}
+
+#[cfg(all(feature = "std-math", not(feature = "f64")))]
+fn hypot(x: Float, y: Float) -> Float {
+    libm::hypotf(x, y)
+}
+
+#[cfg(all(feature = "std-math", feature = "f64"))]
+fn hypot(x: Float, y: Float) -> Float {
+    libm::hypot(x, y)
+}
+
+#[cfg(all(feature = "std-math", not(feature = "f64")))]
+fn atan2(y: Float, x: Float) -> Float {
+    libm::atan2f(y, x)
+}
+
+#[cfg(all(feature = "std-math", feature = "f64"))]
+fn atan2(y: Float, x: Float) -> Float {
+    libm::atan2(y, x)
+}
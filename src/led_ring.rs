@@ -0,0 +1,64 @@
+//! LED ring and bar indicators.
+//!
+//! `LedMode` turns a normalized value (e.g. `PotHead::last_output_normalized()`)
+//! into per-LED brightness for a ring or bar of N LEDs. The LED at the boundary
+//! gets fractional brightness, so the display moves smoothly with PWM dimming.
+
+use crate::float::Float;
+
+/// How the value is shown on the LEDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LedMode {
+    /// LEDs fill up from the first one (volume, level)
+    #[default]
+    Bar,
+
+    /// A single lit position, crossfading between neighbouring LEDs
+    Dot,
+
+    /// LEDs fill from the center towards the value (pan, balance, bipolar)
+    FromCenter,
+}
+
+impl LedMode {
+    /// Brightness (0.0..=1.0) of each of N LEDs for `position` (0.0..=1.0).
+    ///
+    /// A NaN position turns all LEDs off.
+    pub fn render<const N: usize>(&self, position: Float) -> [Float; N] {
+        let mut leds = [0.0; N];
+        self.render_into(position, &mut leds);
+        leds
+    }
+
+    /// Like `render()`, writing into a slice of any length.
+    pub fn render_into(&self, position: Float, leds: &mut [Float]) {
+        if position.is_nan() {
+            leds.fill(0.0);
+            return;
+        }
+
+        let count = leds.len() as Float;
+        let position = position.clamp(0.0, 1.0);
+
+        // Lit span in LED units: LED i covers [i, i + 1)
+        let (start, end) = match self {
+            LedMode::Bar => (0.0, position * count),
+            LedMode::Dot => {
+                let start = position * (count - 1.0).max(0.0);
+                (start, start + 1.0)
+            }
+            LedMode::FromCenter => {
+                let center = count * 0.5;
+                let value = position * count;
+                (center.min(value), center.max(value))
+            }
+        };
+
+        for (index, led) in leds.iter_mut().enumerate() {
+            let low = index as Float;
+            let overlap = end.min(low + 1.0) - start.max(low);
+            *led = if overlap > 0.0 { overlap.min(1.0) } else { 0.0 };
+        }
+    }
+}
@@ -1,23 +1,162 @@
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod async_sampler;
+mod bank;
+mod calibration;
 mod config;
+mod convert;
+mod crossfader;
 pub mod curves;
+mod dual;
+mod encoder;
+mod endless;
+mod esp_adc;
+mod events;
+mod fault;
 pub mod filters;
+mod float;
 pub mod hysteresis;
+mod input;
+mod joystick;
+mod led_ring;
+mod macro_control;
+mod midi;
+mod motion;
+mod multi_turn;
+mod mux;
+mod pedal;
+pub mod pipeline;
 mod pothead;
+mod push_pot;
+mod ribbon;
+mod scaling;
+mod servo;
 pub mod snap_zones;
+mod snapshot;
+mod split;
 mod state;
+pub mod static_pot;
+mod summary;
+
+#[cfg(feature = "no-float")]
+pub mod fixed;
 
 #[cfg(feature = "grab-mode")]
 pub mod grab_mode;
 
-pub use config::{Config, ConfigError};
+#[cfg(feature = "fine-adjust")]
+mod fine_adjust;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(any(feature = "wasm", feature = "python"))]
+mod export;
+
+#[cfg(feature = "noise-report")]
+mod noise;
+
+#[cfg(feature = "python")]
+mod python;
+
+#[cfg(feature = "queue-sink")]
+mod queue_sink;
+
+#[cfg(feature = "self-test")]
+mod self_test;
+
+#[cfg(feature = "usage-stats")]
+mod stats;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+#[cfg(feature = "tuning")]
+mod tuning;
+
+#[cfg(feature = "value-format")]
+mod value_format;
+
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use async_sampler::AsyncPotSampler;
+pub use bank::PotBank;
+pub use calibration::CalibrationData;
+#[doc(hidden)]
+pub use config::validate_normalized_ranges;
+pub use config::{Config, ConfigBuilder, ConfigError, ValidationErrors};
+pub use convert::{FromNormalized, IntoNormalized};
+pub use crossfader::{CrossfadeLaw, Crossfader};
 pub use curves::ResponseCurve;
+pub use dual::{DualPotHead, GangCombine};
+pub use encoder::{Acceleration, EncoderEdge, EncoderHead};
+pub use endless::EndlessPotHead;
+pub use esp_adc::EspAttenuation;
+pub use events::{UpdateEvent, UpdateEvents};
+pub use fault::Fault;
 pub use filters::NoiseFilter;
+pub use float::Float;
 pub use hysteresis::{HysteresisMode, HysteresisState, SchmittState};
+pub use input::InputError;
+pub use joystick::{JoystickDeadZone, JoystickHead};
+pub use led_ring::LedMode;
+pub use macro_control::MacroControl;
+pub use midi::{Cc14, CcMessages, MIDI14_MAX, Nrpn, split14};
+pub use motion::Direction;
+pub use multi_turn::MultiTurnPotHead;
+pub use mux::MuxScanner;
+pub use pedal::{ExpressionPedal, PedalProfile};
+pub use pipeline::StageOrder;
 pub use pothead::PotHead;
+pub use push_pot::{PressAction, PushPot, SwitchEvents, SwitchTiming};
+pub use ribbon::{RibbonHead, RibbonRelease, TouchEvent};
+pub use servo::FaderServo;
 pub use snap_zones::{SnapZone, SnapZoneType};
+pub use snapshot::{SnapshotError, StateSnapshot};
+pub use split::{OutputSlot, PotReader, PotSampler};
 pub use state::State;
+pub use static_pot::StaticPotHead;
+pub use summary::{ConfigSummary, ZoneSpan};
+
+#[cfg(feature = "fault-detection")]
+pub use fault::FaultDetection;
+
+#[cfg(feature = "fine-adjust")]
+pub use fine_adjust::FineAdjust;
+
+#[cfg(feature = "no-float")]
+pub use fixed::{FixedConfig, FixedCurve, FixedPotHead};
 
 #[cfg(feature = "grab-mode")]
-pub use grab_mode::GrabMode;
+pub use grab_mode::{GrabMode, ReleasePolicy};
+
+#[cfg(feature = "taps")]
+pub use pipeline::PipelineTaps;
+
+#[cfg(feature = "noise-report")]
+pub use noise::NoiseReport;
+
+#[cfg(feature = "python")]
+pub use python::{PyConfig, PyPotHead};
+
+#[cfg(feature = "queue-sink")]
+pub use queue_sink::QueueSink;
+
+#[cfg(feature = "self-test")]
+pub use self_test::SelfTestReport;
+
+#[cfg(feature = "usage-stats")]
+pub use stats::UsageStats;
+
+#[cfg(feature = "tuning")]
+pub use tuning::{FRAME_LEN, SYNC, TuningMessage, TuningServer, TuningStatus};
+
+#[cfg(feature = "value-format")]
+pub use value_format::{DisplayScale, ValueFormat};
+
+#[cfg(feature = "wasm")]
+pub use wasm::WasmPotHead;
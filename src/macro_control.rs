@@ -0,0 +1,85 @@
+//! One input driving several pots.
+//!
+//! `MacroControl` fans a single physical input out to N `PotHead`s, like a DAW
+//! macro knob. Each target has its own configuration: output range, curve,
+//! polarity (`invert_input`), and input range, so a target can respond to only
+//! part of the knob's travel.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::pothead::PotHead;
+
+/// N targets updated from one input.
+#[derive(Clone)]
+pub struct MacroControl<'a, const N: usize, TIn, TOut = TIn> {
+    targets: [PotHead<'a, TIn, TOut>; N],
+}
+
+impl<'a, const N: usize, TIn, TOut> MacroControl<'a, N, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Create a macro from individually built targets.
+    pub fn new(targets: [PotHead<'a, TIn, TOut>; N]) -> Self {
+        Self { targets }
+    }
+
+    /// Create a macro with one target per configuration.
+    pub fn from_configs(configs: [Config<'a, TIn, TOut>; N]) -> Result<Self, ConfigError> {
+        for config in &configs {
+            config.validate()?;
+        }
+        Ok(Self::new(configs.map(PotHead::from_validated)))
+    }
+
+    /// Process one sample and return every target's output.
+    pub fn update(&mut self, input: TIn) -> [TOut; N] {
+        for target in &mut self.targets {
+            target.update(input);
+        }
+        self.outputs()
+    }
+
+    /// Current output of every target, without processing a new sample.
+    pub fn outputs(&self) -> [TOut; N] {
+        self.targets.each_ref().map(|target| target.last_output())
+    }
+
+    /// Reset every target to the condition right after `new()`.
+    pub fn reset_all(&mut self) {
+        for target in &mut self.targets {
+            target.reset();
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&PotHead<'a, TIn, TOut>> {
+        self.targets.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut PotHead<'a, TIn, TOut>> {
+        self.targets.get_mut(index)
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, PotHead<'a, TIn, TOut>> {
+        self.targets.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, PotHead<'a, TIn, TOut>> {
+        self.targets.iter_mut()
+    }
+
+    /// Number of targets.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Give back the targets.
+    pub fn into_inner(self) -> [PotHead<'a, TIn, TOut>; N] {
+        self.targets
+    }
+}
@@ -0,0 +1,190 @@
+//! High-resolution MIDI output.
+//!
+//! Encoders that turn a 14-bit value (e.g. from a pot built with
+//! `output_midi14()`) into raw MIDI control change messages: 14-bit CC pairs
+//! (MSB on controller n, LSB on n + 32) and NRPN. They remember the last value
+//! sent, so only the bytes that changed go out.
+
+use crate::config::ConfigError;
+
+/// Largest 14-bit value.
+pub const MIDI14_MAX: u16 = 0x3FFF;
+
+const CONTROL_CHANGE: u8 = 0xB0;
+const NRPN_MSB: u8 = 99;
+const NRPN_LSB: u8 = 98;
+const DATA_ENTRY_MSB: u8 = 6;
+const DATA_ENTRY_LSB: u8 = 38;
+
+/// Split a 14-bit value into (MSB, LSB) 7-bit halves. Values above 16383 clamp.
+pub const fn split14(value: u16) -> (u8, u8) {
+    let value = if value > MIDI14_MAX {
+        MIDI14_MAX
+    } else {
+        value
+    };
+    ((value >> 7) as u8, (value & 0x7F) as u8)
+}
+
+/// Up to four raw 3-byte control change messages, in sending order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CcMessages {
+    messages: [[u8; 3]; 4],
+    len: u8,
+}
+
+impl CcMessages {
+    pub fn as_slice(&self) -> &[[u8; 3]] {
+        &self.messages[..self.len as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push(&mut self, channel: u8, controller: u8, value: u8) {
+        if let Some(slot) = self.messages.get_mut(self.len as usize) {
+            *slot = [CONTROL_CHANGE | channel, controller, value];
+            self.len += 1;
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a CcMessages {
+    type Item = &'a [u8; 3];
+    type IntoIter = core::slice::Iter<'a, [u8; 3]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+/// Data bytes to send for `value`: the MSB when it changed (then the LSB too,
+/// since receivers reset the LSB on a new MSB), otherwise the LSB when it changed.
+fn changed_halves(sent: &mut Option<(u8, u8)>, value: u16) -> (Option<u8>, Option<u8>) {
+    let (msb, lsb) = split14(value);
+    let data = match *sent {
+        Some((last_msb, _)) if last_msb != msb => (Some(msb), Some(lsb)),
+        Some((_, last_lsb)) if last_lsb != lsb => (None, Some(lsb)),
+        Some(_) => (None, None),
+        None => (Some(msb), Some(lsb)),
+    };
+    *sent = Some((msb, lsb));
+    data
+}
+
+/// 14-bit control change pair: MSB on `controller` (0..=31), LSB on `controller + 32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Cc14 {
+    channel: u8,
+    controller: u8,
+    sent: Option<(u8, u8)>,
+}
+
+impl Cc14 {
+    /// `channel` is 0..=15 (MIDI channels 1-16).
+    pub const fn new(channel: u8, controller: u8) -> Result<Self, ConfigError> {
+        if channel > 15 {
+            return Err(ConfigError::InvalidMidi {
+                reason: "channel must be in range 0..=15",
+            });
+        }
+        if controller > 31 {
+            return Err(ConfigError::InvalidMidi {
+                reason: "14-bit CC controller must be in range 0..=31",
+            });
+        }
+        Ok(Self {
+            channel,
+            controller,
+            sent: None,
+        })
+    }
+
+    /// Messages needed to send `value` (0..=16383), given what was sent before.
+    /// Empty if nothing changed.
+    pub fn encode(&mut self, value: u16) -> CcMessages {
+        let (msb, lsb) = changed_halves(&mut self.sent, value);
+
+        let mut messages = CcMessages::default();
+        if let Some(msb) = msb {
+            messages.push(self.channel, self.controller, msb);
+        }
+        if let Some(lsb) = lsb {
+            messages.push(self.channel, self.controller + 32, lsb);
+        }
+        messages
+    }
+
+    /// Forget the last value, so the next `encode()` sends both halves (e.g.
+    /// after the receiver reconnects).
+    pub fn resend(&mut self) {
+        self.sent = None;
+    }
+}
+
+/// NRPN parameter (0..=16383) with a 14-bit value.
+///
+/// Every update selects the parameter (CC 99/98) before the data entry bytes
+/// (CC 6/38), so several NRPN encoders can share a channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Nrpn {
+    channel: u8,
+    parameter: u16,
+    sent: Option<(u8, u8)>,
+}
+
+impl Nrpn {
+    /// `channel` is 0..=15 (MIDI channels 1-16).
+    pub const fn new(channel: u8, parameter: u16) -> Result<Self, ConfigError> {
+        if channel > 15 {
+            return Err(ConfigError::InvalidMidi {
+                reason: "channel must be in range 0..=15",
+            });
+        }
+        if parameter > MIDI14_MAX {
+            return Err(ConfigError::InvalidMidi {
+                reason: "NRPN parameter must be in range 0..=16383",
+            });
+        }
+        Ok(Self {
+            channel,
+            parameter,
+            sent: None,
+        })
+    }
+
+    /// Messages needed to send `value` (0..=16383), given what was sent before.
+    /// Empty if nothing changed.
+    pub fn encode(&mut self, value: u16) -> CcMessages {
+        let (msb, lsb) = changed_halves(&mut self.sent, value);
+
+        let mut messages = CcMessages::default();
+        if msb.is_none() && lsb.is_none() {
+            return messages;
+        }
+
+        let (parameter_msb, parameter_lsb) = split14(self.parameter);
+        messages.push(self.channel, NRPN_MSB, parameter_msb);
+        messages.push(self.channel, NRPN_LSB, parameter_lsb);
+        if let Some(msb) = msb {
+            messages.push(self.channel, DATA_ENTRY_MSB, msb);
+        }
+        if let Some(lsb) = lsb {
+            messages.push(self.channel, DATA_ENTRY_LSB, lsb);
+        }
+        messages
+    }
+
+    /// Forget the last value, so the next `encode()` sends both halves.
+    pub fn resend(&mut self) {
+        self.sent = None;
+    }
+}
@@ -0,0 +1,11 @@
+//! Movement direction reported by `PotHead::direction()`.
+
+/// Direction of pot movement during the last update.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Direction {
+    #[default]
+    Stationary,
+    Increasing,
+    Decreasing,
+}
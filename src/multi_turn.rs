@@ -0,0 +1,128 @@
+//! Multi-turn position sensing.
+//!
+//! `MultiTurnPotHead` is for sensors that report an angle within one revolution
+//! but turn several times over their travel (geared magnetic sensors, absolute
+//! encoders on a multi-turn knob). Turns are counted from wrap detection, or set
+//! explicitly, and the extended position runs through the full `PotHead`
+//! pipeline. The config's input range is in turns, e.g. `input_range(0.0, 10.0)`.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::endless::{shortest_path, wrap};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// Multi-turn processor counting revolutions of a single-turn angle reading.
+#[derive(Clone)]
+pub struct MultiTurnPotHead<'a, TIn, TOut = TIn> {
+    pot: PotHead<'a, Float, TOut>,
+    angle_min: TIn,
+    angle_max: TIn,
+    turns: i32,
+    last_angle: Option<Float>,
+}
+
+impl<'a, TIn, TOut> MultiTurnPotHead<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// `angle_range` is the raw reading over one revolution; `config` maps the
+    /// position in turns to the output. Counting starts at turn 0.
+    pub fn new(
+        angle_range: (TIn, TIn),
+        config: Config<'a, Float, TOut>,
+    ) -> Result<Self, ConfigError> {
+        let (angle_min, angle_max) = angle_range;
+        let min_f = angle_min.into_normalized();
+        let max_f = angle_max.into_normalized();
+        if min_f == max_f {
+            return Err(ConfigError::InvalidInputRange {
+                min: min_f,
+                max: max_f,
+            });
+        }
+
+        Ok(Self {
+            pot: PotHead::new(config)?,
+            angle_min,
+            angle_max,
+            turns: 0,
+            last_angle: None,
+        })
+    }
+
+    /// Process one angle reading, counting a turn whenever it wraps.
+    ///
+    /// A NaN or infinite reading is ignored and the last output returned.
+    pub fn update(&mut self, angle: TIn) -> TOut {
+        let Some(angle) = self.normalize_angle(angle) else {
+            return self.pot.last_output();
+        };
+
+        if let Some(last) = self.last_angle {
+            let moved = shortest_path(angle - last);
+            if last + moved >= 1.0 {
+                self.turns += 1;
+            } else if last + moved < 0.0 {
+                self.turns -= 1;
+            }
+        }
+        self.last_angle = Some(angle);
+
+        self.pot.update(self.turns as Float + angle)
+    }
+
+    /// Process one angle reading with the turn count from an external source
+    /// (e.g. a revolution counter or index pulse).
+    pub fn update_with_turn(&mut self, angle: TIn, turn: i32) -> TOut {
+        self.turns = turn;
+        self.last_angle = None;
+        self.update(angle)
+    }
+
+    /// Set the turn count, e.g. restored from flash at power-up, since a
+    /// single-turn sensor can't tell which turn it is on.
+    pub fn set_turns(&mut self, turns: i32) {
+        self.turns = turns;
+    }
+
+    /// Completed turns counted so far.
+    pub fn turns(&self) -> i32 {
+        self.turns
+    }
+
+    /// Position in turns from the last reading (turns plus angle), before processing.
+    pub fn position(&self) -> Float {
+        self.turns as Float + self.last_angle.unwrap_or(0.0)
+    }
+
+    pub fn last_output(&self) -> TOut {
+        self.pot.last_output()
+    }
+
+    pub fn config(&self) -> &Config<'a, Float, TOut> {
+        self.pot.config()
+    }
+
+    /// The underlying pipeline, for queries like `is_dirty()` or `velocity()`.
+    pub fn pot(&self) -> &PotHead<'a, Float, TOut> {
+        &self.pot
+    }
+
+    pub fn pot_mut(&mut self) -> &mut PotHead<'a, Float, TOut> {
+        &mut self.pot
+    }
+
+    /// Angle within the revolution (0.0..1.0), or `None` for NaN or infinity.
+    fn normalize_angle(&self, angle: TIn) -> Option<Float> {
+        let angle_f = angle.into_normalized();
+        if !angle_f.is_finite() {
+            return None;
+        }
+
+        let min_f = self.angle_min.into_normalized();
+        let max_f = self.angle_max.into_normalized();
+        Some(wrap((angle_f - min_f) / (max_f - min_f)))
+    }
+}
@@ -0,0 +1,100 @@
+//! Scanning pots behind an analog multiplexer (e.g. CD74HC4067) on one ADC pin.
+//!
+//! `MuxScanner` sequences the channels of a `PotBank`, discards samples taken
+//! while the mux output settles, and feeds the rest to the matching pot. Pin
+//! and ADC access stay with the caller, so it works with any HAL.
+
+use crate::bank::PotBank;
+use crate::convert::{FromNormalized, IntoNormalized};
+
+/// Channel sequencing and settling state machine for a mux of N pots.
+#[derive(Clone)]
+pub struct MuxScanner<'a, const N: usize, TIn, TOut = TIn> {
+    bank: PotBank<'a, N, TIn, TOut>,
+    channel: usize,
+    settle_samples: u16,
+    discard: u16,
+}
+
+impl<'a, const N: usize, TIn, TOut> MuxScanner<'a, N, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Scan `bank`, discarding `settle_samples` samples after every channel switch.
+    ///
+    /// Scanning starts at channel 0; select it on the mux before the first sample.
+    pub fn new(bank: PotBank<'a, N, TIn, TOut>, settle_samples: u16) -> Self {
+        Self {
+            bank,
+            channel: 0,
+            settle_samples,
+            discard: settle_samples,
+        }
+    }
+
+    /// Channel the mux should currently be set to.
+    pub fn channel(&self) -> usize {
+        self.channel
+    }
+
+    /// Feed one ADC sample taken with the mux set to `channel()`.
+    ///
+    /// Returns the channel and its new output once a settled sample has been
+    /// processed; the scanner then moves on, so switch the mux to the new
+    /// `channel()` before the next sample. Returns `None` while settling.
+    pub fn sample(&mut self, input: TIn) -> Option<(usize, TOut)> {
+        if self.discard > 0 {
+            self.discard -= 1;
+            return None;
+        }
+
+        let channel = self.channel;
+        let output = self.bank.update(channel, input)?;
+
+        self.channel = if channel + 1 < N { channel + 1 } else { 0 };
+        self.discard = self.settle_samples;
+
+        Some((channel, output))
+    }
+
+    /// Run one complete scan using callbacks and return all outputs.
+    ///
+    /// For each channel: `select` sets the mux pins, `settle` waits (e.g. a few
+    /// microseconds of delay), then `settle_samples` reads are discarded before
+    /// the one that is used. Updates the bank's changed mask like `update_all()`.
+    pub fn scan(
+        &mut self,
+        mut select: impl FnMut(usize),
+        mut settle: impl FnMut(),
+        mut read: impl FnMut() -> TIn,
+    ) -> [TOut; N] {
+        let inputs = core::array::from_fn(|channel| {
+            select(channel);
+            settle();
+            for _ in 0..self.settle_samples {
+                read();
+            }
+            read()
+        });
+
+        // Leave the incremental state machine at the start of a new pass
+        self.channel = 0;
+        self.discard = self.settle_samples;
+
+        self.bank.update_all(&inputs)
+    }
+
+    pub fn bank(&self) -> &PotBank<'a, N, TIn, TOut> {
+        &self.bank
+    }
+
+    pub fn bank_mut(&mut self) -> &mut PotBank<'a, N, TIn, TOut> {
+        &mut self.bank
+    }
+
+    /// Give back the bank.
+    pub fn into_inner(self) -> PotBank<'a, N, TIn, TOut> {
+        self.bank
+    }
+}
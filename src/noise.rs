@@ -0,0 +1,98 @@
+//! Input noise measurement.
+//!
+//! `PotHead::noise_report()` summarizes the jitter of the normalized input
+//! over fixed windows of samples, so production test can check pot wiring and
+//! field diagnostics can compare channels against spec.
+
+use crate::float::Float;
+
+/// Jitter of the normalized input (before filtering) over one window.
+///
+/// Values are fractions of the input range; multiply by the range span for ADC
+/// counts. Movement during the window counts as noise, so measure with the pot
+/// at rest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoiseReport {
+    /// Largest minus smallest sample
+    pub peak_to_peak: Float,
+
+    /// Standard deviation around the window mean
+    pub rms: Float,
+
+    /// Number of samples in the window
+    pub samples: u16,
+}
+
+/// Running statistics for the current window (Welford's algorithm, which
+/// stays accurate for tiny jitter around a large mean).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NoiseMeter {
+    /// Samples per window, 0 = off
+    pub window: u16,
+
+    count: u16,
+    mean: Float,
+    m2: Float,
+    min: Float,
+    max: Float,
+
+    /// Last completed window
+    pub report: Option<NoiseReport>,
+}
+
+impl NoiseMeter {
+    pub const DEFAULT_WINDOW: u16 = 64;
+
+    pub const fn new(window: u16) -> Self {
+        Self {
+            window,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: 0.0,
+            max: 0.0,
+            report: None,
+        }
+    }
+
+    pub fn add(&mut self, value: Float) {
+        if self.window == 0 {
+            return;
+        }
+
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as Float;
+        self.m2 += delta * (value - self.mean);
+
+        if self.count >= self.window {
+            self.report = Some(NoiseReport {
+                peak_to_peak: self.max - self.min,
+                rms: sqrt(self.m2 / self.count as Float),
+                samples: self.count,
+            });
+            self.count = 0;
+            self.mean = 0.0;
+            self.m2 = 0.0;
+        }
+    }
+}
+
+#[cfg(not(feature = "f64"))]
+fn sqrt(x: Float) -> Float {
+    libm::sqrtf(x)
+}
+
+#[cfg(feature = "f64")]
+fn sqrt(x: Float) -> Float {
+    libm::sqrt(x)
+}
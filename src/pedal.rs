@@ -0,0 +1,234 @@
+//! Expression pedals.
+//!
+//! `ExpressionPedal` wraps a `PotHead` for pedals that rarely reach the
+//! electrical extremes of their pot. The heel and toe readings are calibrated
+//! (set directly or learned by rocking the pedal), and the processed input range
+//! is derived from them with a dead zone at the heel and a plateau at the toe, so
+//! both ends are reached reliably.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// Distance from the heel (normalized) that still counts as "at the heel" for
+/// spring return detection.
+const HEEL_ZONE: Float = 0.01;
+
+/// Pedal-specific shaping around the calibrated heel and toe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PedalProfile {
+    /// Fraction of the travel at the heel that outputs the minimum
+    pub heel_dead_zone: Float,
+
+    /// Fraction of the travel at the toe that outputs the maximum
+    pub toe_plateau: Float,
+
+    /// Falling speed (normalized travel per update) into the heel that counts as
+    /// a spring return rather than the foot easing back
+    pub return_speed: Float,
+}
+
+impl PedalProfile {
+    /// 2% heel dead zone, 5% toe plateau, spring return when falling faster than
+    /// 5% of the travel per update.
+    pub const DEFAULT: Self = Self {
+        heel_dead_zone: 0.02,
+        toe_plateau: 0.05,
+        return_speed: 0.05,
+    };
+
+    pub const fn validate(&self) -> Result<(), ConfigError> {
+        let reason = if !(self.heel_dead_zone >= 0.0 && self.heel_dead_zone < 1.0) {
+            "heel_dead_zone must be in range [0.0, 1.0)"
+        } else if !(self.toe_plateau >= 0.0 && self.toe_plateau < 1.0) {
+            "toe_plateau must be in range [0.0, 1.0)"
+        } else if self.heel_dead_zone + self.toe_plateau >= 1.0 {
+            "heel_dead_zone + toe_plateau must be < 1.0"
+        } else if self.return_speed.is_nan() || self.return_speed <= 0.0 {
+            "return_speed must be > 0.0"
+        } else {
+            return Ok(());
+        };
+        Err(ConfigError::InvalidPedal { reason })
+    }
+}
+
+impl Default for PedalProfile {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Expression pedal with heel/toe calibration and spring return detection.
+#[derive(Clone)]
+pub struct ExpressionPedal<'a, TIn, TOut = TIn> {
+    pot: PotHead<'a, TIn, TOut>,
+    profile: PedalProfile,
+    heel: TIn,
+    toe: TIn,
+    learning: Option<Option<(TIn, TIn)>>,
+    fall_speed: Float,
+    at_heel: bool,
+    returned: bool,
+}
+
+impl<'a, TIn, TOut> ExpressionPedal<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized + FromNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// The config's input range is the initial heel and toe calibration, e.g. the
+    /// full ADC range. The other settings (filter, curve, hysteresis, ...) apply
+    /// as usual.
+    pub fn new(config: Config<'a, TIn, TOut>, profile: PedalProfile) -> Result<Self, ConfigError> {
+        profile.validate()?;
+
+        let mut pedal = Self {
+            pot: PotHead::new(config)?,
+            profile,
+            heel: config.input_min,
+            toe: config.input_max,
+            learning: None,
+            fall_speed: 0.0,
+            at_heel: true,
+            returned: false,
+        };
+        pedal.calibrate(config.input_min, config.input_max)?;
+
+        Ok(pedal)
+    }
+
+    /// Process one pedal reading.
+    pub fn update(&mut self, input: TIn) -> TOut {
+        if let Some(learned) = self.learning.as_mut() {
+            let input_f = input.into_normalized();
+            if !input_f.is_nan() {
+                *learned = Some(match *learned {
+                    None => (input, input),
+                    Some((min, max)) if input < min => (input, max),
+                    Some((min, max)) if input > max => (min, input),
+                    Some(range) => range,
+                });
+            }
+        }
+
+        let output = self.pot.update(input);
+        self.detect_return();
+        output
+    }
+
+    /// Set the heel and toe readings directly, e.g. restored from flash.
+    ///
+    /// On error the previous calibration is kept.
+    pub fn calibrate(&mut self, heel: TIn, toe: TIn) -> Result<(), ConfigError> {
+        let heel_f = heel.into_normalized();
+        let toe_f = toe.into_normalized();
+        let travel = toe_f - heel_f;
+
+        let min = TIn::from_normalized(heel_f + travel * self.profile.heel_dead_zone);
+        let max = TIn::from_normalized(toe_f - travel * self.profile.toe_plateau);
+        self.pot.reconfigure(|config| {
+            config.input_min = min;
+            config.input_max = max;
+        })?;
+
+        self.heel = heel;
+        self.toe = toe;
+        Ok(())
+    }
+
+    /// Calibrated (heel, toe) readings.
+    pub fn calibration(&self) -> (TIn, TIn) {
+        (self.heel, self.toe)
+    }
+
+    /// Start learning the heel and toe: rock the pedal through its full travel,
+    /// then call `finish_learning()`. Processing continues with the current
+    /// calibration meanwhile.
+    pub fn start_learning(&mut self) {
+        self.learning = Some(None);
+    }
+
+    pub fn is_learning(&self) -> bool {
+        self.learning.is_some()
+    }
+
+    /// Apply the learned range, keeping the heel on the same side as before.
+    ///
+    /// Fails with `InvalidInputRange` if the pedal didn't move. Learning stops
+    /// either way; on error the previous calibration is kept.
+    pub fn finish_learning(&mut self) -> Result<(), ConfigError> {
+        let Some((min, max)) = self.learning.take().flatten() else {
+            return Err(ConfigError::InvalidInputRange { min: 0.0, max: 0.0 });
+        };
+
+        if self.heel < self.toe {
+            self.calibrate(min, max)
+        } else {
+            self.calibrate(max, min)
+        }
+    }
+
+    /// Returns true if the pedal sprang back to the heel during the last update
+    /// (released under spring return), as opposed to being eased back by foot.
+    pub fn returned(&self) -> bool {
+        self.returned
+    }
+
+    /// Returns true if the pedal is resting at the heel.
+    pub fn is_at_heel(&self) -> bool {
+        self.at_heel
+    }
+
+    pub fn profile(&self) -> PedalProfile {
+        self.profile
+    }
+
+    /// Change the profile with validation, recomputing the processed range from
+    /// the current calibration. On error the previous profile is kept.
+    pub fn set_profile(&mut self, profile: PedalProfile) -> Result<(), ConfigError> {
+        profile.validate()?;
+
+        let previous = self.profile;
+        self.profile = profile;
+        if let Err(e) = self.calibrate(self.heel, self.toe) {
+            self.profile = previous;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    pub fn last_output(&self) -> TOut {
+        self.pot.last_output()
+    }
+
+    pub fn config(&self) -> &Config<'a, TIn, TOut> {
+        self.pot.config()
+    }
+
+    pub fn pot(&self) -> &PotHead<'a, TIn, TOut> {
+        &self.pot
+    }
+
+    pub fn pot_mut(&mut self) -> &mut PotHead<'a, TIn, TOut> {
+        &mut self.pot
+    }
+
+    fn detect_return(&mut self) {
+        let velocity = self.pot.velocity();
+        if velocity > 0.0 {
+            self.fall_speed = 0.0;
+        } else {
+            self.fall_speed = self.fall_speed.max(-velocity);
+        }
+
+        let at_heel = self.pot.filtered_position() <= HEEL_ZONE;
+        self.returned = at_heel && !self.at_heel && self.fall_speed >= self.profile.return_speed;
+        if at_heel {
+            self.fall_speed = 0.0;
+        }
+        self.at_heel = at_heel;
+    }
+}
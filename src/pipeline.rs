@@ -0,0 +1,54 @@
+//! Processing stage ordering.
+//!
+//! Normalization and filtering always come first, grab mode always comes last.
+//! The order of the curve, hysteresis and snap zone stages is configurable.
+
+/// Order of the curve, hysteresis and snap zone stages.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum StageOrder {
+    /// Curve → Hysteresis → Snap Zones.
+    /// Thresholds and zones are in curved (output) terms.
+    #[default]
+    CurveFirst,
+
+    /// Hysteresis → Curve → Snap Zones.
+    /// Hysteresis threshold is in physical terms, zones in curved terms.
+    HysteresisFirst,
+
+    /// Hysteresis → Snap Zones → Curve.
+    /// Thresholds and zones are in physical (pot travel) terms.
+    PhysicalFirst,
+}
+
+/// Value after each pipeline stage from the last update, all normalized.
+///
+/// Stage values follow the configured `StageOrder`, e.g. with `PhysicalFirst`
+/// `snapped` is taken before the curve. Requires `taps` feature.
+#[cfg(feature = "taps")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PipelineTaps<T> {
+    /// After normalization (and inversion)
+    pub normalized: T,
+    /// After the noise filter
+    pub filtered: T,
+    /// After the response curve
+    pub curved: T,
+    /// After hysteresis
+    pub hysteresis: T,
+    /// After snap zones
+    pub snapped: T,
+    /// After grab mode (final normalized output)
+    pub output: T,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_order_default() {
+        assert_eq!(StageOrder::default(), StageOrder::CurveFirst);
+    }
+}
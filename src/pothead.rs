@@ -1,74 +1,710 @@
-use num_traits::AsPrimitive;
-
+use crate::calibration::CalibrationData;
 use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::curves::ResponseCurve;
+use crate::events::UpdateEvents;
 use crate::filters::NoiseFilter;
+use crate::float::Float;
+use crate::hysteresis::{HysteresisMode, HysteresisState};
+use crate::input::InputError;
+use crate::motion::Direction;
+use crate::pipeline::StageOrder;
+use crate::scaling::{InputScaling, OutputScaling};
+use crate::snapshot::StateSnapshot;
 use crate::state::State;
 
 #[cfg(feature = "grab-mode")]
-use crate::grab_mode::GrabMode;
+use crate::grab_mode::{GrabMode, ReleasePolicy};
+
+#[cfg(feature = "calibration")]
+use crate::calibration::Calibration;
+
+#[cfg(feature = "fault-detection")]
+use crate::fault::{Fault, FaultDetection, FaultState};
+
+#[cfg(feature = "noise-report")]
+use crate::noise::{NoiseMeter, NoiseReport};
+
+#[cfg(feature = "self-test")]
+use crate::self_test::{SelfTest, SelfTestReport};
+
+#[cfg(feature = "usage-stats")]
+use crate::stats::{UsageStats, UsageTracker};
+
+#[cfg(feature = "taps")]
+use crate::pipeline::PipelineTaps;
+
+#[cfg(feature = "embassy-time")]
+use embassy_time::{Duration, Instant};
 
 use crate::filters::EmaFilter;
 
 #[cfg(feature = "moving-average")]
 use crate::filters::MovingAvgFilter;
 
-pub struct PotHead<TIn, TOut = TIn> {
-    config: Config<TIn, TOut>,
-    state: State<f32>,
+/// Callbacks read by `PotHead::callbacks()` before an update, with the input
+/// range the range callback compares against.
+#[derive(Clone, Copy)]
+pub(crate) struct Callbacks<TIn, TOut> {
+    on_change: Option<fn(TOut)>,
+
+    #[cfg(feature = "auto-range")]
+    on_range_change: Option<fn((TIn, TIn))>,
+
+    #[cfg(feature = "auto-range")]
+    range: (TIn, TIn),
+
+    #[cfg(not(feature = "auto-range"))]
+    _input: core::marker::PhantomData<TIn>,
 }
 
-impl<TIn, TOut> PotHead<TIn, TOut>
+/// Cloning copies the configuration and all processing state, e.g. to copy
+/// settings to another channel or snapshot a pot in tests.
+#[derive(Clone)]
+pub struct PotHead<'a, TIn, TOut = TIn> {
+    config: Config<'a, TIn, TOut>,
+    state: State<Float>,
+    on_change: Option<fn(TOut)>,
+
+    /// Range reciprocals derived from `config`, refreshed whenever it changes
+    input_scaling: InputScaling,
+    output_scaling: OutputScaling,
+
+    #[cfg(feature = "observed-range")]
+    observed: Option<(TIn, TIn)>,
+
+    /// Widen the input range to include every sample, and who to tell
+    #[cfg(feature = "auto-range")]
+    auto_range: bool,
+    #[cfg(feature = "auto-range")]
+    on_range_change: Option<fn((TIn, TIn))>,
+
+    /// Guided calibration session, `None` when not calibrating
+    #[cfg(feature = "calibration")]
+    calibration_session: Option<Calibration<TIn>>,
+
+    #[cfg(feature = "fault-detection")]
+    fault_detection: Option<FaultDetection<TIn>>,
+    #[cfg(feature = "fault-detection")]
+    fault_state: FaultState,
+
+    #[cfg(feature = "noise-report")]
+    noise: NoiseMeter,
+
+    #[cfg(feature = "usage-stats")]
+    usage: UsageTracker,
+
+    /// Synthetic sweep in progress, `None` when not self-testing
+    #[cfg(feature = "self-test")]
+    self_test: Option<SelfTest>,
+
+    #[cfg(feature = "ratiometric")]
+    reference: Reference,
+
+    /// Trace every n-th update (0 = off) and updates since the last trace
+    #[cfg(feature = "defmt-trace")]
+    trace_interval: u16,
+    #[cfg(feature = "defmt-trace")]
+    trace_count: u16,
+
+    #[cfg(feature = "embassy-time")]
+    timing: Timing,
+}
+
+/// Supply reference compensation for `set_reference()`.
+#[cfg(feature = "ratiometric")]
+#[derive(Clone, Copy)]
+struct Reference {
+    /// Reference reading the input range was calibrated at
+    nominal: Option<Float>,
+
+    /// Input multiplier: nominal over the latest reading
+    scale: Float,
+}
+
+/// Timestamps for `update_at()`.
+#[cfg(feature = "embassy-time")]
+#[derive(Clone, Copy)]
+struct Timing {
+    /// Time of the last `update_at()`
+    last_update: Option<Instant>,
+
+    /// Time the output last changed during `update_at()`
+    changed_at: Option<Instant>,
+
+    /// Slew step multiplier: elapsed seconds during `update_at()`, else 1.0
+    slew_scale: Float,
+}
+
+impl<TIn, TOut> PotHead<'_, TIn, TOut> {
+    /// Total RAM for one `PotHead`, in bytes: configuration, processing state and
+    /// callback. Snap zone and values tables are borrowed and not included.
+    ///
+    /// ```ignore
+    /// const _: () = assert!(16 * PotHead::<u16, u8>::SIZE_BYTES <= 4096);
+    /// ```
+    pub const SIZE_BYTES: usize = size_of::<Self>();
+}
+
+/// Prints the config summary and the current processing state.
+impl<TIn, TOut> core::fmt::Debug for PotHead<'_, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PotHead")
+            .field("config", &self.config.describe())
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+/// Prints the config summary and the current output.
+impl<TIn, TOut> core::fmt::Display for PotHead<'_, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized + core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}, output: {}", self.config, self.last_output())
+    }
+}
+
+impl<'a, TIn, TOut> PotHead<'a, TIn, TOut>
 where
-    TIn: Copy + PartialOrd + AsPrimitive<f32>,
-    TOut: Copy + PartialOrd + AsPrimitive<f32>,
-    f32: AsPrimitive<TOut>,
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
 {
-    pub fn new(config: Config<TIn, TOut>) -> Result<Self, ConfigError> {
+    pub fn new(config: Config<'a, TIn, TOut>) -> Result<Self, ConfigError> {
+        config.validate()?;
+        Ok(Self::from_validated(config))
+    }
+
+    /// Build a pot from a configuration that already passed `validate()`.
+    pub(crate) fn from_validated(config: Config<'a, TIn, TOut>) -> Self {
+        let mut pot = Self {
+            config,
+            state: State::default(),
+            on_change: None,
+            input_scaling: InputScaling::new(&config),
+            output_scaling: OutputScaling::new(&config),
+            #[cfg(feature = "observed-range")]
+            observed: None,
+            #[cfg(feature = "auto-range")]
+            auto_range: false,
+            #[cfg(feature = "auto-range")]
+            on_range_change: None,
+            #[cfg(feature = "calibration")]
+            calibration_session: None,
+            #[cfg(feature = "fault-detection")]
+            fault_detection: None,
+            #[cfg(feature = "fault-detection")]
+            fault_state: FaultState::default(),
+            #[cfg(feature = "noise-report")]
+            noise: NoiseMeter::new(NoiseMeter::DEFAULT_WINDOW),
+            #[cfg(feature = "usage-stats")]
+            usage: UsageTracker::default(),
+            #[cfg(feature = "self-test")]
+            self_test: None,
+            #[cfg(feature = "ratiometric")]
+            reference: Reference {
+                nominal: None,
+                scale: 1.0,
+            },
+            #[cfg(feature = "defmt-trace")]
+            trace_interval: 1,
+            #[cfg(feature = "defmt-trace")]
+            trace_count: 0,
+            #[cfg(feature = "embassy-time")]
+            timing: Timing {
+                last_update: None,
+                changed_at: None,
+                slew_scale: 1.0,
+            },
+        };
+        pot.init_filter_state();
+        pot
+    }
+
+    pub fn config(&self) -> &Config<'a, TIn, TOut> {
+        &self.config
+    }
+
+    /// Most recent output, without processing a new sample.
+    ///
+    /// Returns `output_min` before the first `update()`.
+    pub fn last_output(&self) -> TOut {
+        self.output_value(self.state.last_output)
+    }
+
+    /// Most recent output in normalized 0.0..1.0 range.
+    pub fn last_output_normalized(&self) -> Float {
+        self.state.last_output
+    }
+
+    /// Register a callback invoked from `update()` whenever the output changes.
+    ///
+    /// Pass `None` to remove it. Not invoked by `set_output()` or `reset()`.
+    /// The callback runs inside `update()`, so it must not panic if `update()`
+    /// has to stay panic-free (see `tests/no_panic.rs`).
+    pub fn set_on_change(&mut self, on_change: Option<fn(TOut)>) {
+        self.on_change = on_change;
+    }
+
+    /// Force the output to an externally set value (e.g. from a remote app).
+    ///
+    /// Seeds the last output, dead zone hold and hysteresis reference so small pot
+    /// movements don't immediately override it. With grab mode enabled this also
+    /// sets the virtual value, like `set_virtual_value()`.
+    ///
+    /// A value that converts to NaN is ignored.
+    pub fn set_output(&mut self, value: TOut) {
+        let normalized = self.normalize_output(value);
+        if normalized.is_nan() {
+            return;
+        }
+        self.seed_output(normalized);
+    }
+
+    /// Capture the state worth persisting across power cycles.
+    pub fn save_state(&self) -> StateSnapshot<TIn> {
+        #[cfg(feature = "grab-mode")]
+        let (virtual_value, grabbed) = (self.state.virtual_value, self.state.grabbed);
+
+        #[cfg(not(feature = "grab-mode"))]
+        let (virtual_value, grabbed) = (self.state.last_output, true);
+
+        StateSnapshot {
+            calibration: self.config.calibration(),
+            output: self.state.last_output,
+            filtered: self.state.last_filtered,
+            virtual_value,
+            grabbed,
+        }
+    }
+
+    /// Restore a snapshot from `save_state()`, e.g. at power-up.
+    ///
+    /// Applies the calibration, seeds the filter at the saved position and the
+    /// output like `set_output()`, then restores the grab state. Other state is
+    /// reset. Fails if the calibration is invalid or a value is NaN, leaving the
+    /// pot unchanged.
+    pub fn restore_state(&mut self, snapshot: &StateSnapshot<TIn>) -> Result<(), ConfigError> {
+        if snapshot.output.is_nan()
+            || snapshot.virtual_value.is_nan()
+            || snapshot.filtered.is_some_and(Float::is_nan)
+        {
+            return Err(ConfigError::InvalidSnapshot);
+        }
+
+        self.apply_calibration(&snapshot.calibration)?;
+        self.reset();
+
+        if let Some(filtered) = snapshot.filtered {
+            let filtered = filtered.clamp(0.0, 1.0);
+            self.apply_filter(filtered);
+            self.state.last_filtered = Some(filtered);
+        }
+        self.seed_output(snapshot.output.clamp(0.0, 1.0));
+
+        #[cfg(feature = "grab-mode")]
+        {
+            self.state.virtual_value = snapshot.virtual_value.clamp(0.0, 1.0);
+            self.state.grabbed = snapshot.grabbed;
+        }
+
+        Ok(())
+    }
+
+    /// Seed the output and the references derived from it (see `set_output()`).
+    fn seed_output(&mut self, normalized: Float) {
+        let pre_curve = self.config.curve.inverse(normalized);
+
+        let hysteresis_ref = match self.config.stage_order {
+            StageOrder::CurveFirst => normalized,
+            StageOrder::HysteresisFirst | StageOrder::PhysicalFirst => pre_curve,
+        };
+        self.config
+            .hysteresis
+            .seed(hysteresis_ref, &mut self.state.hysteresis);
+
+        self.state.last_snapped = pre_curve;
+        self.state.last_target = normalized;
+        self.state.slewed = Some(normalized);
+        self.state.value_index = None;
+        self.state.last_output = self.apply_values(normalized);
+
+        // Fine adjustment continues from here once the knob has a reference
+        #[cfg(feature = "fine-adjust")]
+        {
+            self.state.fine_position = normalized;
+        }
+
+        #[cfg(feature = "grab-mode")]
+        self.set_virtual_value(normalized);
+    }
+
+    /// Value after each pipeline stage from the last update.
+    /// Requires `taps` feature.
+    #[cfg(feature = "taps")]
+    pub fn taps(&self) -> PipelineTaps<Float> {
+        self.state.taps
+    }
+
+    /// Replace the configuration at runtime, preserving state where compatible.
+    ///
+    /// Filter state is kept when the filter type (and moving average window) and
+    /// input range are unchanged. Hysteresis state is kept when the hysteresis type
+    /// response curve and stage order are unchanged. Grab mode state is always kept,
+    /// fine adjustment state while fine adjustment stays enabled.
+    pub fn set_config(&mut self, config: Config<'a, TIn, TOut>) -> Result<(), ConfigError> {
         config.validate()?;
 
-        let mut state = State::default();
+        let same_input_range = config.input_min == self.config.input_min
+            && config.input_max == self.config.input_max
+            && config.input_center == self.config.input_center
+            && config.end_margin == self.config.end_margin;
 
-        // Initialize filter state based on configuration
-        if matches!(config.filter, NoiseFilter::ExponentialMovingAverage { .. }) {
-            state.ema_filter = Some(EmaFilter::new());
+        let same_filter = match (self.config.filter, config.filter) {
+            (NoiseFilter::None, NoiseFilter::None) => true,
+            (
+                NoiseFilter::ExponentialMovingAverage { .. },
+                NoiseFilter::ExponentialMovingAverage { .. },
+            ) => true,
+            #[cfg(feature = "moving-average")]
+            (
+                NoiseFilter::MovingAverage { window_size: old },
+                NoiseFilter::MovingAverage { window_size: new },
+            ) => old == new,
+            _ => false,
+        };
+
+        let same_hysteresis = core::mem::discriminant(&self.config.hysteresis)
+            == core::mem::discriminant(&config.hysteresis)
+            && self.config.curve == config.curve
+            && self.config.stage_order == config.stage_order;
+
+        let old_snap_zones = self.config.snap_zones;
+        let old_values = self.config.values;
+        self.config = config;
+        self.input_scaling = InputScaling::new(&self.config);
+        self.output_scaling = OutputScaling::new(&self.config);
+
+        if !(same_input_range && same_filter) {
+            self.init_filter_state();
         }
 
-        #[cfg(feature = "moving-average")]
-        if let NoiseFilter::MovingAverage { window_size } = config.filter {
-            state.ma_filter = Some(MovingAvgFilter::new(window_size));
+        if !same_hysteresis {
+            self.state.hysteresis = HysteresisState::default();
+        }
+
+        if self.config.snap_zones != old_snap_zones {
+            self.state.active_zone = None;
+        }
+
+        if self.config.values != old_values {
+            self.state.value_index = None;
         }
 
-        Ok(Self { config, state })
+        // Disabled fine adjustment stops tracking; start afresh when re-enabled
+        #[cfg(feature = "fine-adjust")]
+        if self.config.fine_adjust.is_none() {
+            self.state.fine_last = None;
+        }
+
+        Ok(())
     }
 
-    pub fn config(&self) -> &Config<TIn, TOut> {
-        &self.config
+    /// Modify the configuration in place, e.g. from a settings menu.
+    ///
+    /// The modified configuration is validated and applied via `set_config()`.
+    /// On error, the current configuration is left unchanged.
+    pub fn reconfigure<F>(&mut self, f: F) -> Result<(), ConfigError>
+    where
+        F: FnOnce(&mut Config<'a, TIn, TOut>),
+    {
+        let mut config = self.config;
+        f(&mut config);
+        self.set_config(config)
     }
 
+    /// Change the response curve. Resets hysteresis state if the curve differs.
+    pub fn set_curve(&mut self, curve: ResponseCurve) {
+        if self.config.curve != curve {
+            self.config.curve = curve;
+            self.state.hysteresis = HysteresisState::default();
+        }
+    }
+
+    /// Change the noise filter with validation.
+    ///
+    /// Filter history is kept when only the parameters change (e.g. EMA alpha).
+    pub fn set_filter(&mut self, filter: NoiseFilter) -> Result<(), ConfigError> {
+        self.reconfigure(|c| c.filter = filter)
+    }
+
+    /// Change the hysteresis mode with validation.
+    ///
+    /// Hysteresis state is kept when only the parameters change (e.g. threshold).
+    pub fn set_hysteresis(&mut self, hysteresis: HysteresisMode<Float>) -> Result<(), ConfigError> {
+        self.reconfigure(|c| c.hysteresis = hysteresis)
+    }
+
+    /// Apply a stored calibration (input range, center and inversion) with
+    /// validation, e.g. at power-up.
+    ///
+    /// Filter state is reset if the range or center changed.
+    pub fn apply_calibration(
+        &mut self,
+        calibration: &CalibrationData<TIn>,
+    ) -> Result<(), ConfigError> {
+        self.reconfigure(|config| calibration.apply(config))
+    }
+
+    /// Change the output range with validation, e.g. to limit volume to 80%.
+    ///
+    /// All processing state is kept; the next output is rescaled to the new range.
+    pub fn set_output_range(&mut self, min: TOut, max: TOut) -> Result<(), ConfigError> {
+        self.reconfigure(|c| {
+            c.output_min = min;
+            c.output_max = max;
+        })
+    }
+
+    /// Reset all processing state to the condition right after `new()`.
+    ///
+    /// Clears filter history, hysteresis, dead zone hold and grab state, e.g. after
+    /// waking from standby so stale smoothing doesn't crawl towards the real value.
+    pub fn reset(&mut self) {
+        self.state = State::default();
+        self.init_filter_state();
+
+        #[cfg(feature = "fault-detection")]
+        {
+            self.fault_state = FaultState::default();
+        }
+
+        #[cfg(feature = "embassy-time")]
+        {
+            self.timing.last_update = None;
+            self.timing.changed_at = None;
+        }
+    }
+
+    /// (Re)create filter state from the current configuration.
+    fn init_filter_state(&mut self) {
+        self.state.ema_filter = match self.config.filter {
+            NoiseFilter::ExponentialMovingAverage { .. } => Some(EmaFilter::new()),
+            _ => None,
+        };
+
+        #[cfg(feature = "moving-average")]
+        {
+            self.state.ma_filter = match self.config.filter {
+                NoiseFilter::MovingAverage { window_size } => {
+                    Some(MovingAvgFilter::new(window_size))
+                }
+                _ => None,
+            };
+        }
+    }
+
+    /// Process one input sample and return the output.
+    ///
+    /// Out-of-range inputs (including infinities) are clamped. A NaN input is
+    /// ignored: state is left untouched and the last output is returned, so one
+    /// failed conversion can't poison the filters. Use `try_update()` to detect it.
+    #[inline]
     pub fn update(&mut self, input: TIn) -> TOut {
+        let callbacks = self.callbacks();
+        let (output, changed) = self.process(input);
+        self.notify(callbacks, output, changed);
+        output
+    }
+
+    /// `update()` without the callbacks: the output and whether it changed.
+    fn process(&mut self, input: TIn) -> (TOut, bool) {
+        let Some(input_f) = self.prepare_input(input) else {
+            return (self.last_output(), false);
+        };
+
         // Normalize input to 0.0..1.0
-        let normalized = self.normalize_input(input);
+        let normalized = self.input_scaling.normalize(input_f);
+
+        self.process_normalized(input_f, normalized)
+    }
+
+    /// The callbacks to run once an update is done, read before it starts.
+    ///
+    /// Reading them first means a caller that sets them just before
+    /// `update()` lets the optimizer see which functions run, as
+    /// `tests/no_panic.rs` relies on.
+    #[inline]
+    pub(crate) fn callbacks(&self) -> Callbacks<TIn, TOut> {
+        Callbacks {
+            on_change: self.on_change,
+
+            #[cfg(feature = "auto-range")]
+            on_range_change: self.on_range_change,
+
+            #[cfg(feature = "auto-range")]
+            range: self.input_range(),
+
+            #[cfg(not(feature = "auto-range"))]
+            _input: core::marker::PhantomData,
+        }
+    }
+
+    /// Run `callbacks` for an update that produced `output`.
+    #[inline]
+    pub(crate) fn notify(&self, callbacks: Callbacks<TIn, TOut>, output: TOut, changed: bool) {
+        #[cfg(feature = "auto-range")]
+        if let Some(on_range_change) = callbacks.on_range_change {
+            let range = self.input_range();
+            if range != callbacks.range {
+                on_range_change(range);
+            }
+        }
+
+        if changed && let Some(on_change) = callbacks.on_change {
+            on_change(output);
+        }
+    }
+
+    /// Input range constants, for normalizing outside `update()`.
+    #[cfg(feature = "simd")]
+    pub(crate) fn input_scaling(&self) -> &InputScaling {
+        &self.input_scaling
+    }
+
+    /// Record `input` for the diagnostic features and return it as `Float`,
+    /// ready for normalization. `None` if it is NaN or rejected as a fault, in
+    /// which case the update ends here.
+    ///
+    /// Widens the input range first when auto-ranging, so read `input_scaling`
+    /// afterwards.
+    #[inline]
+    pub(crate) fn prepare_input(&mut self, input: TIn) -> Option<Float> {
+        let input_f = input.into_normalized();
+        if input_f.is_nan() {
+            return None;
+        }
+
+        #[cfg(feature = "observed-range")]
+        self.observe_input(input);
+
+        #[cfg(feature = "auto-range")]
+        if self.auto_range && input_f.is_finite() {
+            self.widen_input_range(input);
+        }
+
+        #[cfg(feature = "noise-report")]
+        self.measure_noise(input_f);
+
+        #[cfg(feature = "calibration")]
+        if let Some(session) = &mut self.calibration_session {
+            session.last = Some(input);
+        }
+
+        #[cfg(feature = "fault-detection")]
+        if self.detect_fault(input, input_f) {
+            return None;
+        }
+
+        // Undo supply sag before mapping to the input range
+        #[cfg(feature = "ratiometric")]
+        let input_f = input_f * self.reference.scale;
+
+        Some(input_f)
+    }
+
+    /// Run the pipeline from the noise filter on, for an input prepared by
+    /// `prepare_input()` and its normalized position. Returns the output and
+    /// whether it changed.
+    #[inline]
+    pub(crate) fn process_normalized(&mut self, input_f: Float, normalized: Float) -> (TOut, bool) {
+        #[cfg(not(feature = "defmt-trace"))]
+        let _ = input_f;
 
         // Apply noise filter
         let filtered = self.apply_filter(normalized);
+        self.track_velocity(filtered);
 
-        // Apply response curve
-        let curved = self.config.curve.apply(filtered);
+        #[cfg(feature = "usage-stats")]
+        self.usage.record(filtered, self.state.velocity);
 
-        // Apply hysteresis
-        let hysteresis_applied = self
-            .config
-            .hysteresis
-            .apply(curved, &mut self.state.hysteresis);
+        // Curve, hysteresis and snap zones in the configured order. Only the
+        // curve moves between orders, so each other stage is inlined once
+        let order = self.config.stage_order;
+        let curve_first = order == StageOrder::CurveFirst;
+        let physical_zones = order == StageOrder::PhysicalFirst;
+
+        let pre_curved = if curve_first {
+            self.config.curve.apply(filtered)
+        } else {
+            filtered
+        };
+        let hysteresis_applied = self.apply_hysteresis(pre_curved);
+
+        // Physical position in curved terms, before snap zones and grab mode
+        let physical = if curve_first {
+            hysteresis_applied
+        } else {
+            self.config.curve.apply(hysteresis_applied)
+        };
+
+        // Fine adjustment remaps after the curve. Zones in physical terms stay
+        // on the knob, so with `PhysicalFirst` it runs after them instead
+        #[cfg(feature = "fine-adjust")]
+        let adjusted = if physical_zones {
+            physical
+        } else {
+            self.apply_fine_adjust(physical)
+        };
+
+        #[cfg(not(feature = "fine-adjust"))]
+        let adjusted = physical;
+
+        // Dead zones hold the last value in the zones' own terms
+        let zoned = if physical_zones {
+            let zoned = self.apply_snap_zones(hysteresis_applied, self.state.last_snapped);
+            self.state.last_snapped = zoned;
+            zoned
+        } else {
+            self.apply_snap_zones(adjusted, self.state.last_target)
+        };
+
+        let (snapped, curved) = if physical_zones {
+            let curved = self.config.curve.apply(zoned);
+            (curved, curved)
+        } else if curve_first {
+            (zoned, pre_curved)
+        } else {
+            (zoned, physical)
+        };
+
+        #[cfg(feature = "fine-adjust")]
+        let snapped = if physical_zones {
+            self.apply_fine_adjust(snapped)
+        } else {
+            snapped
+        };
+
+        #[cfg(feature = "taps")]
+        self.record_stage_taps(curved, hysteresis_applied, zoned);
+
+        #[cfg(not(feature = "defmt-trace"))]
+        let _ = (curved, hysteresis_applied);
 
         // Capture physical position BEFORE snap zones and grab mode
         #[cfg(feature = "grab-mode")]
         {
-            self.state.physical_position = hysteresis_applied;
+            self.state.physical_position = physical;
         }
 
-        // Apply snap zones
-        let snapped = self.apply_snap_zones(hysteresis_applied);
+        #[cfg(not(feature = "grab-mode"))]
+        let _ = physical;
 
         // Apply grab mode logic
         #[cfg(feature = "grab-mode")]
@@ -77,14 +713,526 @@ where
         #[cfg(not(feature = "grab-mode"))]
         let output = snapped;
 
-        // Update last output for dead zones
+        // Ramp towards the target, then pick the nearest discrete value
+        self.state.last_target = output;
+        let output = self.apply_slew(output);
+        let output = self.apply_values(output);
+
+        let changed = output != self.state.last_output;
+        self.state.dirty |= changed;
+        self.state.updates_since_change = if changed {
+            0
+        } else {
+            self.state.updates_since_change.saturating_add(1)
+        };
+
+        // Update last output
         self.state.last_output = output;
 
+        #[cfg(feature = "taps")]
+        {
+            self.state.taps.normalized = normalized;
+            self.state.taps.filtered = filtered;
+            self.state.taps.output = output;
+        }
+
+        #[cfg(feature = "defmt-trace")]
+        self.trace_stages(
+            input_f,
+            normalized,
+            filtered,
+            curved,
+            hysteresis_applied,
+            output,
+        );
+
         // Denormalize to output range
-        self.denormalize_output(output)
+        (self.output_value(output), changed)
+    }
+
+    /// Like `update()`, but reports a NaN input instead of silently holding.
+    ///
+    /// On error the state is unchanged, as with `update()`.
+    pub fn try_update(&mut self, input: TIn) -> Result<TOut, InputError> {
+        if input.into_normalized().is_nan() {
+            return Err(InputError::NotANumber);
+        }
+        Ok(self.update(input))
+    }
+
+    /// Like `update()`, with `dt` seconds elapsed since the previous update.
+    ///
+    /// `velocity()` is then reported in normalized units per second.
+    pub fn update_timed(&mut self, input: TIn, dt: Float) -> TOut {
+        let output = self.update(input);
+        if dt > 0.0 {
+            self.state.velocity /= dt;
+        }
+        output
+    }
+
+    /// Like `update()`, timestamped with `now` (e.g. `Instant::now()`), so timing
+    /// doesn't depend on how regularly the loop runs.
+    ///
+    /// `velocity()` is reported per second and `slew_rate` is applied as output
+    /// units per second. The first call behaves like `update()`.
+    /// Requires `embassy-time` feature.
+    #[cfg(feature = "embassy-time")]
+    pub fn update_at(&mut self, input: TIn, now: Instant) -> TOut {
+        let dt = self.timing.last_update.map(|last| {
+            let elapsed = now.checked_duration_since(last).unwrap_or(Duration::MIN);
+            elapsed.as_micros() as Float / 1_000_000.0
+        });
+        self.timing.last_update = Some(now);
+
+        self.timing.slew_scale = dt.unwrap_or(1.0);
+        let output = match dt {
+            Some(dt) => self.update_timed(input, dt),
+            None => self.update(input),
+        };
+        self.timing.slew_scale = 1.0;
+
+        if self.state.updates_since_change == 0 || self.timing.changed_at.is_none() {
+            self.timing.changed_at = Some(now);
+        }
+        output
+    }
+
+    /// Time since the output last changed in `update_at()`, `Duration::MIN`
+    /// before the first call. Requires `embassy-time` feature.
+    #[cfg(feature = "embassy-time")]
+    pub fn idle_for(&self, now: Instant) -> Duration {
+        self.timing
+            .changed_at
+            .and_then(|changed| now.checked_duration_since(changed))
+            .unwrap_or(Duration::MIN)
+    }
+
+    /// Returns true if the output hasn't changed for at least `duration`, as of
+    /// `now`. Requires `embassy-time` feature.
+    #[cfg(feature = "embassy-time")]
+    pub fn is_idle_for(&self, duration: Duration, now: Instant) -> bool {
+        self.timing.changed_at.is_some() && self.idle_for(now) >= duration
+    }
+
+    /// Movement of the filtered position during the last update.
+    ///
+    /// Normalized units per update after `update()`, per second after `update_timed()`.
+    /// Positive when turning towards `input_max` (or `input_min` if inverted).
+    pub fn velocity(&self) -> Float {
+        self.state.velocity
+    }
+
+    /// Filtered input position (0.0..1.0) from the last update, before the curve,
+    /// hysteresis and zones. 0.0 before the first update.
+    pub fn filtered_position(&self) -> Float {
+        self.state.last_filtered.unwrap_or(0.0)
+    }
+
+    /// Direction of movement during the last update.
+    pub fn direction(&self) -> Direction {
+        if self.state.velocity > 0.0 {
+            Direction::Increasing
+        } else if self.state.velocity < 0.0 {
+            Direction::Decreasing
+        } else {
+            Direction::Stationary
+        }
+    }
+
+    /// Returns true if the output changed since the last `take_output()`.
+    pub fn is_dirty(&self) -> bool {
+        self.state.dirty
+    }
+
+    /// Returns the output if it changed since the last call, clearing the dirty flag.
+    pub fn take_output(&mut self) -> Option<TOut> {
+        if self.state.dirty {
+            self.state.dirty = false;
+            Some(self.last_output())
+        } else {
+            None
+        }
+    }
+
+    /// Number of updates since the output last changed.
+    pub fn updates_since_change(&self) -> u32 {
+        self.state.updates_since_change
+    }
+
+    /// Returns true if the output hasn't changed for at least `updates` updates.
+    pub fn is_idle(&self, updates: u32) -> bool {
+        self.state.updates_since_change >= updates
+    }
+
+    /// Minimum and maximum raw input seen, before clamping.
+    ///
+    /// Raw material for field calibration, or for detecting pots that never reach
+    /// their electrical extremes. `None` before the first update. Not cleared by
+    /// `reset()`. Requires `observed-range` feature.
+    #[cfg(feature = "observed-range")]
+    pub fn observed_range(&self) -> Option<(TIn, TIn)> {
+        self.observed
+    }
+
+    /// Forget the observed input range. Requires `observed-range` feature.
+    #[cfg(feature = "observed-range")]
+    pub fn clear_observed_range(&mut self) {
+        self.observed = None;
+    }
+
+    /// Widen the input range whenever a sample falls outside it.
+    ///
+    /// Start with a conservative (narrow) range: turning the pot to both ends
+    /// once then teaches the real span, since tolerances keep most pots from
+    /// reaching 0 or full scale. The learned range lives in the config, so
+    /// `reset()` keeps it and `save_state()` captures it. Requires `auto-range`
+    /// feature.
+    #[cfg(feature = "auto-range")]
+    pub fn set_auto_range(&mut self, enabled: bool) {
+        self.auto_range = enabled;
+    }
+
+    #[cfg(feature = "auto-range")]
+    pub fn is_auto_range(&self) -> bool {
+        self.auto_range
+    }
+
+    /// Register a callback invoked from `update()` with the new
+    /// `(input_min, input_max)` whenever auto-range widens it, e.g. to persist
+    /// the calibration. Pass `None` to remove it. Like the `set_on_change()`
+    /// callback, it must not panic. Requires `auto-range` feature.
+    #[cfg(feature = "auto-range")]
+    pub fn set_on_range_change(&mut self, on_range_change: Option<fn((TIn, TIn))>) {
+        self.on_range_change = on_range_change;
+    }
+
+    /// Start a guided calibration session, discarding any earlier captures.
+    ///
+    /// The pot keeps processing with the current configuration while the
+    /// operator moves it; each `capture_*()` records the latest sample passed to
+    /// `update()`. Requires `calibration` feature.
+    #[cfg(feature = "calibration")]
+    pub fn begin_calibration(&mut self) {
+        self.calibration_session = Some(Calibration::new());
+    }
+
+    #[cfg(feature = "calibration")]
+    pub fn is_calibrating(&self) -> bool {
+        self.calibration_session.is_some()
+    }
+
+    /// End the calibration session without applying it. Requires `calibration`
+    /// feature.
+    #[cfg(feature = "calibration")]
+    pub fn cancel_calibration(&mut self) {
+        self.calibration_session = None;
+    }
+
+    /// Capture the latest sample as `input_min` and return it.
+    ///
+    /// `None` if not calibrating or no sample arrived since
+    /// `begin_calibration()`. Requires `calibration` feature.
+    #[cfg(feature = "calibration")]
+    pub fn capture_min(&mut self) -> Option<TIn> {
+        let session = self.calibration_session.as_mut()?;
+        session.min = session.last;
+        session.min
+    }
+
+    /// Capture the latest sample as `input_max`, like `capture_min()`.
+    #[cfg(feature = "calibration")]
+    pub fn capture_max(&mut self) -> Option<TIn> {
+        let session = self.calibration_session.as_mut()?;
+        session.max = session.last;
+        session.max
+    }
+
+    /// Capture the latest sample as `input_center` for bipolar controls, like
+    /// `capture_min()`.
+    #[cfg(feature = "calibration")]
+    pub fn capture_center(&mut self) -> Option<TIn> {
+        let session = self.calibration_session.as_mut()?;
+        session.center = session.last;
+        session.center
+    }
+
+    /// Apply the captured points to the input range and end the session.
+    ///
+    /// Ends that weren't captured keep their current value; the center is set
+    /// only if captured, otherwise normalization is linear. `end_margin` and
+    /// `invert_input` are kept. Filter state is reset
+    /// as the range changed. Without a session, returns the current calibration
+    /// unchanged.
+    ///
+    /// Fails with `InvalidInputRange` if the ends coincide and
+    /// `InvalidInputCenter` if the center isn't between them. On error the
+    /// configuration is unchanged and the session continues, so the offending
+    /// point can be captured again. Requires `calibration` feature.
+    #[cfg(feature = "calibration")]
+    pub fn finish_calibration(&mut self) -> Result<CalibrationData<TIn>, ConfigError> {
+        let Some(session) = self.calibration_session else {
+            return Ok(self.config.calibration());
+        };
+
+        let data = CalibrationData {
+            input_min: session.min.unwrap_or(self.config.input_min),
+            input_max: session.max.unwrap_or(self.config.input_max),
+            input_center: session.center,
+            end_margin: self.config.end_margin,
+            invert_input: self.config.invert_input,
+        };
+        self.apply_calibration(&data)?;
+
+        self.calibration_session = None;
+        Ok(data)
+    }
+
+    /// Watch for broken-wiper symptoms; `None` turns detection off.
+    ///
+    /// Clears any detected fault. Fails if the settings are invalid, leaving
+    /// detection unchanged. Requires `fault-detection` feature.
+    #[cfg(feature = "fault-detection")]
+    pub fn set_fault_detection(
+        &mut self,
+        detection: Option<FaultDetection<TIn>>,
+    ) -> Result<(), ConfigError> {
+        if let Some(detection) = &detection {
+            detection.validate()?;
+        }
+        self.fault_detection = detection;
+        self.fault_state = FaultState::default();
+        Ok(())
+    }
+
+    /// Fault detected by the last update, `None` while the input looks healthy.
+    ///
+    /// Rail faults last as long as the input stays on the rail; a jump is
+    /// reported for the update it happened in. Requires `fault-detection`
+    /// feature.
+    #[cfg(feature = "fault-detection")]
+    pub fn fault(&self) -> Option<Fault> {
+        self.fault_state.fault
+    }
+
+    /// Jitter of the input over the last completed window of samples.
+    ///
+    /// `None` until the first window completes. Not cleared by `reset()`.
+    /// Requires `noise-report` feature.
+    #[cfg(feature = "noise-report")]
+    pub fn noise_report(&self) -> Option<NoiseReport> {
+        self.noise.report
+    }
+
+    /// Measure noise over windows of `samples` updates (default 64); 0 turns
+    /// measurement off. Restarts the measurement and clears the last report.
+    /// Requires `noise-report` feature.
+    #[cfg(feature = "noise-report")]
+    pub fn set_noise_window(&mut self, samples: u16) {
+        self.noise = NoiseMeter::new(samples);
+    }
+
+    /// Usage counters for wear estimation and UX research.
+    ///
+    /// Not cleared by `reset()`. Requires `usage-stats` feature.
+    #[cfg(feature = "usage-stats")]
+    pub fn stats(&self) -> UsageStats {
+        self.usage.stats()
+    }
+
+    /// Zero the usage counters. Requires `usage-stats` feature.
+    #[cfg(feature = "usage-stats")]
+    pub fn clear_stats(&mut self) {
+        self.usage = UsageTracker::default();
+    }
+
+    /// Set the reference reading the input range was calibrated at, e.g. the
+    /// supply rail measured during calibration. `None` turns compensation off.
+    /// Requires `ratiometric` feature.
+    #[cfg(feature = "ratiometric")]
+    pub fn set_nominal_reference(&mut self, nominal: Option<TIn>) {
+        self.reference = Reference {
+            nominal: nominal.map(IntoNormalized::into_normalized),
+            scale: 1.0,
+        };
+    }
+
+    /// Report the latest reading of the pot's supply or reference rail.
+    ///
+    /// Subsequent inputs are scaled by nominal over `raw_ref`, so a pot powered
+    /// from a sagging battery rail reads the same position as at calibration.
+    /// Without a nominal reference, the first reading becomes the nominal.
+    /// Zero, negative and NaN readings are ignored. Requires `ratiometric`
+    /// feature.
+    #[cfg(feature = "ratiometric")]
+    pub fn set_reference(&mut self, raw_ref: TIn) {
+        let reference = raw_ref.into_normalized();
+        if reference.is_nan() || reference <= 0.0 {
+            return;
+        }
+
+        let nominal = *self.reference.nominal.get_or_insert(reference);
+        self.reference.scale = nominal / reference;
+    }
+
+    /// Start a synthetic sweep over the input range, pushed through the
+    /// pipeline one sample per `self_test_step()`.
+    ///
+    /// The sweep dwells at `input_min` for `steps / 2` samples, ramps to
+    /// `input_max` over `steps` samples and dwells there again, so filters
+    /// settle at both ends. Resets the processing state first. Requires
+    /// `self-test` feature.
+    #[cfg(feature = "self-test")]
+    pub fn begin_self_test(&mut self, steps: u16) {
+        self.reset();
+        self.self_test = Some(SelfTest::new(steps));
+    }
+
+    #[cfg(feature = "self-test")]
+    pub fn is_self_testing(&self) -> bool {
+        self.self_test.is_some()
+    }
+
+    /// Abandon the sweep. Requires `self-test` feature.
+    #[cfg(feature = "self-test")]
+    pub fn cancel_self_test(&mut self) {
+        self.self_test = None;
+    }
+
+    /// Process the next synthetic sample, returning the report after the last.
+    ///
+    /// `None` while the sweep runs or when not self-testing. Samples go through
+    /// `update()`, so callbacks fire and the pot is left at `input_max`; call
+    /// `reset()` before feeding real input. Requires `self-test` feature.
+    #[cfg(feature = "self-test")]
+    pub fn self_test_step(&mut self) -> Option<SelfTestReport>
+    where
+        TIn: FromNormalized,
+    {
+        let mut test = self.self_test?;
+
+        let min_f = self.config.input_min.into_normalized();
+        let max_f = self.config.input_max.into_normalized();
+        let input = TIn::from_normalized(min_f + (max_f - min_f) * test.position());
+        self.update(input);
+        test.record(self.state.last_output);
+
+        if test.is_finished() {
+            self.self_test = None;
+            Some(test.report())
+        } else {
+            self.self_test = Some(test);
+            None
+        }
+    }
+
+    /// Emit the pipeline trace on every `interval`-th update; 0 turns it off.
+    ///
+    /// Defaults to every update. Requires `defmt-trace` feature.
+    #[cfg(feature = "defmt-trace")]
+    pub fn set_trace_interval(&mut self, interval: u16) {
+        self.trace_interval = interval;
+        self.trace_count = 0;
+    }
+
+    /// Process a sample and select one of `steps` discrete values (`0..steps`).
+    ///
+    /// The step follows the normalized output with boundary hysteresis of a quarter
+    /// step, so a pot resting on a boundary doesn't flicker between neighbours.
+    /// The continuous output is still available via `last_output()`.
+    /// `steps` of zero is treated as one.
+    pub fn update_stepped(&mut self, input: TIn, steps: usize) -> usize {
+        const STEP_HYSTERESIS: Float = 0.25;
+
+        self.update(input);
+
+        let steps = steps.max(1);
+        let last_index = steps - 1;
+        let scale = steps as Float;
+        let value = self.state.last_output;
+
+        let step = match self.state.last_step {
+            Some(last) => {
+                let last = last.min(last_index);
+                let margin = STEP_HYSTERESIS / scale;
+
+                if value >= (last + 1) as Float / scale + margin {
+                    ((value - margin) * scale) as usize
+                } else if value <= last as Float / scale - margin {
+                    ((value + margin) * scale) as usize
+                } else {
+                    last
+                }
+            }
+            None => (value * scale) as usize,
+        }
+        .min(last_index);
+
+        self.state.last_step = Some(step);
+        step
+    }
+
+    /// Like `update()`, but also reports what happened during this update.
+    pub fn update_with_events(&mut self, input: TIn) -> (TOut, UpdateEvents) {
+        let prev_output = self.state.last_output;
+        let prev_zone = self.state.active_zone;
+        let prev_schmitt = self.state.hysteresis.schmitt_state;
+
+        #[cfg(feature = "fault-detection")]
+        let prev_fault = self.fault_state.fault;
+
+        #[cfg(feature = "grab-mode")]
+        let was_grabbed = self.state.grabbed;
+
+        let output = self.update(input);
+
+        let zone = self.state.active_zone;
+        let zone_changed = zone != prev_zone;
+        let schmitt_state = self.state.hysteresis.schmitt_state;
+
+        let events = UpdateEvents {
+            changed: self.state.last_output != prev_output,
+            entered_zone: if zone_changed { zone } else { None },
+            left_zone: if zone_changed { prev_zone } else { None },
+            #[cfg(feature = "grab-mode")]
+            grabbed: !was_grabbed && self.state.grabbed && self.config.grab_mode != GrabMode::None,
+            #[cfg(not(feature = "grab-mode"))]
+            grabbed: false,
+            schmitt_edge: match self.config.hysteresis {
+                HysteresisMode::SchmittTrigger { .. } if schmitt_state != prev_schmitt => {
+                    Some(schmitt_state)
+                }
+                _ => None,
+            },
+            #[cfg(feature = "fault-detection")]
+            fault: self
+                .fault_state
+                .fault
+                .filter(|&fault| Some(fault) != prev_fault),
+            #[cfg(not(feature = "fault-detection"))]
+            fault: None,
+        };
+
+        (output, events)
+    }
+
+    /// Process a block of samples (e.g. DMA-filled) and return the final output.
+    ///
+    /// Equivalent to calling `update()` for each sample in order. Returns
+    /// `last_output()` for an empty slice.
+    pub fn update_batch(&mut self, samples: &[TIn]) -> TOut {
+        for &sample in samples {
+            self.update(sample);
+        }
+        self.last_output()
+    }
+
+    /// Process a block of samples lazily, yielding each intermediate output.
+    pub fn update_iter<'s>(&'s mut self, samples: &'s [TIn]) -> impl Iterator<Item = TOut> + 's {
+        samples.iter().map(move |&sample| self.update(sample))
     }
 
-    fn apply_filter(&mut self, value: f32) -> f32 {
+    fn apply_filter(&mut self, value: Float) -> Float {
         match &self.config.filter {
             NoiseFilter::None => value,
 
@@ -107,45 +1255,344 @@ where
         }
     }
 
-    fn apply_snap_zones(&self, value: f32) -> f32 {
+    #[cfg(feature = "observed-range")]
+    fn observe_input(&mut self, input: TIn) {
+        self.observed = Some(match self.observed {
+            Some((min, max)) => (
+                if input < min { input } else { min },
+                if input > max { input } else { max },
+            ),
+            None => (input, input),
+        });
+    }
+
+    /// Feed the noise meter with the input as a fraction of the input range.
+    /// Unclamped, so a pot resting at an end still shows its jitter.
+    #[cfg(feature = "noise-report")]
+    fn measure_noise(&mut self, input_f: Float) {
+        if !input_f.is_finite() {
+            return;
+        }
+        let min_f = self.config.input_min.into_normalized();
+        let max_f = self.config.input_max.into_normalized();
+
+        // Safe division: validation ensures max_f != min_f
+        self.noise.add((input_f - min_f) / (max_f - min_f));
+    }
+
+    /// Update fault detection with a sample. Returns true if it should be ignored.
+    #[cfg(feature = "fault-detection")]
+    fn detect_fault(&mut self, input: TIn, input_f: Float) -> bool {
+        let Some(detection) = self.fault_detection else {
+            return false;
+        };
+        let state = &mut self.fault_state;
+
+        let rail = if input <= detection.low_rail {
+            Some(Fault::LowRail)
+        } else if input >= detection.high_rail {
+            Some(Fault::HighRail)
+        } else {
+            None
+        };
+        state.rail_count = match rail {
+            Some(_) if rail == state.rail => state.rail_count.saturating_add(1),
+            Some(_) => 1,
+            None => 0,
+        };
+        state.rail = rail;
+
+        // Step as a fraction of the input range; validation ensures a non-zero span
+        let span = (self.config.input_max.into_normalized()
+            - self.config.input_min.into_normalized())
+        .abs();
+        let jumped = match (detection.max_step, state.last_input) {
+            (Some(max_step), Some(last)) => (input_f - last).abs() / span > max_step,
+            _ => false,
+        };
+        state.last_input = Some(input_f);
+
+        state.fault = if state.rail_count >= detection.rail_samples.max(1) {
+            rail
+        } else if jumped {
+            Some(Fault::Jump)
+        } else {
+            None
+        };
+
+        detection.hold_output && state.fault.is_some()
+    }
+
+    #[cfg(feature = "auto-range")]
+    fn input_range(&self) -> (TIn, TIn) {
+        (self.config.input_min, self.config.input_max)
+    }
+
+    /// Move the range end nearest to `input` out to it, keeping the orientation
+    /// of inverted ranges (`input_min > input_max`).
+    #[cfg(feature = "auto-range")]
+    fn widen_input_range(&mut self, input: TIn) {
+        let (min, max) = (self.config.input_min, self.config.input_max);
+        let ascending = min < max;
+        let (low, high) = if ascending { (min, max) } else { (max, min) };
+
+        let range = if input < low {
+            if ascending {
+                (input, max)
+            } else {
+                (min, input)
+            }
+        } else if input > high {
+            if ascending {
+                (min, input)
+            } else {
+                (input, max)
+            }
+        } else {
+            return;
+        };
+
+        self.config.input_min = range.0;
+        self.config.input_max = range.1;
+        self.input_scaling = InputScaling::new(&self.config);
+    }
+
+    /// Log each pipeline stage at trace level, throttled by `trace_interval`.
+    #[cfg(feature = "defmt-trace")]
+    fn trace_stages(
+        &mut self,
+        raw: Float,
+        normalized: Float,
+        filtered: Float,
+        curved: Float,
+        hysteresis: Float,
+        output: Float,
+    ) {
+        if self.trace_interval == 0 {
+            return;
+        }
+        self.trace_count += 1;
+        if self.trace_count < self.trace_interval {
+            return;
+        }
+        self.trace_count = 0;
+
+        defmt::trace!(
+            "pot raw={} normalized={} filtered={} curved={} hysteresis={} output={}",
+            raw,
+            normalized,
+            filtered,
+            curved,
+            hysteresis,
+            output
+        );
+    }
+
+    fn track_velocity(&mut self, filtered: Float) {
+        self.state.velocity = match self.state.last_filtered {
+            Some(last) => filtered - last,
+            None => 0.0,
+        };
+        self.state.last_filtered = Some(filtered);
+    }
+
+    /// Remap `value` so slow movement moves it by a fraction (see `FineAdjust`).
+    #[cfg(feature = "fine-adjust")]
+    fn apply_fine_adjust(&mut self, value: Float) -> Float {
+        let Some(fine) = self.config.fine_adjust else {
+            return value;
+        };
+
+        let position = match self.state.fine_last {
+            Some(last) => fine.step(self.state.fine_position, last, value, self.state.velocity),
+            None => value,
+        };
+        self.state.fine_last = Some(value);
+        self.state.fine_position = position;
+        position
+    }
+
+    #[cfg(feature = "taps")]
+    fn record_stage_taps(&mut self, curved: Float, hysteresis: Float, snapped: Float) {
+        self.state.taps.curved = curved;
+        self.state.taps.hysteresis = hysteresis;
+        self.state.taps.snapped = snapped;
+    }
+
+    fn apply_hysteresis(&mut self, value: Float) -> Float {
+        self.config
+            .hysteresis
+            .apply(value, &mut self.state.hysteresis)
+    }
+
+    /// `hold` is the value dead zones keep, in the same space as `value`.
+    fn apply_snap_zones(&mut self, value: Float, hold: Float) -> Float {
         // Process zones in order - first match wins
-        for zone in self.config.snap_zones {
+        for (index, zone) in self.config.snap_zones.iter().enumerate() {
             if zone.contains(value) {
-                return zone.apply(value, self.state.last_output);
+                self.state.active_zone = Some(index);
+                return zone.apply(value, hold);
             }
         }
+        self.state.active_zone = None;
         value // No zone matched
     }
 
-    fn normalize_input(&self, input: TIn) -> f32 {
-        let input_f = input.as_();
-        let min_f = self.config.input_min.as_();
-        let max_f = self.config.input_max.as_();
+    /// Position of `output` in the output range, as `Config::values` entries
+    /// are placed.
+    pub(crate) fn normalize_output(&self, output: TOut) -> Float {
+        self.output_scaling.normalize(output.into_normalized())
+    }
 
-        // Clamp input to valid range
-        let clamped = if input_f < min_f {
-            min_f
-        } else if input_f > max_f {
-            max_f
-        } else {
-            input_f
+    /// Move at most `slew_rate` towards `target`. The first update jumps directly.
+    fn apply_slew(&mut self, target: Float) -> Float {
+        let Some(rate) = self.config.slew_rate else {
+            return target;
         };
 
-        // Normalize to 0.0..1.0
-        // Safe division: validation ensures max_f > min_f
-        (clamped - min_f) / (max_f - min_f)
+        let min_f = self.config.output_min.into_normalized();
+        let max_f = self.config.output_max.into_normalized();
+        let step = rate / (max_f - min_f).abs();
+
+        #[cfg(feature = "embassy-time")]
+        let step = step * self.timing.slew_scale;
+
+        let next = match self.state.slewed {
+            Some(current) if target > current + step => current + step,
+            Some(current) if target < current - step => current - step,
+            _ => target,
+        };
+
+        self.state.slewed = Some(next);
+        next
+    }
+
+    /// Select the `Config::values` entry nearest to `value` and return its position.
+    ///
+    /// Leaving the current entry requires moving a quarter of the gap to the
+    /// neighbouring entry past the midpoint, like `update_stepped()`.
+    #[inline]
+    fn apply_values(&mut self, value: Float) -> Float {
+        if self.config.values.is_empty() {
+            return value;
+        }
+        self.select_value(value)
     }
 
-    fn denormalize_output(&self, normalized: f32) -> TOut {
-        let min_f = self.config.output_min.as_();
-        let max_f = self.config.output_max.as_();
+    /// `apply_values()` with a non-empty table, kept out of line so pots
+    /// without one don't carry the search in their hot path.
+    #[inline(never)]
+    fn select_value(&mut self, value: Float) -> Float {
+        let values = self.config.values;
 
-        let output_f = min_f + normalized * (max_f - min_f);
-        output_f.as_()
+        let position = |index: usize| match values.get(index) {
+            Some(&entry) => self.normalize_output(entry),
+            None => value,
+        };
+        let distance = |index: usize| (position(index) - value).abs();
+
+        let mut nearest = 0;
+        let mut nearest_distance = distance(0);
+        for index in 1..values.len() {
+            let d = distance(index);
+            if d < nearest_distance {
+                nearest = index;
+                nearest_distance = d;
+            }
+        }
+
+        let index = match self.state.value_index {
+            Some(last) if last < values.len() && last != nearest => {
+                let neighbour = if nearest > last {
+                    last + 1
+                } else {
+                    last.saturating_sub(1)
+                };
+                let gap = (position(neighbour) - position(last)).abs();
+
+                if distance(last) - nearest_distance > gap * 0.5 {
+                    nearest
+                } else {
+                    last
+                }
+            }
+            _ => nearest,
+        };
+
+        let selected = position(index);
+        self.state.value_index = Some(index);
+        selected
+    }
+
+    /// Output for a normalized value, returning the selected `Config::values` entry exactly.
+    fn output_value(&self, normalized: Float) -> TOut {
+        match self
+            .state
+            .value_index
+            .and_then(|index| self.config.values.get(index))
+        {
+            Some(&value) => value,
+            None => self.denormalize_output(normalized),
+        }
+    }
+
+    /// Output for a normalized value from outside the pipeline, e.g. combined
+    /// from several pots: the nearest `Config::values` entry, or the
+    /// denormalized value without a table.
+    pub(crate) fn output_for(&self, normalized: Float) -> TOut {
+        let mut nearest = None;
+        let mut nearest_distance = Float::INFINITY;
+        for &value in self.config.values {
+            let distance = (self.normalize_output(value) - normalized).abs();
+            if distance < nearest_distance {
+                nearest = Some(value);
+                nearest_distance = distance;
+            }
+        }
+        nearest.unwrap_or_else(|| self.denormalize_output(normalized))
+    }
+
+    pub(crate) fn denormalize_output(&self, normalized: Float) -> TOut {
+        self.output_scaling
+            .output(normalized, self.config.output_min, self.config.output_max)
     }
 
     #[cfg(feature = "grab-mode")]
-    fn apply_grab_mode(&mut self, value: f32) -> f32 {
+    #[inline]
+    fn apply_grab_mode(&mut self, value: Float) -> Float {
+        // A grabbed pot has direct control in every mode; catching it is rare,
+        // so it stays out of line. `release()` and `set_virtual_value()` reset
+        // the re-grab flag, so a grabbed pot can skip it
+        if self.state.grabbed {
+            self.state.last_physical = value;
+            self.state.virtual_value = value;
+            return value;
+        }
+        self.catch_pot(value)
+    }
+
+    /// `apply_grab_mode()` for a pot that isn't grabbed yet.
+    #[cfg(feature = "grab-mode")]
+    #[inline(never)]
+    fn catch_pot(&mut self, value: Float) -> Float {
+        // Release policy: re-grab if pot hasn't moved since release(). The
+        // virtual value is still the physical position release() took, so
+        // compare it with the physical position rather than the snapped value
+        if self.state.regrab_pending {
+            self.state.regrab_pending = false;
+
+            let physical = self.state.physical_position;
+            let diff = if physical > self.state.virtual_value {
+                physical - self.state.virtual_value
+            } else {
+                self.state.virtual_value - physical
+            };
+
+            if diff <= self.config.grab_margin {
+                self.state.grabbed = true;
+            }
+        }
+
         match self.config.grab_mode {
             GrabMode::None => {
                 // Direct control, no grab logic
@@ -156,8 +1603,10 @@ where
 
             GrabMode::Pickup => {
                 if !self.state.grabbed {
-                    // Check if pot crosses virtual value from below
-                    if value >= self.state.virtual_value {
+                    // Check if pot crosses virtual value (plus margin) from below.
+                    // Near the top the margin is cut short so the end still grabs
+                    let threshold = (self.state.virtual_value + self.config.grab_margin).min(1.0);
+                    if value >= threshold {
                         self.state.grabbed = true;
                     } else {
                         // Hold virtual value until grabbed
@@ -178,12 +1627,23 @@ where
                         return self.state.virtual_value;
                     }
 
-                    // Check if pot crosses virtual value from either direction
-                    let crossing_from_below = value >= self.state.virtual_value
-                        && self.state.last_physical < self.state.virtual_value;
+                    // Margin band around the virtual value, cut short at the ends
+                    // of travel so a value near either end can still be crossed
+                    let margin = self.config.grab_margin;
+                    let upper = (self.state.virtual_value + margin).min(1.0);
+                    let lower = (self.state.virtual_value - margin).max(0.0);
+
+                    // Inside the margin band - hold without updating the approach side
+                    if value > lower && value < upper {
+                        return self.state.virtual_value;
+                    }
+
+                    // Check if pot crosses virtual value (plus margin) from either direction
+                    let crossing_from_below =
+                        value >= upper && self.state.last_physical < self.state.virtual_value;
 
-                    let crossing_from_above = value <= self.state.virtual_value
-                        && self.state.last_physical > self.state.virtual_value;
+                    let crossing_from_above =
+                        value <= lower && self.state.last_physical > self.state.virtual_value;
 
                     if crossing_from_below || crossing_from_above {
                         self.state.grabbed = true;
@@ -207,17 +1667,18 @@ where
     /// Returns the current physical input position in normalized 0.0..1.0 range.
     /// Useful for UI display when grab mode is active.
     ///
-    /// This always reflects where the pot physically is (after normalize→filter→curve→hysteresis),
-    /// but BEFORE virtual modifications like snap zones and grab mode logic.
+    /// This always reflects where the pot physically is (after normalize→filter→curve→hysteresis,
+    /// in the configured stage order), but BEFORE virtual modifications like snap zones and
+    /// grab mode logic.
     #[cfg(feature = "grab-mode")]
-    pub fn physical_position(&self) -> f32 {
+    pub fn physical_position(&self) -> Float {
         self.state.physical_position
     }
 
     /// Returns the current output value in normalized 0.0..1.0 range without updating state.
     /// Useful for reading the locked virtual value in grab mode.
     #[cfg(feature = "grab-mode")]
-    pub fn current_output(&self) -> f32 {
+    pub fn current_output(&self) -> Float {
         self.state.virtual_value
     }
 
@@ -234,22 +1695,45 @@ where
     /// Set the virtual parameter value (e.g., after preset change or automation).
     /// This unlocks grab mode, requiring the pot to be grabbed again.
     #[cfg(feature = "grab-mode")]
-    pub fn set_virtual_value(&mut self, value: f32) {
+    pub fn set_virtual_value(&mut self, value: Float) {
         self.state.virtual_value = value;
         self.state.grabbed = false;
         self.state.passthrough_initialized = false; // Reset for PassThrough mode
+        self.state.regrab_pending = false;
     }
 
     /// Release grab and set virtual value to current physical position.
     /// Useful when switching which parameter a physical pot controls.
     ///
     /// After calling this, the pot will be ungrabbed and the virtual value
-    /// will be set to the current physical position. The pot must be moved
-    /// to re-grab (in Pickup/PassThrough modes).
+    /// will be set to the current physical position. With `ReleasePolicy::RequireMovement`
+    /// the pot must be moved to re-grab (in Pickup/PassThrough modes). With
+    /// `ReleasePolicy::GrabInPlace` the next update re-grabs if the pot hasn't moved.
     #[cfg(feature = "grab-mode")]
     pub fn release(&mut self) {
         self.state.virtual_value = self.state.physical_position;
         self.state.grabbed = false;
         self.state.passthrough_initialized = false;
+        self.state.regrab_pending = self.config.release_policy == ReleasePolicy::GrabInPlace;
+    }
+}
+
+/// Allocating helpers for host-side tools and tests.
+#[cfg(feature = "std")]
+impl<'a, TIn, TOut> PotHead<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Create one pot per configuration. Fails on the first invalid one.
+    pub fn from_configs(
+        configs: impl IntoIterator<Item = Config<'a, TIn, TOut>>,
+    ) -> Result<std::vec::Vec<Self>, ConfigError> {
+        configs.into_iter().map(Self::new).collect()
+    }
+
+    /// Process a block of samples and collect every intermediate output.
+    pub fn update_vec(&mut self, samples: &[TIn]) -> std::vec::Vec<TOut> {
+        self.update_iter(samples).collect()
     }
 }
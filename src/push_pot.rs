@@ -0,0 +1,250 @@
+//! Pots with an integrated push switch.
+//!
+//! `PushPot` debounces the switch alongside the pot, detects clicks, double
+//! clicks and holds, and optionally lets the switch change how the pot behaves
+//! (freeze the value while pressed, or toggle a fine adjustment mode). Timing is
+//! counted in updates, so call `update()` at a steady rate.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// Switch timing, in updates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SwitchTiming {
+    /// Updates the switch must stay in a new state before it counts
+    pub debounce: u16,
+
+    /// Press length that counts as a hold instead of a click
+    pub hold: u16,
+
+    /// Maximum gap between two clicks that makes a double click
+    pub double_click: u16,
+}
+
+impl SwitchTiming {
+    /// 5 ms debounce, 500 ms hold, 300 ms double click at a 1 kHz update rate.
+    pub const DEFAULT: Self = Self {
+        debounce: 5,
+        hold: 500,
+        double_click: 300,
+    };
+}
+
+impl Default for SwitchTiming {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// What pressing the switch does to the pot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PressAction {
+    /// The switch only reports events
+    None,
+
+    /// The output holds while the switch is pressed
+    Freeze,
+
+    /// A click toggles fine mode, where pot movement is scaled by `scale`
+    /// around the value at the time of the click
+    ToggleFine { scale: Float },
+}
+
+impl PressAction {
+    pub const fn validate(&self) -> Result<(), &'static str> {
+        if let PressAction::ToggleFine { scale } = self
+            && (scale.is_nan() || *scale <= 0.0 || *scale > 1.0)
+        {
+            return Err("ToggleFine scale must be in range (0.0, 1.0]");
+        }
+        Ok(())
+    }
+}
+
+/// Switch events from one update. The first click of a double click is also
+/// reported as a click.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SwitchEvents {
+    pub pressed: bool,
+    pub released: bool,
+    pub click: bool,
+    pub double_click: bool,
+    pub hold: bool,
+}
+
+impl SwitchEvents {
+    /// Returns true if nothing happened.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Pot with a debounced push switch.
+#[derive(Clone)]
+pub struct PushPot<'a, TIn, TOut = TIn> {
+    pot: PotHead<'a, TIn, TOut>,
+    action: PressAction,
+    timing: SwitchTiming,
+    pressed: bool,
+    bounce: u16,
+    press_length: u16,
+    since_click: Option<u16>,
+    frozen: Option<Float>,
+    fine: Option<(Float, Float)>,
+    output: Float,
+}
+
+impl<'a, TIn, TOut> PushPot<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    pub fn new(
+        config: Config<'a, TIn, TOut>,
+        action: PressAction,
+        timing: SwitchTiming,
+    ) -> Result<Self, ConfigError> {
+        if let Err(reason) = action.validate() {
+            return Err(ConfigError::InvalidPressAction { reason });
+        }
+
+        Ok(Self {
+            pot: PotHead::new(config)?,
+            action,
+            timing,
+            pressed: false,
+            bounce: 0,
+            press_length: 0,
+            since_click: None,
+            frozen: None,
+            fine: None,
+            output: 0.0,
+        })
+    }
+
+    /// Process one pot sample and the raw switch state.
+    pub fn update(&mut self, input: TIn, pressed: bool) -> (TOut, SwitchEvents) {
+        self.pot.update(input);
+        let events = self.update_switch(pressed);
+
+        match self.action {
+            PressAction::None => {}
+            PressAction::Freeze => {
+                if events.pressed {
+                    self.frozen = Some(self.output);
+                }
+                if events.released
+                    && let Some(frozen) = self.frozen.take()
+                {
+                    // Resume from the frozen value (picked up first with grab mode)
+                    self.pot.set_output(self.pot.denormalize_output(frozen));
+                }
+            }
+            PressAction::ToggleFine { .. } => {
+                if events.click {
+                    self.toggle_fine();
+                }
+            }
+        }
+
+        self.output = self.processed();
+        (self.last_output(), events)
+    }
+
+    /// Most recent output, without processing a new sample.
+    pub fn last_output(&self) -> TOut {
+        self.pot.denormalize_output(self.output)
+    }
+
+    /// Debounced switch state.
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.is_some()
+    }
+
+    pub fn is_fine(&self) -> bool {
+        self.fine.is_some()
+    }
+
+    pub fn pot(&self) -> &PotHead<'a, TIn, TOut> {
+        &self.pot
+    }
+
+    pub fn pot_mut(&mut self) -> &mut PotHead<'a, TIn, TOut> {
+        &mut self.pot
+    }
+
+    /// Output after the press action, normalized.
+    fn processed(&self) -> Float {
+        let pot = self.pot.last_output_normalized();
+
+        if let Some(frozen) = self.frozen {
+            return frozen;
+        }
+
+        match (self.action, self.fine) {
+            (PressAction::ToggleFine { scale }, Some((anchor, pot_anchor))) => {
+                (anchor + (pot - pot_anchor) * scale).clamp(0.0, 1.0)
+            }
+            _ => pot,
+        }
+    }
+
+    fn toggle_fine(&mut self) {
+        match self.fine.take() {
+            None => self.fine = Some((self.output, self.pot.last_output_normalized())),
+            Some(_) => {
+                // Continue coarse adjustment from the fine value
+                self.pot
+                    .set_output(self.pot.denormalize_output(self.output));
+            }
+        }
+    }
+
+    fn update_switch(&mut self, pressed: bool) -> SwitchEvents {
+        let mut events = SwitchEvents::default();
+
+        if let Some(since) = self.since_click.as_mut() {
+            *since = since.saturating_add(1);
+        }
+
+        // Debounce: the new state must persist for `debounce` updates
+        if pressed == self.pressed {
+            self.bounce = 0;
+        } else {
+            self.bounce = self.bounce.saturating_add(1);
+            if self.bounce >= self.timing.debounce {
+                self.bounce = 0;
+                self.pressed = pressed;
+                events.pressed = pressed;
+                events.released = !pressed;
+            }
+        }
+
+        if self.pressed {
+            self.press_length = self.press_length.saturating_add(1);
+            if self.press_length == self.timing.hold {
+                events.hold = true;
+            }
+        } else if events.released {
+            if self.press_length < self.timing.hold {
+                events.click = true;
+                events.double_click = self
+                    .since_click
+                    .is_some_and(|since| since <= self.timing.double_click);
+                self.since_click = if events.double_click { None } else { Some(0) };
+            }
+            self.press_length = 0;
+        }
+
+        events
+    }
+}
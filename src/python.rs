@@ -0,0 +1,180 @@
+//! Python bindings for offline tuning.
+//!
+//! `Config` and `PotHead` (over `Float` values) are exposed to Python so logged
+//! ADC traces can be replayed through the same pipeline code the firmware runs,
+//! e.g. to sweep filter parameters in a notebook. The chosen configuration goes
+//! back to the firmware as `Config::builder()` code. Snap zones and discrete
+//! values borrow tables and are not exposed. Requires `python` feature.
+
+use std::format;
+use std::string::String;
+use std::vec::Vec;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::config::{Config, ConfigError};
+use crate::curves::ResponseCurve;
+use crate::export::builder_code;
+use crate::filters::NoiseFilter;
+use crate::float::Float;
+use crate::hysteresis::HysteresisMode;
+use crate::pothead::PotHead;
+
+fn value_error(error: ConfigError) -> PyErr {
+    PyValueError::new_err(format!("{}", error))
+}
+
+/// Validated configuration, exposed to Python as `Config`.
+#[pyclass(name = "Config", frozen, from_py_object)]
+#[derive(Clone, Copy)]
+pub struct PyConfig {
+    config: Config<'static, Float, Float>,
+}
+
+#[pymethods]
+impl PyConfig {
+    /// `threshold` selects a change threshold, `schmitt` a `(rising, falling)`
+    /// Schmitt trigger; `alpha` enables the EMA filter.
+    #[new]
+    #[pyo3(signature = (
+        input_min,
+        input_max,
+        output_min = 0.0,
+        output_max = 1.0,
+        *,
+        invert_input = false,
+        logarithmic = false,
+        alpha = None,
+        threshold = None,
+        schmitt = None,
+        slew_rate = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_min: Float,
+        input_max: Float,
+        output_min: Float,
+        output_max: Float,
+        invert_input: bool,
+        logarithmic: bool,
+        alpha: Option<Float>,
+        threshold: Option<Float>,
+        schmitt: Option<(Float, Float)>,
+        slew_rate: Option<Float>,
+    ) -> PyResult<Self> {
+        let curve = match logarithmic {
+            false => ResponseCurve::Linear,
+            #[cfg(feature = "std-math")]
+            true => ResponseCurve::Logarithmic,
+            #[cfg(not(feature = "std-math"))]
+            true => {
+                return Err(PyValueError::new_err(
+                    "logarithmic curve requires the std-math feature",
+                ));
+            }
+        };
+
+        let filter = match alpha {
+            Some(alpha) => NoiseFilter::ExponentialMovingAverage { alpha },
+            None => NoiseFilter::None,
+        };
+
+        let hysteresis = match (threshold, schmitt) {
+            (Some(_), Some(_)) => {
+                return Err(PyValueError::new_err(
+                    "set either threshold or schmitt, not both",
+                ));
+            }
+            (Some(threshold), None) => HysteresisMode::ChangeThreshold { threshold },
+            (None, Some((rising, falling))) => HysteresisMode::SchmittTrigger { rising, falling },
+            (None, None) => HysteresisMode::none(),
+        };
+
+        let mut builder = Config::builder()
+            .input_range(input_min, input_max)
+            .output_range(output_min, output_max)
+            .invert_input(invert_input)
+            .curve(curve)
+            .filter(filter)
+            .hysteresis(hysteresis);
+        if let Some(rate) = slew_rate {
+            builder = builder.slew_rate(rate);
+        }
+
+        let config = builder.build().map_err(value_error)?;
+        Ok(Self { config })
+    }
+
+    /// `Config::builder()` code to paste into firmware.
+    pub fn to_rust(&self) -> String {
+        builder_code(&self.config)
+    }
+
+    fn __str__(&self) -> String {
+        format!("{}", self.config)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Config {}>", self.config)
+    }
+}
+
+/// A pot running the firmware pipeline, exposed to Python as `PotHead`.
+#[pyclass(name = "PotHead")]
+pub struct PyPotHead {
+    pot: PotHead<'static, Float, Float>,
+}
+
+#[pymethods]
+impl PyPotHead {
+    #[new]
+    pub fn new(config: PyConfig) -> PyResult<Self> {
+        let pot = PotHead::new(config.config).map_err(value_error)?;
+        Ok(Self { pot })
+    }
+
+    /// Process one sample.
+    pub fn update(&mut self, sample: Float) -> Float {
+        self.pot.update(sample)
+    }
+
+    /// Process a logged trace and return every intermediate output.
+    pub fn process(&mut self, samples: Vec<Float>) -> Vec<Float> {
+        self.pot.update_vec(&samples)
+    }
+
+    pub fn reset(&mut self) {
+        self.pot.reset();
+    }
+
+    /// Last output, without processing a sample.
+    #[getter]
+    pub fn output(&self) -> Float {
+        self.pot.last_output()
+    }
+
+    #[getter]
+    pub fn config(&self) -> PyConfig {
+        PyConfig {
+            config: *self.pot.config(),
+        }
+    }
+
+    /// Swap the configuration, keeping processing state where possible (see
+    /// `PotHead::set_config()`).
+    pub fn set_config(&mut self, config: PyConfig) -> PyResult<()> {
+        self.pot.set_config(config.config).map_err(value_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<PotHead {}>", self.pot)
+    }
+}
+
+#[pymodule]
+fn pot_head(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyConfig>()?;
+    module.add_class::<PyPotHead>()?;
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Change events into a `heapless::spsc::Queue`.
+//!
+//! `QueueSink` holds the producer half of a user-provided queue and pushes
+//! `(channel, output)` whenever a pot's output changes, so the task sampling
+//! the pots and the task consuming changes (MIDI, USB) only share the queue.
+//! Requires `queue-sink` feature.
+
+use heapless::spsc::Producer;
+
+use crate::bank::PotBank;
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::pothead::PotHead;
+
+/// Pushes `(channel, output)` change events into a queue.
+pub struct QueueSink<'q, TOut> {
+    producer: Producer<'q, (usize, TOut)>,
+    dropped: u32,
+}
+
+impl<'q, TOut: Copy> QueueSink<'q, TOut> {
+    pub fn new(producer: Producer<'q, (usize, TOut)>) -> Self {
+        Self {
+            producer,
+            dropped: 0,
+        }
+    }
+
+    /// Push an event. Returns false if the queue was full and the event was
+    /// dropped.
+    pub fn send(&mut self, channel: usize, output: TOut) -> bool {
+        if self.producer.enqueue((channel, output)).is_ok() {
+            return true;
+        }
+        self.dropped = self.dropped.saturating_add(1);
+        false
+    }
+
+    /// Update `pot` and push its output as `channel` if it changed.
+    pub fn update<TIn>(
+        &mut self,
+        channel: usize,
+        pot: &mut PotHead<'_, TIn, TOut>,
+        input: TIn,
+    ) -> TOut
+    where
+        TIn: Copy + PartialOrd + IntoNormalized,
+        TOut: PartialOrd + IntoNormalized + FromNormalized,
+    {
+        let before = pot.last_output_normalized();
+        let output = pot.update(input);
+        if pot.last_output_normalized() != before {
+            self.send(channel, output);
+        }
+        output
+    }
+
+    /// Push every change from the bank's last `update_all()`. Returns the
+    /// number of events pushed.
+    pub fn send_changed<const N: usize, TIn>(&mut self, bank: &PotBank<'_, N, TIn, TOut>) -> usize
+    where
+        TIn: Copy + PartialOrd + IntoNormalized,
+        TOut: PartialOrd + IntoNormalized + FromNormalized,
+    {
+        bank.changed()
+            .filter(|&(channel, output)| self.send(channel, output))
+            .count()
+    }
+
+    /// Events dropped because the queue was full (saturating).
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+
+    /// Give back the producer.
+    pub fn into_inner(self) -> Producer<'q, (usize, TOut)> {
+        self.producer
+    }
+}
@@ -0,0 +1,177 @@
+//! Ribbon controllers and soft pots.
+//!
+//! A soft pot reads garbage (floating or pulled to a rail) when nothing touches
+//! it. `RibbonHead` detects "no touch" from a valid reading window or an
+//! external gate (e.g. an FSR under the strip), holds or resets the output
+//! while untouched, and reports touch and release events.
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::pothead::PotHead;
+
+/// Output while the ribbon is not touched.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RibbonRelease<T> {
+    /// Keep the last touched value
+    Hold,
+
+    /// Return to a fixed value (e.g. center for pitch bend)
+    Reset(T),
+}
+
+/// Touch state change during one update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TouchEvent {
+    None,
+    Touch,
+    Release,
+}
+
+/// Ribbon processor with touch detection.
+///
+/// A new touch starts from the touched position: filter state is reset so the
+/// output doesn't glide over from where the last touch ended.
+#[derive(Clone)]
+pub struct RibbonHead<'a, TIn, TOut = TIn> {
+    pot: PotHead<'a, TIn, TOut>,
+    release: RibbonRelease<TOut>,
+    window: (TIn, TIn),
+    settle: u16,
+    in_window: u16,
+    touched: bool,
+    output: TOut,
+}
+
+impl<'a, TIn, TOut> RibbonHead<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Readings inside the config's input range count as touched until a
+    /// window is set with `set_window()`.
+    pub fn new(
+        config: Config<'a, TIn, TOut>,
+        release: RibbonRelease<TOut>,
+    ) -> Result<Self, ConfigError> {
+        let pot = PotHead::new(config)?;
+        let output = match release {
+            RibbonRelease::Hold => pot.last_output(),
+            RibbonRelease::Reset(value) => value,
+        };
+
+        Ok(Self {
+            pot,
+            release,
+            window: ordered(config.input_min, config.input_max),
+            settle: 1,
+            in_window: 0,
+            touched: false,
+            output,
+        })
+    }
+
+    /// Set the window of readings that count as touched (bounds inclusive).
+    pub fn set_window(&mut self, min: TIn, max: TIn) -> Result<(), ConfigError> {
+        let min_f = min.into_normalized();
+        let max_f = max.into_normalized();
+        if min_f.is_nan() || max_f.is_nan() || min_f == max_f {
+            return Err(ConfigError::InvalidInputRange {
+                min: min_f,
+                max: max_f,
+            });
+        }
+        self.window = ordered(min, max);
+        Ok(())
+    }
+
+    /// Consecutive in-window readings required before a touch registers, to
+    /// skip the unstable readings while a finger lands. Default 1; 0 acts as 1.
+    pub fn set_settle(&mut self, samples: u16) {
+        self.settle = samples.max(1);
+    }
+
+    /// Process one reading, detecting touch from the valid window.
+    pub fn update(&mut self, input: TIn) -> (TOut, TouchEvent) {
+        let (min, max) = self.window;
+        // NaN fails both comparisons
+        let in_window = input >= min && input <= max;
+        self.process(input, in_window)
+    }
+
+    /// Process one reading with touch state from an external gate. The reading
+    /// is ignored while the gate is off.
+    pub fn update_gated(&mut self, input: TIn, touched: bool) -> (TOut, TouchEvent) {
+        let touched = touched && !input.into_normalized().is_nan();
+        self.process(input, touched)
+    }
+
+    /// Most recent output, without processing a new sample.
+    pub fn last_output(&self) -> TOut {
+        self.output
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn release(&self) -> RibbonRelease<TOut> {
+        self.release
+    }
+
+    pub fn set_release(&mut self, release: RibbonRelease<TOut>) {
+        self.release = release;
+        if !self.touched
+            && let RibbonRelease::Reset(value) = release
+        {
+            self.output = value;
+        }
+    }
+
+    pub fn config(&self) -> &Config<'a, TIn, TOut> {
+        self.pot.config()
+    }
+
+    pub fn pot(&self) -> &PotHead<'a, TIn, TOut> {
+        &self.pot
+    }
+
+    pub fn pot_mut(&mut self) -> &mut PotHead<'a, TIn, TOut> {
+        &mut self.pot
+    }
+
+    fn process(&mut self, input: TIn, in_window: bool) -> (TOut, TouchEvent) {
+        if !in_window {
+            self.in_window = 0;
+            if !self.touched {
+                return (self.output, TouchEvent::None);
+            }
+
+            self.touched = false;
+            if let RibbonRelease::Reset(value) = self.release {
+                self.output = value;
+            }
+            return (self.output, TouchEvent::Release);
+        }
+
+        let mut event = TouchEvent::None;
+        if !self.touched {
+            self.in_window = self.in_window.saturating_add(1);
+            if self.in_window < self.settle {
+                return (self.output, TouchEvent::None);
+            }
+
+            self.touched = true;
+            self.pot.reset();
+            event = TouchEvent::Touch;
+        }
+
+        self.output = self.pot.update(input);
+        (self.output, event)
+    }
+}
+
+fn ordered<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if a <= b { (a, b) } else { (b, a) }
+}
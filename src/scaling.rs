@@ -0,0 +1,206 @@
+//! Range constants precomputed from a `Config`.
+//!
+//! Normalization runs on every sample, and on soft-float targets a division
+//! costs several multiplications. `PotHead` derives the reciprocals once per
+//! configuration change, so `update()` only multiplies.
+
+use crate::config::Config;
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+
+/// Maps raw inputs to 0.0..1.0 as configured by the input range,
+/// `input_center`, `end_margin` and `invert_input`.
+///
+/// Center and inversion are linear on each side of the center, so they fold
+/// into one multiply-add per sample.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InputScaling {
+    /// Clamp bounds (swapped for descending ranges)
+    lo: Float,
+    hi: Float,
+
+    /// Inputs map to `base + (input - origin) * scale`, with the scale for the
+    /// side of `origin` the input is on. Without a center, `origin` is
+    /// `input_min` and both scales match.
+    origin: Float,
+    base: Float,
+    below: Float,
+    above: Float,
+
+    /// `end_margin` and the scale stretching the travel between the margins
+    margin: Float,
+    stretch: Float,
+}
+
+impl InputScaling {
+    pub fn new<TIn: Copy + IntoNormalized, TOut>(config: &Config<'_, TIn, TOut>) -> Self {
+        let min_f = config.input_min.into_normalized();
+        let max_f = config.input_max.into_normalized();
+
+        // Scale each side of the center separately so it lands on 0.5.
+        // Safe divisions: validation keeps the range non-degenerate and the
+        // center strictly inside it
+        let (origin, base, towards_min, towards_max) = match config.input_center {
+            Some(center) => {
+                let center = center.into_normalized();
+                (center, 0.5, 0.5 / (center - min_f), 0.5 / (max_f - center))
+            }
+            None => {
+                let scale = 1.0 / (max_f - min_f);
+                (min_f, 0.0, scale, scale)
+            }
+        };
+
+        // The end margin is symmetric, so inverting before it is equivalent
+        let (base, towards_min, towards_max) = if config.invert_input {
+            (1.0 - base, -towards_min, -towards_max)
+        } else {
+            (base, towards_min, towards_max)
+        };
+
+        let (lo, hi, below, above) = if min_f < max_f {
+            (min_f, max_f, towards_min, towards_max)
+        } else {
+            (max_f, min_f, towards_max, towards_min)
+        };
+
+        Self {
+            lo,
+            hi,
+            origin,
+            base,
+            below,
+            above,
+            margin: config.end_margin,
+            stretch: 1.0 / (1.0 - 2.0 * config.end_margin),
+        }
+    }
+
+    /// Clamp to the input range and map to 0.0..1.0, applying the center,
+    /// end margin and inversion.
+    #[inline]
+    pub fn normalize(&self, input_f: Float) -> Float {
+        // The ends map exactly, free of rounding error from the reciprocals.
+        // A negative scale means the map falls from 1.0 at `lo`
+        let normalized = if input_f <= self.lo {
+            if self.below < 0.0 { 1.0 } else { 0.0 }
+        } else if input_f >= self.hi {
+            if self.above < 0.0 { 0.0 } else { 1.0 }
+        } else if input_f < self.origin {
+            self.base + (input_f - self.origin) * self.below
+        } else {
+            self.base + (input_f - self.origin) * self.above
+        };
+
+        // Stretch the travel between the margins; the center stays at 0.5
+        let stretched = if self.margin <= 0.0 {
+            normalized
+        } else if normalized <= self.margin {
+            0.0
+        } else if normalized >= 1.0 - self.margin {
+            1.0
+        } else {
+            (normalized - self.margin) * self.stretch
+        };
+
+        // Inside the range, rounding in the reciprocals can overshoot an end
+        // by a step
+        stretched.clamp(0.0, 1.0)
+    }
+
+    /// `normalize()` for `LANES` inputs at once, each with its own scaling.
+    ///
+    /// Transposes the scalings into one array per constant and selects
+    /// instead of branching, so the compiler can vectorize every step. The
+    /// arithmetic matches `normalize()`, so results are bit-identical.
+    #[cfg(feature = "simd")]
+    pub fn normalize_lanes(scalings: &[Self; LANES], inputs: &[Float; LANES]) -> [Float; LANES] {
+        let lane = |field: fn(&Self) -> Float| -> [Float; LANES] {
+            core::array::from_fn(|i| field(&scalings[i]))
+        };
+        let lo = lane(|s| s.lo);
+        let hi = lane(|s| s.hi);
+        let origin = lane(|s| s.origin);
+        let base = lane(|s| s.base);
+        let below = lane(|s| s.below);
+        let above = lane(|s| s.above);
+        let margin = lane(|s| s.margin);
+        let stretch = lane(|s| s.stretch);
+
+        let mut normalized = [0.0; LANES];
+        for i in 0..LANES {
+            let x = inputs[i];
+            let scale = if x < origin[i] { below[i] } else { above[i] };
+            let mapped = base[i] + (x - origin[i]) * scale;
+            let at_lo = if below[i] < 0.0 { 1.0 } else { 0.0 };
+            let at_hi = if above[i] < 0.0 { 0.0 } else { 1.0 };
+            let value = if x <= lo[i] {
+                at_lo
+            } else if x >= hi[i] {
+                at_hi
+            } else {
+                mapped
+            };
+
+            let stretched = if value <= margin[i] {
+                0.0
+            } else if value >= 1.0 - margin[i] {
+                1.0
+            } else {
+                (value - margin[i]) * stretch[i]
+            };
+            let result = if margin[i] <= 0.0 { value } else { stretched };
+            normalized[i] = result.clamp(0.0, 1.0);
+        }
+        normalized
+    }
+}
+
+/// Inputs normalized together by `InputScaling::normalize_lanes()`: 8 `f32`
+/// fill one AVX register, or two NEON/SSE registers.
+#[cfg(feature = "simd")]
+pub(crate) const LANES: usize = 8;
+
+/// Maps between normalized values and the output range.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OutputScaling {
+    min: Float,
+    span: Float,
+}
+
+impl OutputScaling {
+    pub fn new<TIn, TOut: Copy + IntoNormalized>(config: &Config<'_, TIn, TOut>) -> Self {
+        let min = config.output_min.into_normalized();
+        Self {
+            min,
+            span: config.output_max.into_normalized() - min,
+        }
+    }
+
+    #[inline]
+    pub fn denormalize(&self, normalized: Float) -> Float {
+        self.min + normalized * self.span
+    }
+
+    /// `denormalize()` converted to `TOut`, with the range ends `min` and `max`
+    /// returned exactly, even for outputs wider than `Float` precision.
+    #[inline]
+    pub fn output<TOut: FromNormalized>(&self, normalized: Float, min: TOut, max: TOut) -> TOut {
+        if normalized <= 0.0 {
+            return min;
+        }
+        if normalized >= 1.0 {
+            return max;
+        }
+
+        TOut::from_normalized(self.denormalize(normalized))
+    }
+
+    /// Position of `output_f` in the output range, clamped to 0.0..1.0. The
+    /// inverse of `denormalize()`.
+    #[inline]
+    pub fn normalize(&self, output_f: Float) -> Float {
+        // Safe division: validation ensures output_max != output_min
+        ((output_f - self.min) / self.span).clamp(0.0, 1.0)
+    }
+}
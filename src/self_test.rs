@@ -0,0 +1,116 @@
+//! Synthetic sweep for bring-up and hardware-in-the-loop tests.
+//!
+//! `PotHead::begin_self_test()` replaces the real input with a generated sweep
+//! from `input_min` to `input_max`; each `self_test_step()` pushes one sample
+//! through the configured pipeline. The finished `SelfTestReport` tells whether
+//! the output covered its whole range without reversing, catching configs whose
+//! hysteresis, snap zones or slew limit keep the output off its ends.
+
+use crate::float::Float;
+
+/// Output changes smaller than this (normalized) don't count as reversing.
+const MONOTONIC_EPSILON: Float = 1e-6;
+
+/// Result of a finished self-test sweep.
+///
+/// Output values are normalized: 0.0 is `output_min`, 1.0 is `output_max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelfTestReport {
+    /// Lowest and highest output seen
+    pub lowest: Float,
+    pub highest: Float,
+
+    /// The output never moved against the sweep
+    pub monotonic: bool,
+
+    /// Samples pushed through the pipeline, including the dwell at each end
+    pub samples: u32,
+}
+
+impl SelfTestReport {
+    /// How close (normalized) the output must get to each end to count as
+    /// reaching it.
+    pub const TOLERANCE: Float = 0.001;
+
+    /// The output reached both `output_min` and `output_max`.
+    pub fn covers_full_range(&self) -> bool {
+        self.lowest <= Self::TOLERANCE && self.highest >= 1.0 - Self::TOLERANCE
+    }
+
+    /// Full range covered monotonically.
+    pub fn passed(&self) -> bool {
+        self.monotonic && self.covers_full_range()
+    }
+}
+
+/// Sweep progress: dwell at `input_min`, ramp over `steps` samples, dwell at
+/// `input_max`. The dwells last half the ramp, long enough for filters to settle.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SelfTest {
+    steps: u32,
+    index: u32,
+
+    lowest: Float,
+    highest: Float,
+    last: Option<Float>,
+    rose: bool,
+    fell: bool,
+}
+
+impl SelfTest {
+    pub fn new(steps: u16) -> Self {
+        Self {
+            steps: steps.max(2) as u32,
+            index: 0,
+            lowest: Float::INFINITY,
+            highest: Float::NEG_INFINITY,
+            last: None,
+            rose: false,
+            fell: false,
+        }
+    }
+
+    fn dwell(&self) -> u32 {
+        self.steps / 2
+    }
+
+    /// Sweep position (0.0 = `input_min`) of the next sample.
+    pub fn position(&self) -> Float {
+        let ramp = self.index.saturating_sub(self.dwell());
+        if self.index < self.dwell() {
+            0.0
+        } else if ramp < self.steps {
+            ramp as Float / (self.steps - 1) as Float
+        } else {
+            1.0
+        }
+    }
+
+    /// Record the normalized output for the sample at `position()`.
+    pub fn record(&mut self, output: Float) {
+        self.index += 1;
+        self.lowest = self.lowest.min(output);
+        self.highest = self.highest.max(output);
+
+        if let Some(last) = self.last {
+            self.rose |= output > last + MONOTONIC_EPSILON;
+            self.fell |= output < last - MONOTONIC_EPSILON;
+        }
+        self.last = Some(output);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.index >= self.steps + 2 * self.dwell()
+    }
+
+    /// Either direction is monotonic, so inverted inputs and outputs pass too.
+    pub fn report(&self) -> SelfTestReport {
+        SelfTestReport {
+            lowest: self.lowest,
+            highest: self.highest,
+            monotonic: !(self.rose && self.fell),
+            samples: self.index,
+        }
+    }
+}
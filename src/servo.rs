@@ -0,0 +1,117 @@
+//! Motorized fader positioning.
+//!
+//! `FaderServo` turns a target output and the fader's current position into a
+//! signed motor duty. It works in fader travel (before the response curve), so
+//! the approach behaves the same whatever curve the pot uses.
+
+use crate::config::ConfigError;
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// Proportional fader positioning with a dead band and approach slow-down.
+///
+/// Distances are normalized fader travel (0.0..1.0).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FaderServo {
+    /// Stop when this close to the target
+    pub dead_band: Float,
+
+    /// Ramp the duty down from `max_duty` to `min_duty` within this distance
+    pub slow_zone: Float,
+
+    /// Smallest duty that still moves the fader (overcomes friction)
+    pub min_duty: Float,
+
+    /// Duty far from the target
+    pub max_duty: Float,
+}
+
+impl FaderServo {
+    /// Starting point for a typical 100 mm motor fader.
+    pub const DEFAULT: Self = Self {
+        dead_band: 0.005,
+        slow_zone: 0.1,
+        min_duty: 0.3,
+        max_duty: 1.0,
+    };
+
+    pub const fn validate(&self) -> Result<(), ConfigError> {
+        let reason = if !(self.dead_band >= 0.0 && self.dead_band < 1.0) {
+            "dead_band must be in range [0.0, 1.0)"
+        } else if self.slow_zone.is_nan() || self.slow_zone < self.dead_band {
+            "slow_zone must be >= dead_band"
+        } else if !(self.min_duty >= 0.0 && self.min_duty <= self.max_duty) {
+            "min_duty must be in range [0.0, max_duty]"
+        } else if self.max_duty > 1.0 {
+            "max_duty must be <= 1.0"
+        } else {
+            return Ok(());
+        };
+        Err(ConfigError::InvalidServo { reason })
+    }
+
+    /// Signed duty (-1.0..=1.0) to move from `position` to `target`, both in
+    /// normalized travel. Positive moves towards 1.0; 0.0 inside the dead band.
+    pub fn drive(&self, target: Float, position: Float) -> Float {
+        let error = target - position;
+        let distance = error.abs();
+
+        if distance.is_nan() || distance <= self.dead_band {
+            return 0.0;
+        }
+
+        let duty = if distance >= self.slow_zone {
+            self.max_duty
+        } else {
+            let ramp = (distance - self.dead_band) / (self.slow_zone - self.dead_band);
+            self.min_duty + (self.max_duty - self.min_duty) * ramp
+        };
+
+        if error < 0.0 { -duty } else { duty }
+    }
+
+    /// Signed duty to move `pot`'s fader until it outputs `target`.
+    ///
+    /// The target is mapped back through the output range and the inverse curve
+    /// and compared with `pot.filtered_position()`, so call it after `update()`.
+    /// Stops as soon as the pot outputs `target`, e.g. on entering a snap zone
+    /// or selecting a `Config::values` entry.
+    pub fn drive_to<TIn, TOut>(&self, pot: &PotHead<'_, TIn, TOut>, target: TOut) -> Float
+    where
+        TIn: Copy + PartialOrd + IntoNormalized,
+        TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+    {
+        if pot.last_output() == target {
+            return 0.0;
+        }
+        self.drive(travel(pot, target), pot.filtered_position())
+    }
+
+    /// Returns true if `pot` outputs `target` or its fader is within the dead
+    /// band of it.
+    pub fn at_target<TIn, TOut>(&self, pot: &PotHead<'_, TIn, TOut>, target: TOut) -> bool
+    where
+        TIn: Copy + PartialOrd + IntoNormalized,
+        TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+    {
+        pot.last_output() == target
+            || (travel(pot, target) - pot.filtered_position()).abs() <= self.dead_band
+    }
+}
+
+impl Default for FaderServo {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Fader travel at which `pot` outputs `target`.
+fn travel<TIn, TOut>(pot: &PotHead<'_, TIn, TOut>, target: TOut) -> Float
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    pot.config().curve.inverse(pot.normalize_output(target))
+}
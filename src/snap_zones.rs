@@ -2,8 +2,11 @@
 //!
 //! Operates on normalized values (0.0-1.0) in the processing pipeline.
 
+use crate::float::Float;
+
 /// Snap zone behavior types
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SnapZoneType {
     /// Snap to target value when within threshold
     Snap,
@@ -15,6 +18,7 @@ pub enum SnapZoneType {
 /// Snap zone configuration.
 /// Defines a target value and threshold range around it.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct SnapZone<T> {
     pub target: T,
     pub threshold: T,
@@ -62,6 +66,20 @@ where
     }
 }
 
+impl SnapZone<Float> {
+    /// Validate zone configuration at compile time
+    pub const fn validate(&self) -> Result<(), &'static str> {
+        if self.threshold.is_nan() || self.threshold < 0.0 {
+            return Err("Snap zone threshold must be >= 0.0");
+        }
+        // Snap zones output their target
+        if !(self.target >= 0.0 && self.target <= 1.0) {
+            return Err("Snap zone target must be in range [0.0, 1.0]");
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +125,25 @@ mod tests {
         assert!(zone3.overlaps(&zone1)); // Symmetric
     }
 
+    #[test]
+    fn test_snap_zone_validate() {
+        assert!(
+            SnapZone::new(0.5, 0.1, SnapZoneType::Snap)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            SnapZone::new(0.5, 0.0, SnapZoneType::Snap)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            SnapZone::new(0.5, -0.1, SnapZoneType::Snap)
+                .validate()
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_snap_zone_edge_cases() {
         let zone = SnapZone::new(0.0, 0.02, SnapZoneType::Snap);
@@ -0,0 +1,183 @@
+//! Persistent pot state.
+//!
+//! `StateSnapshot` holds what should survive a power cycle: the input
+//! calibration, the output, the filter position and the grab mode virtual
+//! value. It is plain data, so it can be written to EEPROM or flash as is, with
+//! the compact versioned `to_bytes()` encoding, or with the `serde` feature
+//! through any serde format such as postcard.
+
+use crate::calibration::CalibrationData;
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+
+/// State saved by `PotHead::save_state()` and applied by `restore_state()`.
+///
+/// All fields are present regardless of enabled features, so a snapshot saved
+/// by one firmware build can be restored by another.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateSnapshot<TIn> {
+    /// Input range, center, end margin and inversion
+    pub calibration: CalibrationData<TIn>,
+
+    /// Last output, normalized
+    pub output: Float,
+
+    /// Filtered input position to seed the filter with, `None` if the pot was
+    /// never updated
+    pub filtered: Option<Float>,
+
+    /// Grab mode virtual value, normalized (the output without `grab-mode`)
+    pub virtual_value: Float,
+
+    /// Grab mode: whether the pot had grabbed the virtual value (`true` without
+    /// `grab-mode`)
+    pub grabbed: bool,
+}
+
+/// Why `StateSnapshot::from_bytes()` or `CalibrationData::from_bytes()`
+/// rejected a record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SnapshotError {
+    /// Fewer than `SIZE` bytes.
+    TooShort,
+
+    /// Written by a different format version, e.g. erased EEPROM (0xFF).
+    UnsupportedVersion(u8),
+
+    /// Checksum mismatch, e.g. power lost during the write.
+    Corrupted,
+}
+
+impl core::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SnapshotError::TooShort => f.write_str("snapshot record too short"),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            SnapshotError::Corrupted => f.write_str("snapshot checksum mismatch"),
+        }
+    }
+}
+
+impl core::error::Error for SnapshotError {}
+
+/// Encoded length, see `StateSnapshot::SIZE`
+const SIZE: usize = 25;
+
+// Bits 0 and 1 are `CalibrationData` flags
+const GRABBED: u8 = 1 << 2;
+const HAS_FILTERED: u8 = 1 << 3;
+
+/// Compact binary encoding for EEPROM and flash.
+///
+/// Layout (little-endian): version, flags, the calibration's input
+/// min/max/center and end margin as `f32`, output, filtered position and
+/// virtual value as `u16` fractions of 65535,
+/// then a CRC-8 over the preceding bytes. The input values are stored as `f32`
+/// whatever `Float` is, so integer inputs round-trip exactly up to 24 bits;
+/// normalized values keep 16-bit resolution.
+impl<TIn: IntoNormalized + FromNormalized> StateSnapshot<TIn> {
+    /// Encoded length in bytes.
+    pub const SIZE: usize = SIZE;
+
+    /// Format version written to the first byte.
+    pub const VERSION: u8 = 2;
+
+    pub fn to_bytes(&self) -> [u8; SIZE] {
+        let mut flags = self.calibration.flags();
+        if self.grabbed {
+            flags |= GRABBED;
+        }
+        if self.filtered.is_some() {
+            flags |= HAS_FILTERED;
+        }
+
+        let mut bytes = [0; SIZE];
+        bytes[0] = Self::VERSION;
+        bytes[1] = flags;
+        self.calibration.write_inputs(&mut bytes[2..18]);
+        bytes[18..20].copy_from_slice(&encode_unit(self.output));
+        bytes[20..22].copy_from_slice(&encode_unit(self.filtered.unwrap_or(0.0)));
+        bytes[22..24].copy_from_slice(&encode_unit(self.virtual_value));
+        bytes[24] = crc8(&bytes[..24]);
+        bytes
+    }
+
+    /// Decode a record from `to_bytes()`. Extra trailing bytes are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let Some(bytes) = bytes.get(..SIZE) else {
+            return Err(SnapshotError::TooShort);
+        };
+        if bytes[0] != Self::VERSION {
+            return Err(SnapshotError::UnsupportedVersion(bytes[0]));
+        }
+        if crc8(&bytes[..24]) != bytes[24] {
+            return Err(SnapshotError::Corrupted);
+        }
+
+        let flags = bytes[1];
+        let unit = |at: usize| decode_unit([bytes[at], bytes[at + 1]]);
+
+        Ok(Self {
+            calibration: CalibrationData::read_inputs(&bytes[2..18], flags),
+            output: unit(18),
+            filtered: (flags & HAS_FILTERED != 0).then(|| unit(20)),
+            virtual_value: unit(22),
+            grabbed: flags & GRABBED != 0,
+        })
+    }
+}
+
+/// Normalized 0.0..=1.0 to a `u16` fraction, rounded to nearest. NaN becomes 0.
+fn encode_unit(value: Float) -> [u8; 2] {
+    let scaled = if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0) * u16::MAX as Float + 0.5
+    };
+    (scaled as u16).to_le_bytes()
+}
+
+fn decode_unit(bytes: [u8; 2]) -> Float {
+    u16::from_le_bytes(bytes) as Float / u16::MAX as Float
+}
+
+#[cfg(not(feature = "f64"))]
+pub(crate) fn to_wire(value: Float) -> f32 {
+    value
+}
+
+#[cfg(not(feature = "f64"))]
+pub(crate) fn from_wire(value: f32) -> Float {
+    value
+}
+
+#[cfg(feature = "f64")]
+pub(crate) fn to_wire(value: Float) -> f32 {
+    value as f32
+}
+
+#[cfg(feature = "f64")]
+pub(crate) fn from_wire(value: f32) -> Float {
+    value as Float
+}
+
+/// CRC-8 (polynomial 0x07).
+pub(crate) fn crc8(bytes: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in bytes {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
@@ -0,0 +1,150 @@
+//! Split a `PotHead` into a sampling half and a reading half.
+//!
+//! The sampler runs the pipeline (e.g. in an ADC interrupt) and publishes each
+//! output to an `OutputSlot`; the reader picks up the latest value elsewhere.
+//! The handoff only uses 32-bit atomic loads and stores, so it also works on
+//! targets without compare-and-swap (e.g. Cortex-M0).
+
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::float::Float;
+use crate::pothead::PotHead;
+
+/// Lock-free single-producer/single-consumer slot holding the latest output.
+///
+/// Place it in a `static` so both halves can be moved to different contexts.
+pub struct OutputSlot {
+    value: AtomicU32,
+    dirty: AtomicBool,
+}
+
+impl OutputSlot {
+    pub const fn new() -> Self {
+        Self {
+            value: AtomicU32::new(0),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    fn publish(&self, value: Float) {
+        self.value.store(to_bits(value), Ordering::Release);
+        self.dirty.store(true, Ordering::Release);
+    }
+
+    fn load(&self) -> Float {
+        from_bits(self.value.load(Ordering::Acquire))
+    }
+}
+
+impl Default for OutputSlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sampling half: feeds inputs through the pipeline and publishes the result.
+pub struct PotSampler<'a, 's, TIn, TOut = TIn> {
+    pot: PotHead<'a, TIn, TOut>,
+    slot: &'s OutputSlot,
+}
+
+/// Reading half: returns the latest published output.
+pub struct PotReader<'s, TOut> {
+    slot: &'s OutputSlot,
+    _output: PhantomData<TOut>,
+}
+
+impl<'a, TIn, TOut> PotHead<'a, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Split into a sampler and a reader sharing `slot`.
+    pub fn split<'s>(
+        self,
+        slot: &'s OutputSlot,
+    ) -> (PotSampler<'a, 's, TIn, TOut>, PotReader<'s, TOut>) {
+        slot.value.store(
+            to_bits(self.last_output().into_normalized()),
+            Ordering::Relaxed,
+        );
+        slot.dirty.store(false, Ordering::Release);
+
+        (
+            PotSampler { pot: self, slot },
+            PotReader {
+                slot,
+                _output: PhantomData,
+            },
+        )
+    }
+}
+
+impl<'a, TIn, TOut> PotSampler<'a, '_, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+{
+    /// Process a sample and publish the output to the reader.
+    pub fn update(&mut self, input: TIn) -> TOut {
+        let output = self.pot.update(input);
+        self.slot.publish(output.into_normalized());
+        output
+    }
+
+    /// Access the pot, e.g. to reconfigure it. Changes made here are not
+    /// published until the next `update()`.
+    pub fn pot_mut(&mut self) -> &mut PotHead<'a, TIn, TOut> {
+        &mut self.pot
+    }
+
+    /// Give back the pot.
+    pub fn into_inner(self) -> PotHead<'a, TIn, TOut> {
+        self.pot
+    }
+}
+
+impl<TOut: FromNormalized> PotReader<'_, TOut> {
+    /// Latest published output.
+    pub fn read(&self) -> TOut {
+        TOut::from_normalized(self.slot.load())
+    }
+
+    /// Returns true if a new output was published since the last `take()`.
+    pub fn has_new(&self) -> bool {
+        self.slot.dirty.load(Ordering::Acquire)
+    }
+
+    /// Latest output if one was published since the last `take()`.
+    pub fn take(&self) -> Option<TOut> {
+        if !self.slot.dirty.load(Ordering::Acquire) {
+            return None;
+        }
+        // Clear before reading, so a publish in between is never lost
+        self.slot.dirty.store(false, Ordering::Release);
+        Some(self.read())
+    }
+}
+
+#[cfg(not(feature = "f64"))]
+fn to_bits(value: Float) -> u32 {
+    value.to_bits()
+}
+
+#[cfg(not(feature = "f64"))]
+fn from_bits(bits: u32) -> Float {
+    Float::from_bits(bits)
+}
+
+// The handoff stays 32 bits wide so it works with 32-bit atomics only
+#[cfg(feature = "f64")]
+fn to_bits(value: Float) -> u32 {
+    (value as f32).to_bits()
+}
+
+#[cfg(feature = "f64")]
+fn from_bits(bits: u32) -> Float {
+    f32::from_bits(bits) as Float
+}
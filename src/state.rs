@@ -2,9 +2,14 @@ use crate::hysteresis::HysteresisState;
 
 use crate::filters::EmaFilter;
 
+#[cfg(feature = "taps")]
+use crate::pipeline::PipelineTaps;
+
 #[cfg(feature = "moving-average")]
 use crate::filters::MovingAvgFilter;
 
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct State<T> {
     /// Hysteresis processing state
     pub hysteresis: HysteresisState<T>,
@@ -19,6 +24,36 @@ pub struct State<T> {
     /// Last output value (for dead zones)
     pub last_output: T,
 
+    /// Last output before slew limiting (dead zone hold reference)
+    pub last_target: T,
+
+    /// Slew-limited output, `None` until the first update
+    pub slewed: Option<T>,
+
+    /// Last snap zone output before the curve (for dead zones with `StageOrder::PhysicalFirst`)
+    pub last_snapped: T,
+
+    /// Filtered position from the last update (for velocity)
+    pub last_filtered: Option<T>,
+
+    /// Filtered movement during the last update (per update, or per second after `update_timed()`)
+    pub velocity: T,
+
+    /// Output changed since the last `take_output()`
+    pub dirty: bool,
+
+    /// Updates since the output last changed (saturating)
+    pub updates_since_change: u32,
+
+    /// Last step index from `update_stepped()`
+    pub last_step: Option<usize>,
+
+    /// Index into `Config::values` selected by the last update
+    pub value_index: Option<usize>,
+
+    /// Index of the snap zone the value was in after the last update
+    pub active_zone: Option<usize>,
+
     /// Grab mode: whether pot has been grabbed
     #[cfg(feature = "grab-mode")]
     pub grabbed: bool,
@@ -38,6 +73,22 @@ pub struct State<T> {
     /// Grab mode: whether we've initialized last_physical (for PassThrough first read)
     #[cfg(feature = "grab-mode")]
     pub passthrough_initialized: bool,
+
+    /// Grab mode: re-grab on next update if still at the released position
+    #[cfg(feature = "grab-mode")]
+    pub regrab_pending: bool,
+
+    /// Fine adjustment: position before remapping from the last update
+    #[cfg(feature = "fine-adjust")]
+    pub fine_last: Option<T>,
+
+    /// Fine adjustment: remapped position, moved by a fraction of the knob movement
+    #[cfg(feature = "fine-adjust")]
+    pub fine_position: T,
+
+    /// Value after each stage from the last update
+    #[cfg(feature = "taps")]
+    pub taps: PipelineTaps<T>,
 }
 
 impl<T> Default for State<T>
@@ -51,6 +102,16 @@ where
             #[cfg(feature = "moving-average")]
             ma_filter: None,
             last_output: T::default(),
+            last_target: T::default(),
+            slewed: None,
+            last_snapped: T::default(),
+            last_filtered: None,
+            velocity: T::default(),
+            dirty: false,
+            updates_since_change: 0,
+            last_step: None,
+            value_index: None,
+            active_zone: None,
             #[cfg(feature = "grab-mode")]
             grabbed: false,
             #[cfg(feature = "grab-mode")]
@@ -61,6 +122,14 @@ where
             last_physical: T::default(),
             #[cfg(feature = "grab-mode")]
             passthrough_initialized: false,
+            #[cfg(feature = "grab-mode")]
+            regrab_pending: false,
+            #[cfg(feature = "fine-adjust")]
+            fine_last: None,
+            #[cfg(feature = "fine-adjust")]
+            fine_position: T::default(),
+            #[cfg(feature = "taps")]
+            taps: PipelineTaps::default(),
         }
     }
 }
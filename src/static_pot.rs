@@ -0,0 +1,317 @@
+//! Pipeline with stages chosen at compile time.
+//!
+//! `StaticPotHead` runs normalize → filter → curve → hysteresis with each stage
+//! as a type parameter, so `update()` is monomorphized with no runtime dispatch.
+//! Snap zones and grab mode are not supported; use `PotHead` for those.
+
+use core::marker::PhantomData;
+
+use crate::config::{Config, ConfigError};
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::filters::{EmaFilter, NoiseFilter};
+use crate::float::Float;
+use crate::hysteresis::{HysteresisMode, SchmittState};
+use crate::scaling::{InputScaling, OutputScaling};
+
+#[cfg(any(feature = "std-math", feature = "log-lut"))]
+use crate::curves::ResponseCurve;
+
+#[cfg(feature = "moving-average")]
+use crate::filters::MovingAvgFilter;
+
+/// Response curve stage.
+pub trait CurveStage {
+    fn apply(value: Float) -> Float;
+}
+
+/// Noise filter stage.
+pub trait FilterStage {
+    fn apply(&mut self, value: Float) -> Float;
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        Ok(())
+    }
+}
+
+/// Hysteresis stage.
+pub trait HysteresisStage {
+    fn apply(&mut self, value: Float) -> Float;
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        Ok(())
+    }
+}
+
+/// Linear response (1:1 mapping).
+pub struct Linear;
+
+impl CurveStage for Linear {
+    #[inline]
+    fn apply(value: Float) -> Float {
+        value
+    }
+}
+
+/// Logarithmic response (audio taper). Requires `std-math` feature.
+#[cfg(feature = "std-math")]
+pub struct Logarithmic;
+
+#[cfg(feature = "std-math")]
+impl CurveStage for Logarithmic {
+    #[inline]
+    fn apply(value: Float) -> Float {
+        ResponseCurve::Logarithmic.apply(value)
+    }
+}
+
+/// Logarithmic response from a lookup table. Requires `log-lut` feature.
+#[cfg(feature = "log-lut")]
+pub struct LogarithmicLut;
+
+#[cfg(feature = "log-lut")]
+impl CurveStage for LogarithmicLut {
+    #[inline]
+    fn apply(value: Float) -> Float {
+        ResponseCurve::LogarithmicLut.apply(value)
+    }
+}
+
+/// No filtering.
+pub struct NoFilter;
+
+impl FilterStage for NoFilter {
+    #[inline]
+    fn apply(&mut self, value: Float) -> Float {
+        value
+    }
+}
+
+/// Exponential moving average.
+pub struct Ema {
+    alpha: Float,
+    filter: EmaFilter,
+}
+
+impl Ema {
+    pub const fn new(alpha: Float) -> Self {
+        Self {
+            alpha,
+            filter: EmaFilter::new(),
+        }
+    }
+}
+
+impl FilterStage for Ema {
+    #[inline]
+    fn apply(&mut self, value: Float) -> Float {
+        self.filter.apply(value, self.alpha)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        NoiseFilter::ExponentialMovingAverage { alpha: self.alpha }
+            .validate()
+            .map_err(|reason| ConfigError::InvalidFilter { reason })
+    }
+}
+
+/// Simple moving average. Requires `moving-average` feature.
+#[cfg(feature = "moving-average")]
+pub struct MovingAverage {
+    window_size: usize,
+    filter: MovingAvgFilter,
+}
+
+#[cfg(feature = "moving-average")]
+impl MovingAverage {
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window_size,
+            filter: MovingAvgFilter::new(window_size.clamp(1, 32)),
+        }
+    }
+}
+
+#[cfg(feature = "moving-average")]
+impl FilterStage for MovingAverage {
+    #[inline]
+    fn apply(&mut self, value: Float) -> Float {
+        self.filter.apply(value)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        NoiseFilter::MovingAverage {
+            window_size: self.window_size,
+        }
+        .validate()
+        .map_err(|reason| ConfigError::InvalidFilter { reason })
+    }
+}
+
+/// No hysteresis.
+pub struct NoHysteresis;
+
+impl HysteresisStage for NoHysteresis {
+    #[inline]
+    fn apply(&mut self, value: Float) -> Float {
+        value
+    }
+}
+
+/// Ignore changes smaller than the threshold.
+pub struct ChangeThreshold {
+    threshold: Float,
+    last_output: Float,
+}
+
+impl ChangeThreshold {
+    pub const fn new(threshold: Float) -> Self {
+        Self {
+            threshold,
+            last_output: 0.0,
+        }
+    }
+}
+
+impl HysteresisStage for ChangeThreshold {
+    #[inline]
+    fn apply(&mut self, value: Float) -> Float {
+        let diff = if value > self.last_output {
+            value - self.last_output
+        } else {
+            self.last_output - value
+        };
+
+        if diff > self.threshold {
+            self.last_output = value;
+        }
+        self.last_output
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        HysteresisMode::ChangeThreshold {
+            threshold: self.threshold,
+        }
+        .validate_normalized()
+        .map_err(|reason| ConfigError::InvalidHysteresis { reason })
+    }
+}
+
+/// Separate rising/falling thresholds.
+pub struct SchmittTrigger {
+    rising: Float,
+    falling: Float,
+    state: SchmittState,
+}
+
+impl SchmittTrigger {
+    pub const fn new(rising: Float, falling: Float) -> Self {
+        Self {
+            rising,
+            falling,
+            state: SchmittState::Low,
+        }
+    }
+}
+
+impl HysteresisStage for SchmittTrigger {
+    #[inline]
+    fn apply(&mut self, value: Float) -> Float {
+        if value >= self.rising {
+            self.state = SchmittState::High;
+        } else if value <= self.falling {
+            self.state = SchmittState::Low;
+        }
+
+        match self.state {
+            SchmittState::High => self.rising,
+            SchmittState::Low => self.falling,
+        }
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        HysteresisMode::SchmittTrigger {
+            rising: self.rising,
+            falling: self.falling,
+        }
+        .validate_normalized()
+        .map_err(|reason| ConfigError::InvalidHysteresis { reason })
+    }
+}
+
+/// Potentiometer processor with the curve, filter and hysteresis fixed by type.
+///
+/// ```ignore
+/// let mut pot: StaticPotHead<u16, f32, Logarithmic, Ema, ChangeThreshold> =
+///     StaticPotHead::new((0, 4095), (0.0, 1.0), Ema::new(0.3), ChangeThreshold::new(0.01))?;
+/// ```
+pub struct StaticPotHead<TIn, TOut, C, F, H> {
+    input: InputScaling,
+    output: OutputScaling,
+    output_min: TOut,
+    output_max: TOut,
+    filter: F,
+    hysteresis: H,
+    last_output: Float,
+    _input: PhantomData<TIn>,
+    _curve: PhantomData<C>,
+}
+
+impl<TIn, TOut, C, F, H> StaticPotHead<TIn, TOut, C, F, H>
+where
+    TIn: Copy + PartialOrd + IntoNormalized,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+    C: CurveStage,
+    F: FilterStage,
+    H: HysteresisStage,
+{
+    /// Create a pot, validating ranges and stage parameters like `PotHead::new()`.
+    pub fn new(
+        input_range: (TIn, TIn),
+        output_range: (TOut, TOut),
+        filter: F,
+        hysteresis: H,
+    ) -> Result<Self, ConfigError> {
+        // The builder's defaults are valid, so only the ranges need checking
+        let config = Config::builder()
+            .input_range(input_range.0, input_range.1)
+            .output_range(output_range.0, output_range.1)
+            .assemble()?;
+        config.validate_ranges()?;
+        filter.validate()?;
+        hysteresis.validate()?;
+
+        Ok(Self {
+            input: InputScaling::new(&config),
+            output: OutputScaling::new(&config),
+            output_min: output_range.0,
+            output_max: output_range.1,
+            filter,
+            hysteresis,
+            last_output: 0.0,
+            _input: PhantomData,
+            _curve: PhantomData,
+        })
+    }
+
+    /// Process one input sample. A NaN input is ignored and returns the last output.
+    pub fn update(&mut self, input: TIn) -> TOut {
+        let input_f = input.into_normalized();
+        if input_f.is_nan() {
+            return self.last_output();
+        }
+
+        let normalized = self.input.normalize(input_f);
+
+        let filtered = self.filter.apply(normalized);
+        let curved = C::apply(filtered);
+        self.last_output = self.hysteresis.apply(curved);
+
+        self.last_output()
+    }
+
+    /// Most recent output, without processing a new sample.
+    pub fn last_output(&self) -> TOut {
+        self.output
+            .output(self.last_output, self.output_min, self.output_max)
+    }
+}
@@ -0,0 +1,82 @@
+//! Usage statistics reported by `PotHead::stats()`.
+//!
+//! Counts how much a control is actually used, for wear estimation and UX
+//! research. Movement is measured on the filtered position, before the curve,
+//! so it reflects mechanical travel.
+
+use crate::float::Float;
+use crate::motion::Direction;
+
+/// Movement of the filtered position below this (normalized) doesn't count as
+/// reversing direction, so noise around a resting position isn't counted.
+const REVERSAL_THRESHOLD: Float = 0.01;
+
+/// Usage counters since creation or the last `PotHead::clear_stats()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UsageStats {
+    /// Total filtered movement in full sweeps (1.0 = end to end once)
+    pub travel: Float,
+
+    /// Times the pot reversed direction by more than 1% of its travel
+    pub direction_changes: u32,
+
+    /// Samples processed (NaN inputs excluded)
+    pub updates: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct UsageTracker {
+    /// Whole sweeps and the fraction on top, so small steps aren't lost once
+    /// the total grows large
+    sweeps: u32,
+    fraction: Float,
+
+    direction_changes: u32,
+    updates: u32,
+
+    /// Direction of the current run and the furthest position reached in it
+    direction: Direction,
+    extreme: Option<Float>,
+}
+
+impl UsageTracker {
+    /// Record one update with the filtered position and its change.
+    pub fn record(&mut self, position: Float, delta: Float) {
+        self.updates = self.updates.saturating_add(1);
+
+        self.fraction += delta.abs();
+        if self.fraction >= 1.0 {
+            let whole = self.fraction as u32;
+            self.sweeps = self.sweeps.saturating_add(whole);
+            self.fraction -= whole as Float;
+        }
+
+        let Some(extreme) = self.extreme else {
+            self.extreme = Some(position);
+            return;
+        };
+
+        let (direction, extreme) = match self.direction {
+            Direction::Increasing if position > extreme => (Direction::Increasing, position),
+            Direction::Decreasing if position < extreme => (Direction::Decreasing, position),
+            _ if position > extreme + REVERSAL_THRESHOLD => (Direction::Increasing, position),
+            _ if position < extreme - REVERSAL_THRESHOLD => (Direction::Decreasing, position),
+            direction => (direction, extreme),
+        };
+
+        if self.direction != Direction::Stationary && direction != self.direction {
+            self.direction_changes = self.direction_changes.saturating_add(1);
+        }
+        self.direction = direction;
+        self.extreme = Some(extreme);
+    }
+
+    pub fn stats(&self) -> UsageStats {
+        UsageStats {
+            travel: self.sweeps as Float + self.fraction,
+            direction_changes: self.direction_changes,
+            updates: self.updates,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Flat, display-friendly view of a `Config`.
+//!
+//! Lets UIs and debug logs show a configuration without matching on every enum.
+
+use core::fmt;
+
+use crate::float::Float;
+use crate::pipeline::StageOrder;
+use crate::snap_zones::{SnapZone, SnapZoneType};
+
+/// Summary of a configuration, from `Config::describe()`.
+///
+/// Ranges are converted to `Float`; thresholds and zone spans are normalized (0.0-1.0).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConfigSummary<'a> {
+    pub input_range: (Float, Float),
+    pub output_range: (Float, Float),
+    pub invert_input: bool,
+    pub curve: &'static str,
+    pub filter: &'static str,
+    pub hysteresis: &'static str,
+
+    /// Change threshold (`ChangeThreshold` only).
+    pub change_threshold: Option<Float>,
+
+    /// `(falling, rising)` levels (`SchmittTrigger` only).
+    pub schmitt_levels: Option<(Float, Float)>,
+
+    pub stage_order: StageOrder,
+
+    #[cfg(feature = "grab-mode")]
+    pub grab_mode: &'static str,
+
+    #[cfg(feature = "grab-mode")]
+    pub grab_margin: Float,
+
+    pub(crate) snap_zones: &'a [SnapZone<Float>],
+}
+
+/// Normalized span covered by a snap zone, clamped to 0.0-1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ZoneSpan {
+    pub min: Float,
+    pub max: Float,
+    pub zone_type: SnapZoneType,
+}
+
+impl<'a> ConfigSummary<'a> {
+    /// Number of snap zones.
+    pub fn zone_count(&self) -> usize {
+        self.snap_zones.len()
+    }
+
+    /// Spans of all snap zones, in processing order.
+    pub fn zone_spans(&self) -> impl Iterator<Item = ZoneSpan> + 'a {
+        self.snap_zones.iter().map(|zone| ZoneSpan {
+            min: (zone.target - zone.threshold).max(0.0),
+            max: (zone.target + zone.threshold).min(1.0),
+            zone_type: zone.zone_type,
+        })
+    }
+}
+
+impl fmt::Display for ConfigSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}..{} -> {}..{}",
+            self.input_range.0, self.input_range.1, self.output_range.0, self.output_range.1
+        )?;
+        if self.invert_input {
+            f.write_str(" inverted")?;
+        }
+        write!(f, ", curve: {}, filter: {}", self.curve, self.filter)?;
+
+        f.write_str(", hysteresis: ")?;
+        match (self.change_threshold, self.schmitt_levels) {
+            (Some(threshold), _) => write!(f, "{} {}", self.hysteresis, threshold)?,
+            (_, Some((falling, rising))) => {
+                write!(f, "{} {}/{}", self.hysteresis, falling, rising)?
+            }
+            _ => f.write_str(self.hysteresis)?,
+        }
+
+        write!(f, ", zones: {}", self.zone_count())?;
+
+        #[cfg(feature = "grab-mode")]
+        write!(f, ", grab: {}", self.grab_mode)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+//! Deterministic noise and pot motion for tests.
+//!
+//! Filters and hysteresis are tuned against noise, so their tests need noise
+//! that repeats from run to run. `Signal` combines a `Motion` (where the wiper
+//! is) with a `Noise` model (what the ADC adds) and yields normalized samples,
+//! or raw ADC counts through `adc()`. Everything is seeded and allocation-free,
+//! so the same signals drive host tests and on-target firmware tests.
+
+use crate::convert::FromNormalized;
+use crate::float::Float;
+
+/// Xorshift PRNG: the same seed always gives the same sequence.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u32,
+}
+
+impl Rng {
+    /// Xorshift never leaves zero, so a zero seed is replaced.
+    pub const fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 { 0x2545_f491 } else { seed },
+        }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Uniform in 0.0..1.0.
+    pub fn next_float(&mut self) -> Float {
+        (self.next_u32() >> 8) as Float / (1_u32 << 24) as Float
+    }
+
+    /// Approximately standard normal: the sum of 12 uniforms minus 6
+    /// (Irwin-Hall), so no `libm` is needed. Never beyond ±6.
+    pub fn next_gaussian(&mut self) -> Float {
+        (0..12).map(|_| self.next_float()).sum::<Float>() - 6.0
+    }
+}
+
+/// Noise added to each sample, in normalized units (1.0 = full travel).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Noise {
+    None,
+
+    /// Uniform in ±`amplitude`, e.g. ADC quantization and reference ripple
+    Uniform {
+        amplitude: Float,
+    },
+
+    /// Gaussian with standard deviation `sigma`, e.g. thermal noise
+    Gaussian {
+        sigma: Float,
+    },
+
+    /// ±`amplitude` on a `probability` fraction of samples and clean otherwise,
+    /// e.g. a dirty wiper or EMI bursts
+    Spikes {
+        probability: Float,
+        amplitude: Float,
+    },
+}
+
+impl Noise {
+    /// Offset to add to the next sample.
+    pub fn sample(&self, rng: &mut Rng) -> Float {
+        match *self {
+            Noise::None => 0.0,
+            Noise::Uniform { amplitude } => (rng.next_float() * 2.0 - 1.0) * amplitude,
+            Noise::Gaussian { sigma } => rng.next_gaussian() * sigma,
+            Noise::Spikes {
+                probability,
+                amplitude,
+            } => {
+                if rng.next_float() >= probability {
+                    0.0
+                } else if rng.next_u32() & 1 == 0 {
+                    amplitude
+                } else {
+                    -amplitude
+                }
+            }
+        }
+    }
+}
+
+/// Wiper position (0.0..1.0) as a function of the sample index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Motion {
+    /// Resting at `position`
+    Hold { position: Float },
+
+    /// Linear move from `from` to `to` over `samples`, then resting at `to`
+    Sweep {
+        from: Float,
+        to: Float,
+        samples: u32,
+    },
+
+    /// At `from` until sample `at`, then at `to`
+    Step { from: Float, to: Float, at: u32 },
+
+    /// Triangle wave of ±`amplitude` around `center`, one cycle every
+    /// `period` samples, e.g. a hand resting on the knob
+    Wiggle {
+        center: Float,
+        amplitude: Float,
+        period: u32,
+    },
+}
+
+impl Motion {
+    /// Position at `sample`.
+    pub fn position(&self, sample: u32) -> Float {
+        match *self {
+            Motion::Hold { position } => position,
+            Motion::Sweep { from, to, samples } => {
+                let progress = (sample as Float / samples.max(1) as Float).min(1.0);
+                from + (to - from) * progress
+            }
+            Motion::Step { from, to, at } => {
+                if sample < at {
+                    from
+                } else {
+                    to
+                }
+            }
+            Motion::Wiggle {
+                center,
+                amplitude,
+                period,
+            } => {
+                // Rises from the center first, like a sine
+                let period = period.max(1);
+                let t = 4.0 * (sample % period) as Float / period as Float;
+                let triangle = if t < 1.0 {
+                    t
+                } else if t < 3.0 {
+                    2.0 - t
+                } else {
+                    t - 4.0
+                };
+                center + triangle * amplitude
+            }
+        }
+    }
+}
+
+/// Endless stream of noisy wiper positions, clamped to 0.0..=1.0 like an ADC
+/// at its rails. Use `take()` to bound it.
+#[derive(Debug, Clone)]
+pub struct Signal {
+    motion: Motion,
+    noise: Noise,
+    rng: Rng,
+    sample: u32,
+}
+
+impl Signal {
+    pub const fn new(motion: Motion, noise: Noise, seed: u32) -> Self {
+        Self {
+            motion,
+            noise,
+            rng: Rng::new(seed),
+            sample: 0,
+        }
+    }
+
+    /// Raw counts from an ADC with `bits` of resolution (at most 16).
+    pub fn adc(self, bits: u8) -> impl Iterator<Item = u16> {
+        let full_scale = ((1_u32 << bits.min(16)) - 1) as Float;
+        self.map(move |position| u16::from_normalized(position * full_scale))
+    }
+}
+
+impl Iterator for Signal {
+    type Item = Float;
+
+    fn next(&mut self) -> Option<Float> {
+        let position = self.motion.position(self.sample) + self.noise.sample(&mut self.rng);
+        self.sample = self.sample.wrapping_add(1);
+        Some(position.clamp(0.0, 1.0))
+    }
+}
@@ -0,0 +1,347 @@
+//! Live tuning over a serial link.
+//!
+//! `TuningServer` reads parameter-change frames from any `embedded-io`
+//! transport (UART, USB CDC, RTT), applies them to a running pot through the
+//! runtime setters and answers each with an acknowledgement, so filter and
+//! hysteresis constants can be adjusted on the device during bring-up.
+//! Requires `tuning` feature.
+//!
+//! Every frame is `FRAME_LEN` bytes:
+//!
+//! | Byte | Content |
+//! |------|---------|
+//! | 0 | `SYNC` (0xA5) |
+//! | 1 | Channel (pot index) |
+//! | 2 | Command (acknowledgements set bit 7) |
+//! | 3..7 | First value, `f32` little-endian (curve: byte 3; ack: status in byte 3) |
+//! | 7..11 | Second value, `f32` little-endian |
+//! | 11 | XOR of bytes 1..11 |
+
+use embedded_io::{Read, ReadReady, Write};
+
+use crate::convert::{FromNormalized, IntoNormalized};
+use crate::curves::ResponseCurve;
+use crate::filters::NoiseFilter;
+use crate::float::Float;
+use crate::hysteresis::HysteresisMode;
+use crate::pothead::PotHead;
+use crate::snapshot::{from_wire, to_wire};
+
+/// Length of every frame, in bytes.
+pub const FRAME_LEN: usize = 12;
+
+/// First byte of every frame.
+pub const SYNC: u8 = 0xA5;
+
+const SET_ALPHA: u8 = 0x01;
+const SET_THRESHOLD: u8 = 0x02;
+const SET_SCHMITT: u8 = 0x03;
+const SET_CURVE: u8 = 0x04;
+const ACK: u8 = 0x80;
+
+const CURVE_LINEAR: u8 = 0;
+#[cfg(feature = "std-math")]
+const CURVE_LOGARITHMIC: u8 = 1;
+#[cfg(feature = "log-lut")]
+const CURVE_LOGARITHMIC_LUT: u8 = 2;
+
+/// A parameter change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TuningMessage {
+    /// EMA filter with this alpha
+    SetAlpha(Float),
+
+    /// Change threshold hysteresis
+    SetThreshold(Float),
+
+    /// Schmitt trigger hysteresis
+    SetSchmitt {
+        rising: Float,
+        falling: Float,
+    },
+
+    SetCurve(ResponseCurve),
+}
+
+/// Result of a message, sent back in the acknowledgement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TuningStatus {
+    Applied,
+
+    /// The value failed validation; the pot is unchanged
+    InvalidValue,
+
+    /// No pot on this channel
+    UnknownChannel,
+
+    /// Unknown command, or a curve this build doesn't include
+    Unsupported,
+}
+
+impl TuningStatus {
+    const fn code(self) -> u8 {
+        match self {
+            TuningStatus::Applied => 0,
+            TuningStatus::InvalidValue => 1,
+            TuningStatus::UnknownChannel => 2,
+            TuningStatus::Unsupported => 3,
+        }
+    }
+
+    /// Parse an acknowledgement frame into `(channel, status)`, e.g. on the host.
+    pub fn from_ack(frame: &[u8; FRAME_LEN]) -> Option<(u8, TuningStatus)> {
+        if !frame_is_valid(frame) || frame[2] & ACK == 0 {
+            return None;
+        }
+        let status = match frame[3] {
+            0 => TuningStatus::Applied,
+            1 => TuningStatus::InvalidValue,
+            2 => TuningStatus::UnknownChannel,
+            3 => TuningStatus::Unsupported,
+            _ => return None,
+        };
+        Some((frame[1], status))
+    }
+}
+
+impl TuningMessage {
+    /// Encode as a frame for `channel`, e.g. on the host.
+    pub fn encode(&self, channel: u8) -> [u8; FRAME_LEN] {
+        let payload = match *self {
+            TuningMessage::SetAlpha(alpha) => values(alpha, 0.0),
+            TuningMessage::SetThreshold(threshold) => values(threshold, 0.0),
+            TuningMessage::SetSchmitt { rising, falling } => values(rising, falling),
+            TuningMessage::SetCurve(curve) => {
+                let code = match curve {
+                    ResponseCurve::Linear => CURVE_LINEAR,
+                    #[cfg(feature = "std-math")]
+                    ResponseCurve::Logarithmic => CURVE_LOGARITHMIC,
+                    #[cfg(feature = "log-lut")]
+                    ResponseCurve::LogarithmicLut => CURVE_LOGARITHMIC_LUT,
+                };
+                [code, 0, 0, 0, 0, 0, 0, 0]
+            }
+        };
+        frame(channel, self.command(), payload)
+    }
+
+    const fn command(&self) -> u8 {
+        match self {
+            TuningMessage::SetAlpha(_) => SET_ALPHA,
+            TuningMessage::SetThreshold(_) => SET_THRESHOLD,
+            TuningMessage::SetSchmitt { .. } => SET_SCHMITT,
+            TuningMessage::SetCurve(_) => SET_CURVE,
+        }
+    }
+
+    /// Decode a frame into `(channel, message)`.
+    ///
+    /// Returns `None` for corrupt frames and `Some(Err(status))` for valid
+    /// frames this build can't apply.
+    pub fn decode(frame: &[u8; FRAME_LEN]) -> Option<Result<(u8, TuningMessage), TuningStatus>> {
+        if !frame_is_valid(frame) {
+            return None;
+        }
+
+        let first = from_wire(f32::from_le_bytes([frame[3], frame[4], frame[5], frame[6]]));
+        let second = from_wire(f32::from_le_bytes([
+            frame[7], frame[8], frame[9], frame[10],
+        ]));
+        let message = match (frame[2], frame[3]) {
+            (SET_ALPHA, _) => TuningMessage::SetAlpha(first),
+            (SET_THRESHOLD, _) => TuningMessage::SetThreshold(first),
+            (SET_SCHMITT, _) => TuningMessage::SetSchmitt {
+                rising: first,
+                falling: second,
+            },
+            (SET_CURVE, CURVE_LINEAR) => TuningMessage::SetCurve(ResponseCurve::Linear),
+            #[cfg(feature = "std-math")]
+            (SET_CURVE, CURVE_LOGARITHMIC) => TuningMessage::SetCurve(ResponseCurve::Logarithmic),
+            #[cfg(feature = "log-lut")]
+            (SET_CURVE, CURVE_LOGARITHMIC_LUT) => {
+                TuningMessage::SetCurve(ResponseCurve::LogarithmicLut)
+            }
+            _ => return Some(Err(TuningStatus::Unsupported)),
+        };
+        Some(Ok((frame[1], message)))
+    }
+
+    /// Apply through the runtime setters. Invalid values leave the pot unchanged.
+    pub fn apply<TIn, TOut>(&self, pot: &mut PotHead<'_, TIn, TOut>) -> TuningStatus
+    where
+        TIn: Copy + PartialOrd + IntoNormalized,
+        TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+    {
+        let result = match *self {
+            TuningMessage::SetAlpha(alpha) => {
+                pot.set_filter(NoiseFilter::ExponentialMovingAverage { alpha })
+            }
+            TuningMessage::SetThreshold(threshold) => {
+                pot.set_hysteresis(HysteresisMode::ChangeThreshold { threshold })
+            }
+            TuningMessage::SetSchmitt { rising, falling } => {
+                pot.set_hysteresis(HysteresisMode::SchmittTrigger { rising, falling })
+            }
+            TuningMessage::SetCurve(curve) => {
+                pot.set_curve(curve);
+                Ok(())
+            }
+        };
+
+        match result {
+            Ok(()) => TuningStatus::Applied,
+            Err(_) => TuningStatus::InvalidValue,
+        }
+    }
+}
+
+/// Receives tuning frames from a transport and acknowledges them.
+///
+/// ```ignore
+/// let mut server = TuningServer::new(uart);
+///
+/// loop {
+///     while let Some((channel, message)) = server.receive()? {
+///         server.apply(channel, message, bank.get_mut(channel as usize))?;
+///     }
+///     bank.update_all(&adc.read_all());
+/// }
+/// ```
+pub struct TuningServer<T> {
+    transport: T,
+    frame: [u8; FRAME_LEN],
+    len: usize,
+    discarded: u32,
+}
+
+impl<T: Read + ReadReady + Write> TuningServer<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            frame: [0; FRAME_LEN],
+            len: 0,
+            discarded: 0,
+        }
+    }
+
+    /// Read the bytes available without blocking and return the next valid
+    /// message, or `None` if no complete frame has arrived yet.
+    ///
+    /// Frames this build can't apply are acknowledged as `Unsupported`; corrupt
+    /// frames are skipped up to the next `SYNC` byte.
+    pub fn receive(&mut self) -> Result<Option<(u8, TuningMessage)>, T::Error> {
+        loop {
+            if self.len == FRAME_LEN {
+                match TuningMessage::decode(&self.frame) {
+                    Some(Ok(message)) => {
+                        self.len = 0;
+                        return Ok(Some(message));
+                    }
+                    Some(Err(status)) => {
+                        let (channel, command) = (self.frame[1], self.frame[2]);
+                        self.len = 0;
+                        self.acknowledge(channel, command, status)?;
+                    }
+                    None => {
+                        self.discarded = self.discarded.saturating_add(1);
+                        self.drop_front(1);
+                    }
+                }
+                continue;
+            }
+
+            if !self.transport.read_ready()? {
+                return Ok(None);
+            }
+            let read = self.transport.read(&mut self.frame[self.len..])?;
+            if read == 0 {
+                return Ok(None);
+            }
+            self.len += read;
+            self.drop_front(0);
+        }
+    }
+
+    /// Apply a received message to `pot` (`None` if no pot has this channel)
+    /// and acknowledge it.
+    pub fn apply<TIn, TOut>(
+        &mut self,
+        channel: u8,
+        message: TuningMessage,
+        pot: Option<&mut PotHead<'_, TIn, TOut>>,
+    ) -> Result<TuningStatus, T::Error>
+    where
+        TIn: Copy + PartialOrd + IntoNormalized,
+        TOut: Copy + PartialOrd + IntoNormalized + FromNormalized,
+    {
+        let status = match pot {
+            Some(pot) => message.apply(pot),
+            None => TuningStatus::UnknownChannel,
+        };
+        self.acknowledge(channel, message.command(), status)?;
+        Ok(status)
+    }
+
+    /// Corrupt frames skipped (saturating).
+    pub fn discarded(&self) -> u32 {
+        self.discarded
+    }
+
+    /// Give back the transport.
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+
+    fn acknowledge(
+        &mut self,
+        channel: u8,
+        command: u8,
+        status: TuningStatus,
+    ) -> Result<(), T::Error> {
+        let mut payload = [0; 8];
+        payload[0] = status.code();
+        self.transport
+            .write_all(&frame(channel, command | ACK, payload))?;
+        self.transport.flush()
+    }
+
+    /// Drop `count` bytes, then everything before the next `SYNC`.
+    fn drop_front(&mut self, count: usize) {
+        let start = self.frame[..self.len]
+            .iter()
+            .skip(count)
+            .position(|&byte| byte == SYNC)
+            .map_or(self.len, |offset| offset + count);
+        self.frame.copy_within(start..self.len, 0);
+        self.len -= start;
+    }
+}
+
+fn frame(channel: u8, command: u8, payload: [u8; 8]) -> [u8; FRAME_LEN] {
+    let mut frame = [0; FRAME_LEN];
+    frame[0] = SYNC;
+    frame[1] = channel;
+    frame[2] = command;
+    frame[3..11].copy_from_slice(&payload);
+    frame[FRAME_LEN - 1] = checksum(&frame);
+    frame
+}
+
+fn values(first: Float, second: Float) -> [u8; 8] {
+    let mut payload = [0; 8];
+    payload[..4].copy_from_slice(&to_wire(first).to_le_bytes());
+    payload[4..].copy_from_slice(&to_wire(second).to_le_bytes());
+    payload
+}
+
+fn checksum(frame: &[u8; FRAME_LEN]) -> u8 {
+    frame[1..FRAME_LEN - 1]
+        .iter()
+        .fold(0, |sum, &byte| sum ^ byte)
+}
+
+fn frame_is_valid(frame: &[u8; FRAME_LEN]) -> bool {
+    frame[0] == SYNC && frame[FRAME_LEN - 1] == checksum(frame)
+}
@@ -0,0 +1,110 @@
+//! Display formatting for output values.
+//!
+//! `ValueFormat` renders a value with fixed precision and a unit suffix into a
+//! `heapless::String` (or any `core::fmt::Write`), optionally converting it to
+//! percent or dB first, so display code can show a pot's output in one call.
+//! Requires `value-format` feature.
+
+use core::fmt::{self, Write};
+
+use heapless::String;
+
+use crate::convert::IntoNormalized;
+
+#[cfg(feature = "std-math")]
+use crate::float::Float;
+
+/// Conversion applied before printing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DisplayScale {
+    /// Print the value as is
+    #[default]
+    Value,
+
+    /// Multiply by 100, for 0.0-1.0 outputs
+    Percent,
+
+    /// Treat the value as a linear gain and print 20·log10(value); zero or
+    /// negative gains print as `-inf`
+    #[cfg(feature = "std-math")]
+    Decibels,
+}
+
+/// How to print a value: `precision` decimals, then `unit` (e.g. `" dB"`, `"%"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ValueFormat {
+    pub scale: DisplayScale,
+    pub precision: u8,
+    pub unit: &'static str,
+}
+
+impl ValueFormat {
+    /// Print the value as is.
+    pub const fn new(precision: u8, unit: &'static str) -> Self {
+        Self {
+            scale: DisplayScale::Value,
+            precision,
+            unit,
+        }
+    }
+
+    /// 0.0-1.0 value as percent with a `%` suffix.
+    pub const fn percent(precision: u8) -> Self {
+        Self {
+            scale: DisplayScale::Percent,
+            precision,
+            unit: "%",
+        }
+    }
+
+    /// Linear gain in dB with a ` dB` suffix.
+    #[cfg(feature = "std-math")]
+    pub const fn decibels(precision: u8) -> Self {
+        Self {
+            scale: DisplayScale::Decibels,
+            precision,
+            unit: " dB",
+        }
+    }
+
+    /// Render `value` (e.g. `pot.last_output()`) into a string of capacity N.
+    ///
+    /// Returns `Err` if the text doesn't fit.
+    pub fn render<const N: usize, T: IntoNormalized>(
+        &self,
+        value: T,
+    ) -> Result<String<N>, fmt::Error> {
+        let mut text = String::new();
+        self.write_to(&mut text, value)?;
+        Ok(text)
+    }
+
+    /// Like `render()`, writing into any `core::fmt::Write`.
+    pub fn write_to<W: Write, T: IntoNormalized>(&self, out: &mut W, value: T) -> fmt::Result {
+        let value = value.into_normalized();
+        let value = match self.scale {
+            DisplayScale::Value => value,
+            DisplayScale::Percent => value * 100.0,
+            #[cfg(feature = "std-math")]
+            DisplayScale::Decibels => {
+                if value <= 0.0 {
+                    return write!(out, "-inf{}", self.unit);
+                }
+                20.0 * log10(value)
+            }
+        };
+        write!(out, "{:.*}{}", self.precision as usize, value, self.unit)
+    }
+}
+
+#[cfg(all(feature = "std-math", not(feature = "f64")))]
+fn log10(x: Float) -> Float {
+    libm::log10f(x)
+}
+
+#[cfg(all(feature = "std-math", feature = "f64"))]
+fn log10(x: Float) -> Float {
+    libm::log10(x)
+}
@@ -0,0 +1,116 @@
+//! Browser bindings for tuning.
+//!
+//! `WasmPotHead` exposes a `PotHead<Float, Float>` to JavaScript (as `PotHead`)
+//! so a browser tool can replay recorded ADC traces through the same pipeline
+//! code the firmware runs, adjust filter, hysteresis and curve live, and export
+//! the result as builder code. Snap zones and discrete values borrow tables and
+//! are not exposed. Requires `wasm` feature.
+
+use std::format;
+use std::string::String;
+use std::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::config::Config;
+use crate::export::builder_code;
+use crate::filters::NoiseFilter;
+use crate::float::Float;
+use crate::hysteresis::HysteresisMode;
+use crate::pothead::PotHead;
+
+#[cfg(feature = "std-math")]
+use crate::curves::ResponseCurve;
+
+#[wasm_bindgen(js_name = PotHead)]
+pub struct WasmPotHead {
+    pot: PotHead<'static, Float, Float>,
+}
+
+#[wasm_bindgen(js_class = PotHead)]
+impl WasmPotHead {
+    /// Linear pot without filtering or hysteresis.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        input_min: Float,
+        input_max: Float,
+        output_min: Float,
+        output_max: Float,
+    ) -> Result<WasmPotHead, JsError> {
+        let config = Config::builder()
+            .input_range(input_min, input_max)
+            .output_range(output_min, output_max)
+            .build()?;
+        Ok(Self {
+            pot: PotHead::new(config)?,
+        })
+    }
+
+    /// Process one sample.
+    pub fn update(&mut self, input: Float) -> Float {
+        self.pot.update(input)
+    }
+
+    /// Process a recorded trace and return every intermediate output.
+    pub fn process(&mut self, trace: &[Float]) -> Vec<Float> {
+        self.pot.update_vec(trace)
+    }
+
+    pub fn reset(&mut self) {
+        self.pot.reset();
+    }
+
+    pub fn set_invert_input(&mut self, invert: bool) -> Result<(), JsError> {
+        Ok(self.pot.reconfigure(|c| c.invert_input = invert)?)
+    }
+
+    #[cfg(feature = "std-math")]
+    pub fn set_logarithmic(&mut self, logarithmic: bool) {
+        self.pot.set_curve(if logarithmic {
+            ResponseCurve::Logarithmic
+        } else {
+            ResponseCurve::Linear
+        });
+    }
+
+    pub fn set_ema(&mut self, alpha: Float) -> Result<(), JsError> {
+        Ok(self
+            .pot
+            .set_filter(NoiseFilter::ExponentialMovingAverage { alpha })?)
+    }
+
+    pub fn set_no_filter(&mut self) -> Result<(), JsError> {
+        Ok(self.pot.set_filter(NoiseFilter::None)?)
+    }
+
+    pub fn set_threshold(&mut self, threshold: Float) -> Result<(), JsError> {
+        Ok(self
+            .pot
+            .set_hysteresis(HysteresisMode::ChangeThreshold { threshold })?)
+    }
+
+    pub fn set_schmitt(&mut self, rising: Float, falling: Float) -> Result<(), JsError> {
+        Ok(self
+            .pot
+            .set_hysteresis(HysteresisMode::SchmittTrigger { rising, falling })?)
+    }
+
+    pub fn set_no_hysteresis(&mut self) -> Result<(), JsError> {
+        Ok(self.pot.set_hysteresis(HysteresisMode::none())?)
+    }
+
+    /// Slew limit in normalized units per update; `undefined` disables it.
+    pub fn set_slew_rate(&mut self, rate: Option<Float>) -> Result<(), JsError> {
+        Ok(self.pot.reconfigure(|c| c.slew_rate = rate)?)
+    }
+
+    /// One-line config summary (see `ConfigSummary`).
+    pub fn describe(&self) -> String {
+        format!("{}", self.pot.config())
+    }
+
+    /// The tuned configuration as `Config::builder()` code to paste into firmware.
+    pub fn export_config(&self) -> String {
+        builder_code(self.pot.config())
+    }
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use core::cell::Cell;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+
+use common::pot;
+use pot_head::AsyncPotSampler;
+
+/// Run a future that never actually waits (all reads and ticks are ready).
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_sample() {
+    let ticks = Cell::new(0);
+    let mut samples = [0, 500, 1000].into_iter();
+
+    let mut sampler = AsyncPotSampler::new(
+        pot((0_u16, 1000), (0_u8, 100)),
+        async || samples.next().unwrap(),
+        async || ticks.set(ticks.get() + 1),
+    );
+
+    assert_eq!(block_on(sampler.sample()), 0);
+    assert_eq!(block_on(sampler.sample()), 50);
+    assert_eq!(block_on(sampler.sample()), 100);
+    assert_eq!(sampler.last_output(), 100);
+    assert_eq!(ticks.get(), 3);
+}
+
+#[test]
+fn test_next_change_skips_unchanged() {
+    let reads = Cell::new(0);
+    let mut samples = [300, 300, 300, 301, 700].into_iter();
+
+    let mut sampler = AsyncPotSampler::new(
+        pot((0_u16, 1000), (0_u8, 100)),
+        async || {
+            reads.set(reads.get() + 1);
+            samples.next().unwrap()
+        },
+        async || {},
+    );
+
+    assert_eq!(block_on(sampler.next_change()), 30);
+    assert_eq!(reads.get(), 1);
+
+    // 301 moves the pipeline but still rounds to 30
+    assert_eq!(block_on(sampler.next_change()), 70);
+    assert_eq!(reads.get(), 5);
+}
+
+#[test]
+fn test_into_inner() {
+    let mut sampler =
+        AsyncPotSampler::new(pot((0_u16, 1000), (0_u8, 100)), async || 500, async || {});
+    assert_eq!(block_on(sampler.next_change()), 50);
+
+    let pot = sampler.into_inner();
+    assert_eq!(pot.last_output(), 50);
+}
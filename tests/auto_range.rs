@@ -0,0 +1,108 @@
+#![cfg(feature = "auto-range")]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use pot_head::{Config, PotHead};
+
+fn pot(min: u16, max: u16) -> PotHead<'static, u16, f32> {
+    let config = Config::builder()
+        .input_range(min, max)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.set_auto_range(true);
+    pot
+}
+
+#[test]
+fn disabled_by_default() {
+    let config = Config::builder()
+        .input_range(100_u16, 900)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert!(!pot.is_auto_range());
+    pot.update(1000);
+    assert_eq!(pot.config().input_max, 900);
+}
+
+#[test]
+fn widens_to_samples_beyond_range() {
+    let mut pot = pot(100, 900);
+
+    assert_eq!(pot.update(1000), 1.0);
+    assert_eq!(
+        (pot.config().input_min, pot.config().input_max),
+        (100, 1000)
+    );
+
+    pot.update(20);
+    assert_eq!((pot.config().input_min, pot.config().input_max), (20, 1000));
+
+    // Samples inside the learned range map against it
+    assert!((pot.update(510) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn never_narrows() {
+    let mut pot = pot(100, 900);
+    pot.update(500);
+    assert_eq!((pot.config().input_min, pot.config().input_max), (100, 900));
+}
+
+#[test]
+fn keeps_inverted_orientation() {
+    let mut pot = pot(900, 100);
+
+    pot.update(1000);
+    pot.update(0);
+    assert_eq!((pot.config().input_min, pot.config().input_max), (1000, 0));
+    assert_eq!(pot.update(1000), 0.0);
+}
+
+#[test]
+fn survives_reset() {
+    let mut pot = pot(100, 900);
+    pot.update(1000);
+    pot.reset();
+    assert_eq!(pot.config().input_max, 1000);
+}
+
+static RANGE_CHANGES: AtomicU32 = AtomicU32::new(0);
+static LAST_MAX: AtomicU32 = AtomicU32::new(0);
+
+fn persist(range: (u16, u16)) {
+    RANGE_CHANGES.fetch_add(1, Ordering::Relaxed);
+    LAST_MAX.store(range.1 as u32, Ordering::Relaxed);
+}
+
+#[test]
+fn hook_reports_each_widening() {
+    let mut pot = pot(100, 900);
+    pot.set_on_range_change(Some(persist));
+
+    pot.update(500);
+    pot.update(950);
+    pot.update(950);
+    pot.update(980);
+
+    assert_eq!(RANGE_CHANGES.load(Ordering::Relaxed), 2);
+    assert_eq!(LAST_MAX.load(Ordering::Relaxed), 980);
+}
+
+#[test]
+fn ignores_non_finite_float_inputs() {
+    let config = Config::builder()
+        .input_range(0.1_f32, 0.9)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.set_auto_range(true);
+
+    pot.update(f32::INFINITY);
+    assert_eq!(pot.config().input_max, 0.9);
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "calibration")]
+
+mod common;
+
+use common::pot;
+use pot_head::{CalibrationData, Config, ConfigError, NoiseFilter, PotHead};
+
+#[test]
+fn captures_latest_sample() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    assert!(!pot.is_calibrating());
+    assert_eq!(pot.capture_min(), None);
+
+    pot.begin_calibration();
+    assert!(pot.is_calibrating());
+
+    // Nothing sampled since begin_calibration()
+    assert_eq!(pot.capture_min(), None);
+
+    pot.update(130);
+    pot.update(120);
+    assert_eq!(pot.capture_min(), Some(120));
+
+    pot.update(3950);
+    assert_eq!(pot.capture_max(), Some(3950));
+}
+
+#[test]
+fn finish_applies_range() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    pot.begin_calibration();
+
+    pot.update(120);
+    pot.capture_min();
+    pot.update(3920);
+    pot.capture_max();
+
+    let data = pot.finish_calibration().unwrap();
+    assert_eq!(
+        data,
+        CalibrationData {
+            input_min: 120,
+            input_max: 3920,
+            input_center: None,
+            end_margin: 0.0,
+            invert_input: false,
+        }
+    );
+    assert!(!pot.is_calibrating());
+
+    assert_eq!(pot.update(120), 0.0);
+    assert!((pot.update(2020) - 0.5).abs() < 1e-6);
+    assert_eq!(pot.update(3920), 1.0);
+}
+
+#[test]
+fn center_maps_to_half() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    pot.begin_calibration();
+
+    pot.update(100);
+    pot.capture_min();
+    pot.update(3900);
+    pot.capture_max();
+    pot.update(2100);
+    pot.capture_center();
+
+    let data = pot.finish_calibration().unwrap();
+    assert_eq!(data.input_center, Some(2100));
+    assert_eq!(pot.config().input_center, Some(2100));
+
+    assert_eq!(pot.update(1100), 0.25);
+    assert_eq!(pot.update(2100), 0.5);
+    assert_eq!(pot.update(3000), 0.75);
+}
+
+#[test]
+fn uncaptured_ends_keep_current_range() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    pot.begin_calibration();
+
+    pot.update(3900);
+    pot.capture_max();
+
+    let data = pot.finish_calibration().unwrap();
+    assert_eq!((data.input_min, data.input_max), (0, 3900));
+}
+
+#[test]
+fn invalid_capture_keeps_session() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    pot.begin_calibration();
+
+    // Operator pressed OK twice at the same end
+    pot.update(100);
+    pot.capture_min();
+    pot.capture_max();
+
+    assert!(matches!(
+        pot.finish_calibration(),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+    assert!(pot.is_calibrating());
+    assert_eq!(pot.config().input_max, 4095);
+
+    pot.update(4000);
+    pot.capture_max();
+    assert!(pot.finish_calibration().is_ok());
+}
+
+#[test]
+fn center_outside_range_rejected() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    pot.begin_calibration();
+
+    pot.update(100);
+    pot.capture_min();
+    pot.capture_center();
+    pot.update(3900);
+    pot.capture_max();
+
+    assert!(matches!(
+        pot.finish_calibration(),
+        Err(ConfigError::InvalidInputCenter { .. })
+    ));
+    assert_eq!(pot.config().input_center, None);
+}
+
+#[test]
+fn cancel_discards_captures() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    pot.begin_calibration();
+    pot.update(500);
+    pot.capture_min();
+    pot.cancel_calibration();
+
+    assert!(!pot.is_calibrating());
+    assert_eq!(pot.finish_calibration().unwrap().input_min, 0);
+    assert_eq!(pot.config().input_min, 0);
+}
+
+#[test]
+fn finish_resets_filter() {
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(0.0_f32, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.1 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.begin_calibration();
+    pot.update(0);
+    pot.capture_min();
+    pot.update(4000);
+    pot.capture_max();
+    pot.finish_calibration().unwrap();
+
+    // First sample after calibration starts the filter fresh
+    assert_eq!(pot.update(4000), 1.0);
+}
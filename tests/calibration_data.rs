@@ -0,0 +1,157 @@
+use pot_head::{CalibrationData, Config, ConfigError, PotHead, SnapshotError};
+
+static PRODUCT_CONFIG: Config<u16, f32> = Config::<u16, f32>::DEFAULT;
+
+const UNIT: CalibrationData<u16> = CalibrationData {
+    input_min: 40,
+    input_max: 4040,
+    input_center: Some(2240),
+    end_margin: 0.0,
+    invert_input: true,
+};
+
+#[test]
+fn test_from_config() {
+    let config = Config::builder()
+        .input_range(10_u16, 1000)
+        .input_center(600)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        config.calibration(),
+        CalibrationData {
+            input_min: 10,
+            input_max: 1000,
+            input_center: Some(600),
+            end_margin: 0.0,
+            invert_input: false,
+        }
+    );
+}
+
+#[test]
+fn test_apply_to_shared_config() {
+    let mut config = PRODUCT_CONFIG;
+    UNIT.apply(&mut config);
+
+    assert_eq!(config.calibration(), UNIT);
+    assert_eq!(config.output_max, PRODUCT_CONFIG.output_max);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_builder_calibration() {
+    let config = Config::builder()
+        .calibration(UNIT)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    assert_eq!(config.calibration(), UNIT);
+}
+
+#[test]
+fn test_validate() {
+    assert!(UNIT.validate().is_ok());
+
+    let degenerate = CalibrationData {
+        input_max: 40,
+        ..UNIT
+    };
+    assert!(matches!(
+        degenerate.validate(),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+
+    let outside = CalibrationData {
+        input_center: Some(4095),
+        ..UNIT
+    };
+    assert_eq!(
+        outside.validate(),
+        Err(ConfigError::InvalidInputCenter { center: 4095.0 })
+    );
+}
+
+#[test]
+fn test_pothead_apply_calibration() {
+    let mut pot = PotHead::new(PRODUCT_CONFIG).unwrap();
+    pot.apply_calibration(&UNIT).unwrap();
+
+    assert_eq!(pot.config().calibration(), UNIT);
+    assert_eq!(pot.update(2240), 0.5);
+    assert_eq!(pot.update(40), 1.0);
+
+    // Invalid calibration leaves the pot unchanged
+    let invalid = CalibrationData {
+        input_center: Some(10),
+        ..UNIT
+    };
+    assert!(pot.apply_calibration(&invalid).is_err());
+    assert_eq!(pot.config().calibration(), UNIT);
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let bytes = UNIT.to_bytes();
+    assert_eq!(bytes.len(), CalibrationData::<u16>::SIZE);
+    assert_eq!(CalibrationData::<u16>::from_bytes(&bytes), Ok(UNIT));
+
+    let linear = CalibrationData {
+        input_center: None,
+        invert_input: false,
+        ..UNIT
+    };
+    assert_eq!(
+        CalibrationData::<u16>::from_bytes(&linear.to_bytes()),
+        Ok(linear)
+    );
+}
+
+#[test]
+fn test_end_margin_round_trip() {
+    let worn = CalibrationData {
+        end_margin: 0.125,
+        ..UNIT
+    };
+    let decoded = CalibrationData::<u16>::from_bytes(&worn.to_bytes()).unwrap();
+    assert_eq!(decoded, worn);
+
+    let mut pot = PotHead::new(PRODUCT_CONFIG).unwrap();
+    pot.apply_calibration(&decoded).unwrap();
+    assert_eq!(pot.config().end_margin, 0.125);
+    assert_eq!(pot.config().calibration(), worn);
+
+    let invalid = CalibrationData {
+        end_margin: 0.5,
+        ..UNIT
+    };
+    assert!(matches!(
+        invalid.validate(),
+        Err(ConfigError::InvalidEndMargin { .. })
+    ));
+}
+
+#[test]
+fn test_bytes_rejected() {
+    assert_eq!(
+        CalibrationData::<u16>::from_bytes(&[0xFF; 19]),
+        Err(SnapshotError::UnsupportedVersion(0xFF))
+    );
+
+    let mut torn = UNIT.to_bytes();
+    torn[4] ^= 0x01;
+    assert_eq!(
+        CalibrationData::<u16>::from_bytes(&torn),
+        Err(SnapshotError::Corrupted)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_postcard() {
+    let bytes = postcard::to_allocvec(&UNIT).unwrap();
+    let decoded: CalibrationData<u16> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, UNIT);
+}
@@ -0,0 +1,43 @@
+//! Helpers shared by the integration tests.
+
+// Each test crate compiles this module and uses only part of it.
+#![allow(dead_code)]
+
+use pot_head::{Config, FromNormalized, IntoNormalized, NoiseFilter, PotHead};
+
+/// A pot mapping `input` to `output` with the default processing.
+pub fn pot<TIn, TOut>(input: (TIn, TIn), output: (TOut, TOut)) -> PotHead<'static, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized + 'static,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized + 'static,
+{
+    filtered_pot(input, output, NoiseFilter::None)
+}
+
+/// Like `pot()`, with a noise filter.
+pub fn filtered_pot<TIn, TOut>(
+    input: (TIn, TIn),
+    output: (TOut, TOut),
+    filter: NoiseFilter,
+) -> PotHead<'static, TIn, TOut>
+where
+    TIn: Copy + PartialOrd + IntoNormalized + 'static,
+    TOut: Copy + PartialOrd + IntoNormalized + FromNormalized + 'static,
+{
+    let config = Config::builder()
+        .input_range(input.0, input.1)
+        .output_range(output.0, output.1)
+        .filter(filter)
+        .build()
+        .unwrap();
+    PotHead::new(config).unwrap()
+}
+
+/// Assert two values agree to within float rounding.
+pub fn assert_close(actual: impl Into<f64>, expected: impl Into<f64>) {
+    let (actual, expected) = (actual.into(), expected.into());
+    assert!(
+        (actual - expected).abs() < 1e-5,
+        "expected {expected}, got {actual}"
+    );
+}
@@ -0,0 +1,216 @@
+use pot_head::{Config, ConfigError, HysteresisMode, NoiseFilter, PotHead, ResponseCurve};
+
+#[test]
+fn test_builder_defaults() {
+    let config: Config<u16, f32> = Config::builder()
+        .input_range(0, 4095)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.curve, ResponseCurve::Linear);
+    assert_eq!(config.filter, NoiseFilter::None);
+    assert_eq!(config.hysteresis, HysteresisMode::none());
+    assert!(config.snap_zones.is_empty());
+}
+
+#[test]
+fn test_builder_full_pipeline() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 100.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.5 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .curve(ResponseCurve::Linear)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+    assert_eq!(pot.update(500), 50.0);
+}
+
+#[test]
+fn test_builder_missing_ranges() {
+    let result = Config::<u16, f32>::builder().output_range(0.0, 1.0).build();
+    assert_eq!(result.err(), Some(ConfigError::MissingInputRange));
+
+    let result = Config::<u16, f32>::builder().input_range(0, 4095).build();
+    assert_eq!(result.err(), Some(ConfigError::MissingOutputRange));
+}
+
+#[test]
+fn test_builder_validates() {
+    let result = Config::<u16, f32>::builder()
+        .input_range(0, 4095)
+        .output_range(0.0, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.0 })
+        .build();
+    assert!(matches!(result, Err(ConfigError::InvalidFilter { .. })));
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_builder_grab_settings() {
+    use pot_head::{GrabMode, ReleasePolicy};
+
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .grab_mode(GrabMode::PassThrough)
+        .grab_margin(0.02)
+        .release_policy(ReleasePolicy::GrabInPlace)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.grab_mode, GrabMode::PassThrough);
+    assert_eq!(config.grab_margin, 0.02);
+    assert_eq!(config.release_policy, ReleasePolicy::GrabInPlace);
+}
+
+#[test]
+fn test_default_config() {
+    let config: Config<u16, f32> = Config::default();
+
+    assert_eq!(config.input_min, 0);
+    assert_eq!(config.input_max, 4095);
+    assert_eq!(config.output_min, 0.0);
+    assert_eq!(config.output_max, 1.0);
+    assert_eq!(config.filter, NoiseFilter::None);
+    assert!(config.validate().is_ok());
+
+    let mut pot = PotHead::new(config).unwrap();
+    assert_eq!(pot.update(4095), 1.0);
+}
+
+#[test]
+fn test_partial_default_config() {
+    let config = Config {
+        input_max: 1023_u16,
+        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.3 },
+        ..Default::default()
+    };
+
+    let mut pot = PotHead::new(config).unwrap();
+    assert_eq!(pot.update(1023), 1.0);
+}
+
+#[test]
+fn test_default_config_in_static() {
+    static CONFIG: Config<u8, f32> = Config {
+        curve: ResponseCurve::Linear,
+        ..Config::<u8, f32>::DEFAULT
+    };
+    const _: () = pot_head::assert_valid!(CONFIG);
+
+    assert_eq!(CONFIG.input_max, 255);
+
+    let normalized: Config<f32, f32> = Config::default();
+    assert_eq!(normalized.input_max, 1.0);
+}
+
+#[test]
+fn test_from_adc_bits_unsigned() {
+    let config = Config::<u16, f32>::from_adc_bits(12)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    assert_eq!((config.input_min, config.input_max), (0, 4095));
+
+    let config = Config::<u16, f32>::builder()
+        .input_adc_bits(16)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    assert_eq!((config.input_min, config.input_max), (0, u16::MAX));
+}
+
+#[test]
+fn test_from_adc_bits_signed() {
+    let config = Config::<i16, f32>::from_adc_bits(12)
+        .output_range(-1.0, 1.0)
+        .build()
+        .unwrap();
+    assert_eq!((config.input_min, config.input_max), (-2048, 2047));
+
+    let config = Config::<i32, f32>::from_adc_bits(24)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    assert_eq!(
+        (config.input_min, config.input_max),
+        (-8_388_608, 8_388_607)
+    );
+}
+
+#[test]
+fn test_from_adc_bits_out_of_range() {
+    let result = Config::<u8, f32>::from_adc_bits(9)
+        .output_range(0.0, 1.0)
+        .build();
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidAdcBits { bits: 9 })
+    ));
+
+    let result = Config::<u16, f32>::builder()
+        .input_adc_bits(0)
+        .output_range(0.0, 1.0)
+        .build();
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidAdcBits { bits: 0 })
+    ));
+
+    // A later explicit range replaces the rejected width
+    let config = Config::<u8, f32>::from_adc_bits(9)
+        .input_range(0, 255)
+        .output_range(0.0, 1.0)
+        .build();
+    assert!(config.is_ok());
+}
+
+#[test]
+fn test_output_presets() {
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_percent()
+        .build()
+        .unwrap();
+    assert_eq!((config.output_min, config.output_max), (0.0, 100.0));
+    assert_eq!(config.curve, ResponseCurve::Linear);
+
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_db(-60.0, 0.0)
+        .build()
+        .unwrap();
+    assert_eq!((config.output_min, config.output_max), (-60.0, 0.0));
+    let mut pot = PotHead::new(config).unwrap();
+    assert_eq!(pot.update(500), -30.0);
+
+    let config = Config::<u16, u8>::builder()
+        .input_range(0, 1000)
+        .output_midi7()
+        .build()
+        .unwrap();
+    assert_eq!((config.output_min, config.output_max), (0, 127));
+
+    let config = Config::<u16, u16>::builder()
+        .input_range(0, 1000)
+        .output_midi14()
+        .build()
+        .unwrap();
+    assert_eq!((config.output_min, config.output_max), (0, 16383));
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_curve_after_preset_overrides() {
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_percent()
+        .curve(ResponseCurve::Logarithmic)
+        .build()
+        .unwrap();
+    assert_eq!(config.curve, ResponseCurve::Logarithmic);
+}
@@ -0,0 +1,65 @@
+use pot_head::{Config, Float, HysteresisMode, NoiseFilter, SnapZone, SnapZoneType, StageOrder};
+
+static ZONES: [SnapZone<Float>; 2] = [
+    SnapZone::new(0.0, 0.05, SnapZoneType::Snap),
+    SnapZone::new(0.5, 0.1, SnapZoneType::Dead),
+];
+
+fn config() -> Config<'static, u16, f32> {
+    Config::builder()
+        .input_range(0, 4095)
+        .output_range(0.0, 100.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .hysteresis(HysteresisMode::SchmittTrigger {
+            rising: 0.6,
+            falling: 0.4,
+        })
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_describe_fields() {
+    let summary = config().describe();
+
+    assert_eq!(summary.input_range, (0.0, 4095.0));
+    assert_eq!(summary.output_range, (0.0, 100.0));
+    assert!(!summary.invert_input);
+    assert_eq!(summary.curve, "Linear");
+    assert_eq!(summary.filter, "EMA");
+    assert_eq!(summary.hysteresis, "SchmittTrigger");
+    assert_eq!(summary.change_threshold, None);
+    assert_eq!(summary.schmitt_levels, Some((0.4, 0.6)));
+    assert_eq!(summary.stage_order, StageOrder::CurveFirst);
+}
+
+#[test]
+fn test_describe_zone_spans() {
+    let summary = config().describe();
+    assert_eq!(summary.zone_count(), 2);
+
+    let spans: Vec<_> = summary.zone_spans().collect();
+    assert_eq!(spans[0].min, 0.0); // clamped
+    assert_eq!(spans[0].max, 0.05);
+    assert_eq!(spans[0].zone_type, SnapZoneType::Snap);
+    assert!((spans[1].min - 0.4).abs() < 1e-6);
+    assert!((spans[1].max - 0.6).abs() < 1e-6);
+    assert_eq!(spans[1].zone_type, SnapZoneType::Dead);
+}
+
+#[test]
+fn test_summary_display() {
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.05 })
+        .build()
+        .unwrap();
+
+    let text = config.describe().to_string();
+    assert!(text.starts_with("0..1000 -> 0..1"));
+    assert!(text.contains("curve: Linear"));
+    assert!(text.contains("hysteresis: ChangeThreshold 0.05"));
+    assert!(text.contains("zones: 0"));
+}
@@ -1,48 +1,73 @@
 use pot_head::{
-    Config, ConfigError, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone,
+    Config, ConfigError, Float, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone,
+    SnapZoneType, StageOrder, ValidationErrors,
 };
 
 #[cfg(feature = "grab-mode")]
-use pot_head::GrabMode;
+use pot_head::{GrabMode, ReleasePolicy};
 
-static EMPTY_SNAP_ZONES: [SnapZone<f32>; 0] = [];
+static EMPTY_SNAP_ZONES: [SnapZone<Float>; 0] = [];
 
 #[test]
 fn test_invalid_input_range() {
     let config = Config {
         input_min: 100_u16,
         input_max: 100_u16, // Same as min - invalid
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let result = PotHead::new(config);
-    assert!(matches!(result, Err(ConfigError::InvalidInputRange)));
+    assert!(matches!(result, Err(ConfigError::InvalidInputRange { .. })));
 }
 
 #[test]
-fn test_inverted_input_range() {
+fn test_descending_input_range_accepted() {
     let config = Config {
         input_min: 200_u16,
-        input_max: 100_u16, // Less than min - invalid
+        input_max: 100_u16, // Descending - valid
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
-    let result = PotHead::new(config);
-    assert!(matches!(result, Err(ConfigError::InvalidInputRange)));
+    assert!(config.validate_all().is_empty());
+    assert!(PotHead::new(config).is_ok());
 }
 
 #[test]
@@ -50,16 +75,427 @@ fn test_invalid_output_range() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 1.0_f32,
         output_max: 1.0_f32, // Same as min - invalid
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let result = PotHead::new(config);
-    assert!(matches!(result, Err(ConfigError::InvalidOutputRange)));
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidOutputRange { .. })
+    ));
+}
+
+static VALID_CONFIG: Config<u16, f32> = Config {
+    input_min: 0,
+    input_max: 4095,
+    input_center: None,
+    end_margin: 0.0,
+    invert_input: false,
+    output_min: 0.0,
+    output_max: 1.0,
+    hysteresis: HysteresisMode::SchmittTrigger {
+        rising: 0.6,
+        falling: 0.4,
+    },
+    curve: ResponseCurve::Linear,
+    filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.3 },
+    snap_zones: &[SnapZone::new(0.5, 0.05, SnapZoneType::Snap)],
+    stage_order: StageOrder::CurveFirst,
+    values: &[],
+    slew_rate: None,
+    #[cfg(feature = "grab-mode")]
+    grab_mode: GrabMode::Pickup,
+    #[cfg(feature = "grab-mode")]
+    grab_margin: 0.01,
+    #[cfg(feature = "grab-mode")]
+    release_policy: ReleasePolicy::RequireMovement,
+    #[cfg(feature = "fine-adjust")]
+    fine_adjust: None,
+};
+
+// Compile-time validation
+const _: () = pot_head::assert_valid!(VALID_CONFIG);
+
+const _: () = match VALID_CONFIG.validate_processing() {
+    Ok(()) => {}
+    Err(e) => panic!("{}", e.message()),
+};
+
+#[test]
+fn test_validate_processing_const() {
+    const RESULT: Result<(), ConfigError> = VALID_CONFIG.validate_processing();
+    assert_eq!(RESULT, Ok(()));
+}
+
+#[test]
+fn test_invalid_snap_zone() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, -0.05, SnapZoneType::Snap)];
+
+    let config = Config {
+        snap_zones: &ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
+        ..Config::<u16, f32>::builder()
+            .input_range(0, 100)
+            .output_range(0.0, 1.0)
+            .build()
+            .unwrap()
+    };
+
+    assert_eq!(
+        config.validate(),
+        Err(ConfigError::InvalidSnapZone {
+            index: 0,
+            threshold: -0.05
+        })
+    );
+}
+
+#[test]
+fn test_negative_change_threshold() {
+    let result = Config::<u16, f32>::builder()
+        .input_range(0, 100)
+        .output_range(0.0, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: -0.1 })
+        .build();
+
+    assert!(matches!(result, Err(ConfigError::InvalidHysteresis { .. })));
+}
+
+#[test]
+fn test_error_message_matches_display() {
+    let err = ConfigError::MissingInputRange;
+    assert_eq!(err.to_string(), err.message());
+}
+
+#[test]
+fn test_error_carries_offending_values() {
+    let result = Config::<u16, f32>::builder()
+        .input_range(200, 200)
+        .output_range(0.0, 1.0)
+        .build();
+    assert_eq!(
+        result.err(),
+        Some(ConfigError::InvalidInputRange {
+            min: 200.0,
+            max: 200.0
+        })
+    );
+
+    let err = ConfigError::InvalidInputRange {
+        min: 200.0,
+        max: 200.0,
+    };
+    assert_eq!(
+        err.to_string(),
+        "input_min must not equal input_max (min: 200, max: 200)"
+    );
+}
+
+#[test]
+fn test_error_carries_reason() {
+    let result = Config::<u16, f32>::builder()
+        .input_range(0, 100)
+        .output_range(0.0, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 1.5 })
+        .build();
+
+    let err = result.err().unwrap();
+    assert_eq!(
+        err,
+        ConfigError::InvalidFilter {
+            reason: "EMA alpha must be in range (0.0, 1.0]"
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid filter configuration: EMA alpha must be in range (0.0, 1.0]"
+    );
+}
+
+#[test]
+fn test_validate_all_valid() {
+    let errors = VALID_CONFIG.validate_all();
+    assert!(errors.is_empty());
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn test_validate_all_reports_every_failure() {
+    static ZONES: [SnapZone<Float>; 2] = [
+        SnapZone::new(0.2, 0.05, SnapZoneType::Snap),
+        SnapZone::new(0.5, -0.05, SnapZoneType::Snap),
+    ];
+
+    let config = Config {
+        input_min: 100_u16,
+        input_max: 100,
+        output_min: 1.0_f32,
+        output_max: 1.0,
+        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.0 },
+        snap_zones: &ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
+        ..Default::default()
+    };
+
+    let errors = config.validate_all();
+    assert_eq!(errors.len(), 4);
+    assert!(errors.contains(ValidationErrors::INPUT_RANGE));
+    assert!(errors.contains(ValidationErrors::OUTPUT_RANGE));
+    assert!(errors.contains(ValidationErrors::FILTER));
+    assert!(errors.contains(ValidationErrors::SNAP_ZONE));
+    assert!(!errors.contains(ValidationErrors::HYSTERESIS));
+
+    // validate() stops at the first error
+    assert!(matches!(
+        config.validate(),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+
+    assert_eq!(
+        errors.to_string(),
+        "input_min must not equal input_max; output_min must not equal output_max; \
+         invalid filter configuration; snap zone target must be in [0.0, 1.0] and threshold >= 0.0"
+    );
+}
+
+#[test]
+fn test_config_error_is_std_error() {
+    fn build() -> Result<Config<'static, u16, f32>, Box<dyn std::error::Error>> {
+        let config = Config::builder()
+            .input_range(100, 100)
+            .output_range(0.0, 1.0)
+            .build()?;
+        Ok(config)
+    }
+
+    let err = build().err().unwrap();
+    assert!(err.downcast_ref::<ConfigError>().is_some());
+    assert!(
+        err.to_string()
+            .starts_with("input_min must not equal input_max")
+    );
+}
+
+#[test]
+fn test_input_center_outside_range_rejected() {
+    for center in [100_u16, 50, 5000] {
+        let result = Config::<u16, f32>::builder()
+            .input_range(100, 4000)
+            .input_center(center)
+            .output_range(0.0, 1.0)
+            .build();
+
+        assert_eq!(
+            result.err(),
+            Some(ConfigError::InvalidInputCenter {
+                center: center as Float
+            })
+        );
+    }
+
+    let config = Config {
+        input_center: Some(4095),
+        ..VALID_CONFIG
+    };
+    assert!(
+        config
+            .validate_all()
+            .contains(ValidationErrors::INPUT_CENTER)
+    );
+}
+
+#[test]
+fn test_end_margin_out_of_range_rejected() {
+    for margin in [-0.1, 0.5, 0.7, Float::NAN] {
+        let config = Config {
+            end_margin: margin,
+            ..VALID_CONFIG
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidEndMargin { .. })
+        ));
+        assert!(config.validate_all().contains(ValidationErrors::END_MARGIN));
+    }
+
+    let config = Config {
+        end_margin: 0.05,
+        ..VALID_CONFIG
+    };
+    assert_eq!(config.validate(), Ok(()));
+}
+
+#[test]
+fn test_non_finite_ranges_rejected() {
+    // NaN or infinite ends, a span overflowing Float, or one so narrow its
+    // reciprocal overflows
+    let ranges: [(Float, Float); 5] = [
+        (Float::NAN, 1.0),
+        (0.0, Float::INFINITY),
+        (Float::NEG_INFINITY, 0.0),
+        (-Float::MAX, Float::MAX),
+        (0.0, Float::MIN_POSITIVE / 4.0),
+    ];
+
+    for (min, max) in ranges {
+        let config = Config::<Float, Float>::builder()
+            .input_range(min, max)
+            .output_range(0.0, 1.0)
+            .build();
+        assert!(
+            matches!(config, Err(ConfigError::InvalidInputRange { .. })),
+            "input {min}..{max}"
+        );
+
+        let config = Config::<Float, Float>::builder()
+            .input_range(0.0, 1.0)
+            .output_range(min, max)
+            .build();
+        assert!(
+            matches!(config, Err(ConfigError::InvalidOutputRange { .. })),
+            "output {min}..{max}"
+        );
+    }
+}
+
+#[test]
+fn test_assert_valid_runs_the_same_range_checks() {
+    // Equal-ends checks alone let all but the first through. The subnormal
+    // span is only invalid with an f32 pipeline
+    let ranges: [(f32, f32); 4] = [
+        (0.0, 1.0),
+        (f32::NAN, 1.0),
+        (0.0, f32::INFINITY),
+        (0.0, f32::MIN_POSITIVE / 4.0),
+    ];
+
+    for (min, max) in ranges {
+        for (input, output) in [((min, max), (0.0, 1.0)), ((0.0, 1.0), (min, max))] {
+            let config = Config {
+                input_min: input.0,
+                input_max: input.1,
+                output_min: output.0,
+                output_max: output.1,
+                ..Config::<f32, f32>::DEFAULT
+            };
+            let rejected = std::panic::catch_unwind(|| pot_head::assert_valid!(config));
+            assert_eq!(
+                rejected.is_err(),
+                config.validate().is_err(),
+                "input {input:?}, output {output:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_input_center_too_close_to_an_end_rejected() {
+    let result = Config::<Float, Float>::builder()
+        .input_range(0.0, 1.0)
+        .input_center(Float::MIN_POSITIVE / 4.0)
+        .output_range(0.0, 1.0)
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidInputCenter { .. })
+    ));
+}
+
+#[test]
+fn test_nan_processing_settings_rejected() {
+    let builder = || {
+        Config::<u16, Float>::builder()
+            .input_range(0, 4095)
+            .output_range(0.0, 1.0)
+    };
+
+    let ema = builder().filter(NoiseFilter::ExponentialMovingAverage { alpha: Float::NAN });
+    assert!(matches!(
+        ema.build(),
+        Err(ConfigError::InvalidFilter { .. })
+    ));
+
+    let schmitt = builder().hysteresis(HysteresisMode::SchmittTrigger {
+        rising: Float::NAN,
+        falling: 0.4,
+    });
+    assert!(matches!(
+        schmitt.build(),
+        Err(ConfigError::InvalidHysteresis { .. })
+    ));
+
+    static NAN_VALUES: [Float; 1] = [Float::NAN];
+    let values = builder().values(&NAN_VALUES);
+    assert_eq!(
+        values.build().err(),
+        Some(ConfigError::UnsortedValues { index: 0 })
+    );
+}
+
+#[test]
+fn test_outputs_outside_normalized_range_rejected() {
+    // Schmitt trigger and snap zones output their levels and targets directly
+    let schmitt = Config::<u16, Float>::builder()
+        .input_range(0, 4095)
+        .output_range(0.0, 1.0)
+        .hysteresis(HysteresisMode::SchmittTrigger {
+            rising: 1.5,
+            falling: 0.4,
+        })
+        .build();
+    assert!(matches!(
+        schmitt,
+        Err(ConfigError::InvalidHysteresis { .. })
+    ));
+
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(-0.2, 0.05, SnapZoneType::Snap)];
+    let config = Config {
+        snap_zones: &ZONES,
+        ..VALID_CONFIG
+    };
+    assert!(matches!(
+        config.validate(),
+        Err(ConfigError::InvalidSnapZone { index: 0, .. })
+    ));
+    assert!(config.validate_all().contains(ValidationErrors::SNAP_ZONE));
+}
+
+#[test]
+fn test_rounding_near_an_end_stays_in_range() {
+    // The reciprocal of a huge span rounds, so inputs just inside the range
+    // could normalize a step past 0.0
+    let config = Config::<Float, Float>::builder()
+        .input_range(-3.2034415e38, 0.0)
+        .invert_input(true)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    let output = pot.update(-2.1818809e24);
+    assert!((0.0..=1.0).contains(&output), "{output}");
 }
@@ -0,0 +1,94 @@
+use pot_head::{Config, ConfigError, CrossfadeLaw, Crossfader, Float, SnapZone, SnapZoneType};
+
+fn fader(law: CrossfadeLaw) -> Crossfader<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    Crossfader::new(config, law).unwrap()
+}
+
+fn close(a: (Float, Float), b: (Float, Float)) -> bool {
+    (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4
+}
+
+#[test]
+fn test_linear() {
+    let mut fader = fader(CrossfadeLaw::Linear);
+
+    assert_eq!(fader.update(0), (1.0, 0.0));
+    assert!(close(fader.update(250), (0.75, 0.25)));
+    assert_eq!(fader.update(1000), (0.0, 1.0));
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_equal_power() {
+    let mut fader = fader(CrossfadeLaw::EqualPower);
+
+    for input in (0..=1000).step_by(50) {
+        let (a, b) = fader.update(input);
+        assert!((a * a + b * b - 1.0).abs() < 1e-4, "{}: {} {}", input, a, b);
+    }
+    assert!(close(fader.update(1000), (0.0, 1.0)));
+}
+
+#[test]
+fn test_constant_gain() {
+    let mut fader = fader(CrossfadeLaw::ConstantGain);
+
+    assert_eq!(fader.update(0), (1.0, 0.0));
+    assert!(close(fader.update(250), (1.0, 0.5)));
+    assert!(close(fader.update(500), (1.0, 1.0)));
+    assert!(close(fader.update(900), (0.2, 1.0)));
+}
+
+#[test]
+fn test_sharp_cut() {
+    let mut fader = fader(CrossfadeLaw::SharpCut { width: 0.05 });
+
+    assert_eq!(fader.update(0), (1.0, 0.0));
+    assert!(close(fader.update(25), (1.0, 0.5)));
+    assert!(close(fader.update(500), (1.0, 1.0)));
+    assert_eq!(fader.update(1000), (0.0, 1.0));
+}
+
+#[test]
+fn test_center_detent_from_snap_zone() {
+    static DETENT: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.03, SnapZoneType::Snap)];
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 1.0)
+        .snap_zones(&DETENT)
+        .build()
+        .unwrap();
+    let mut fader = Crossfader::new(config, CrossfadeLaw::Linear).unwrap();
+
+    assert_eq!(fader.update(520), (0.5, 0.5));
+    assert_eq!(fader.position(), 0.5);
+}
+
+#[test]
+fn test_invalid_law() {
+    for width in [0.0, 0.6, Float::NAN] {
+        let config = Config::builder()
+            .input_range(0_u16, 1000)
+            .output_range(0.0 as Float, 1.0)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            Crossfader::new(config, CrossfadeLaw::SharpCut { width }),
+            Err(ConfigError::InvalidCrossfadeLaw { .. })
+        ));
+    }
+
+    let mut fader = fader(CrossfadeLaw::Linear);
+    assert!(
+        fader
+            .set_law(CrossfadeLaw::SharpCut { width: 1.0 })
+            .is_err()
+    );
+    assert_eq!(fader.law(), CrossfadeLaw::Linear);
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "csv")]
+
+use pot_head::csv::{CsvError, HEADER, Recorder, read_samples, replay};
+use pot_head::{Config, HysteresisMode, NoiseFilter, PotHead};
+
+fn config() -> Config<'static, u16, u8> {
+    Config::from_adc_bits(12)
+        .output_range(0, 127)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.5 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_recorder_writes_every_stage() {
+    let mut pot = PotHead::new(config()).unwrap();
+    let mut recorder = Recorder::new(Vec::new()).unwrap();
+
+    assert_eq!(recorder.update(&mut pot, 0).unwrap(), 0);
+    assert_eq!(recorder.update(&mut pot, 4095).unwrap(), 64);
+    assert_eq!(recorder.samples(), 2);
+
+    let csv = String::from_utf8(recorder.into_inner().unwrap()).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], HEADER);
+    assert_eq!(lines[1], "0,0,0,0,0,0,0,0,0");
+    assert_eq!(lines[2], "1,4095,1,0.5,0.5,0.5,0.5,0.5,64");
+}
+
+#[test]
+fn test_read_samples_from_a_recording_or_a_plain_dump() {
+    let recording = format!("{HEADER}\n0,100,0,0,0,0,0,0,0\n1,2000.4,0,0,0,0,0,0,0\n");
+    let samples: Vec<u16> = read_samples(recording.as_bytes()).unwrap();
+    assert_eq!(samples, [100, 2000]);
+
+    let dump = "512\n\n 1024 \n4095\n";
+    let samples: Vec<u16> = read_samples(dump.as_bytes()).unwrap();
+    assert_eq!(samples, [512, 1024, 4095]);
+
+    let broken = "raw\n12\noops\n";
+    assert!(matches!(
+        read_samples::<u16>(broken.as_bytes()),
+        Err(CsvError::Parse { line: 3 })
+    ));
+}
+
+#[test]
+fn test_replay_reproduces_a_recording() {
+    let captured: Vec<u16> = (0..200).map(|i| ((i * 37) % 4096) as u16).collect();
+
+    let mut pot = PotHead::new(config()).unwrap();
+    let mut recorder = Recorder::new(Vec::new()).unwrap();
+    for &sample in &captured {
+        recorder.update(&mut pot, sample).unwrap();
+    }
+    let recorded = recorder.into_inner().unwrap();
+
+    // The raw column of a recording replays to the same recording
+    let samples: Vec<u16> = read_samples(recorded.as_slice()).unwrap();
+    assert_eq!(samples, captured);
+    let replayed = replay(config(), &samples, Vec::new()).unwrap();
+    assert_eq!(replayed, recorded);
+}
+
+#[test]
+fn test_replay_rejects_an_invalid_config() {
+    let mut config = config();
+    config.output_max = config.output_min;
+
+    assert!(matches!(
+        replay(config, &[0_u16], Vec::new()),
+        Err(CsvError::Config(_))
+    ));
+}
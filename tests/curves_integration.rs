@@ -1,23 +1,37 @@
-use pot_head::{Config, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone};
+use pot_head::{
+    Config, Float, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone, StageOrder,
+};
 
 #[cfg(feature = "grab-mode")]
-use pot_head::GrabMode;
+use pot_head::{GrabMode, ReleasePolicy};
 
-static EMPTY_SNAP_ZONES: [SnapZone<f32>; 0] = [];
+static EMPTY_SNAP_ZONES: [SnapZone<Float>; 0] = [];
 
 #[test]
 fn test_linear_curve_integration() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -28,20 +42,66 @@ fn test_linear_curve_integration() {
     assert_eq!(pot.update(100), 1.0);
 }
 
+/// With the Linear curve, normalization and curve are one multiply-add
+/// against the range reciprocal, bit for bit, and within an ulp of dividing
+/// by the span each sample.
+#[test]
+fn test_linear_curve_fuses_with_normalization() {
+    let (min, max) = (100_u16, 3900_u16);
+    let config = Config::builder()
+        .input_range(min, max)
+        .output_range(0.0 as Float, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    let (min_f, max_f) = (min as Float, max as Float);
+    let scale = 1.0 / (max_f - min_f);
+    for input in min + 1..max {
+        let output = pot.update(input);
+        let offset = input as Float - min_f;
+
+        assert_eq!(
+            output.to_bits(),
+            (offset * scale).to_bits(),
+            "input {input}"
+        );
+        let divided = offset / (max_f - min_f);
+        assert!(
+            (output - divided).abs() <= Float::EPSILON * divided,
+            "input {input}: {output} vs {divided}"
+        );
+    }
+    assert_eq!(pot.update(min), 0.0);
+    assert_eq!(pot.update(max), 1.0);
+}
+
 #[cfg(feature = "std-math")]
 #[test]
 fn test_logarithmic_curve_integration() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Logarithmic,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -82,3 +142,56 @@ fn test_logarithmic_curve_integration() {
     assert!(quarter_output < mid_output);
     assert!(mid_output < max_output);
 }
+
+/// `(e^(3x) - 1) / (e^3 - 1)` in `f64`, the formula `Logarithmic` implements.
+#[cfg(feature = "log-lut")]
+fn exact_log(x: f64) -> f64 {
+    ((3.0 * x).exp() - 1.0) / (3.0_f64.exp() - 1.0)
+}
+
+#[cfg(feature = "log-lut")]
+#[test]
+fn test_logarithmic_lut_matches_formula() {
+    let curve = ResponseCurve::LogarithmicLut;
+
+    assert_eq!(curve.apply(0.0), 0.0);
+    assert_eq!(curve.apply(1.0), 1.0);
+
+    let mut max_error: Float = 0.0;
+    for i in 0..=10_000 {
+        let x = i as f64 / 10_000.0;
+        let error = (curve.apply(x as Float) - exact_log(x) as Float).abs();
+        max_error = max_error.max(error);
+    }
+    assert!(max_error < 1e-4, "max error {max_error}");
+
+    // Out-of-range inputs clamp like the exact curve
+    assert_eq!(curve.apply(-0.5), 0.0);
+    assert_eq!(curve.apply(1.5), 1.0);
+}
+
+#[cfg(all(feature = "log-lut", feature = "std-math"))]
+#[test]
+fn test_logarithmic_lut_matches_libm_curve() {
+    for i in 0..=1000 {
+        let x = i as Float / 1000.0;
+        let lut = ResponseCurve::LogarithmicLut.apply(x);
+        let exact = ResponseCurve::Logarithmic.apply(x);
+        assert!((lut - exact).abs() < 1e-4, "at {x}: {lut} vs {exact}");
+    }
+}
+
+#[cfg(feature = "log-lut")]
+#[test]
+fn test_logarithmic_lut_inverse_round_trip() {
+    let curve = ResponseCurve::LogarithmicLut;
+
+    assert_eq!(curve.inverse(0.0), 0.0);
+    assert_eq!(curve.inverse(1.0), 1.0);
+
+    for i in 0..=1000 {
+        let x = i as Float / 1000.0;
+        let round_trip = curve.inverse(curve.apply(x));
+        assert!((round_trip - x).abs() < 1e-4, "at {x}: {round_trip}");
+    }
+}
@@ -0,0 +1,71 @@
+use pot_head::{Config, Float, FromNormalized, IntoNormalized, PotHead};
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Decibels(Float);
+
+impl IntoNormalized for Decibels {
+    fn into_normalized(self) -> Float {
+        self.0
+    }
+}
+
+impl FromNormalized for Decibels {
+    fn from_normalized(value: Float) -> Self {
+        Decibels(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Midi7(u8);
+
+impl IntoNormalized for Midi7 {
+    fn into_normalized(self) -> Float {
+        self.0 as Float
+    }
+}
+
+impl FromNormalized for Midi7 {
+    fn from_normalized(value: Float) -> Self {
+        Midi7((value + 0.5).clamp(0.0, 127.0) as u8)
+    }
+}
+
+#[test]
+fn test_decibels_output() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(Decibels(-60.0), Decibels(0.0))
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(0), Decibels(-60.0));
+    assert_eq!(pot.update(500), Decibels(-30.0));
+    assert_eq!(pot.update(1000), Decibels(0.0));
+}
+
+#[test]
+fn test_midi7_output() {
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(Midi7(0), Midi7(127))
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(0), Midi7(0));
+    assert_eq!(pot.update(2048), Midi7(64));
+    assert_eq!(pot.update(4095), Midi7(127));
+}
+
+#[test]
+fn test_custom_input_type() {
+    let config = Config::builder()
+        .input_range(Midi7(0), Midi7(127))
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(Midi7(127)), 1.0);
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "defmt")]
+
+use pot_head::{
+    Config, ConfigError, ConfigSummary, Float, HysteresisMode, HysteresisState, NoiseFilter,
+    ResponseCurve, SchmittState, SnapZone, SnapZoneType, StageOrder, State, UpdateEvent,
+    UpdateEvents, ZoneSpan,
+};
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn test_public_types_implement_format() {
+    assert_format::<ConfigError>();
+    assert_format::<Config<u16, f32>>();
+    assert_format::<HysteresisMode<Float>>();
+    assert_format::<HysteresisState<Float>>();
+    assert_format::<SchmittState>();
+    assert_format::<NoiseFilter>();
+    assert_format::<ResponseCurve>();
+    assert_format::<SnapZone<Float>>();
+    assert_format::<SnapZoneType>();
+    assert_format::<State<Float>>();
+    assert_format::<StageOrder>();
+    assert_format::<ConfigSummary>();
+    assert_format::<ZoneSpan>();
+    assert_format::<UpdateEvent>();
+    assert_format::<UpdateEvents>();
+    assert_format::<pot_head::Direction>();
+    assert_format::<pot_head::InputError>();
+    assert_format::<pot_head::Acceleration>();
+    assert_format::<pot_head::EncoderEdge>();
+    assert_format::<pot_head::GangCombine>();
+    assert_format::<pot_head::JoystickDeadZone>();
+    assert_format::<pot_head::CrossfadeLaw>();
+    assert_format::<pot_head::FaderServo>();
+    assert_format::<pot_head::SwitchTiming>();
+    assert_format::<pot_head::PressAction>();
+    assert_format::<pot_head::SwitchEvents>();
+    assert_format::<pot_head::PedalProfile>();
+    assert_format::<pot_head::RibbonRelease<u16>>();
+    assert_format::<pot_head::TouchEvent>();
+    assert_format::<pot_head::LedMode>();
+    assert_format::<pot_head::CcMessages>();
+    assert_format::<pot_head::Cc14>();
+    assert_format::<pot_head::Nrpn>();
+    assert_format::<pot_head::StateSnapshot<u16>>();
+    assert_format::<pot_head::CalibrationData<u16>>();
+    assert_format::<pot_head::SnapshotError>();
+    assert_format::<pot_head::EspAttenuation>();
+    assert_format::<pot_head::Fault>();
+
+    #[cfg(feature = "taps")]
+    assert_format::<pot_head::PipelineTaps<Float>>();
+
+    #[cfg(feature = "fault-detection")]
+    assert_format::<pot_head::FaultDetection<u16>>();
+
+    #[cfg(feature = "noise-report")]
+    assert_format::<pot_head::NoiseReport>();
+    #[cfg(feature = "usage-stats")]
+    assert_format::<pot_head::UsageStats>();
+    #[cfg(feature = "self-test")]
+    assert_format::<pot_head::SelfTestReport>();
+
+    #[cfg(feature = "no-float")]
+    {
+        assert_format::<pot_head::FixedConfig>();
+        assert_format::<pot_head::FixedCurve>();
+    }
+
+    #[cfg(feature = "tuning")]
+    {
+        assert_format::<pot_head::TuningMessage>();
+        assert_format::<pot_head::TuningStatus>();
+    }
+
+    #[cfg(feature = "value-format")]
+    {
+        assert_format::<pot_head::DisplayScale>();
+        assert_format::<pot_head::ValueFormat>();
+    }
+
+    #[cfg(feature = "grab-mode")]
+    {
+        assert_format::<pot_head::GrabMode>();
+        assert_format::<pot_head::ReleasePolicy>();
+    }
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "defmt-trace")]
+
+use pot_head::{Config, PotHead};
+
+#[test]
+fn test_tracing_keeps_output() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    let mut traced = PotHead::new(config).unwrap();
+    let mut silent = PotHead::new(config).unwrap();
+    traced.set_trace_interval(3);
+    silent.set_trace_interval(0);
+
+    for input in (0..=1000).step_by(37) {
+        assert_eq!(traced.update(input), silent.update(input));
+    }
+}
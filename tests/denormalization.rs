@@ -0,0 +1,102 @@
+//! Property tests for integer output rounding across type combinations.
+
+mod common;
+
+use common::pot;
+use proptest::prelude::*;
+
+/// Exact mapping in f64, for comparison against the rounded output.
+fn exact(input: f64, (in_min, in_max): (f64, f64), (out_min, out_max): (f64, f64)) -> f64 {
+    out_min + (input - in_min) / (in_max - in_min) * (out_max - out_min)
+}
+
+macro_rules! rounding_props {
+    ($name:ident, $tin:ty, $tout:ty) => {
+        mod $name {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn ends_map_to_range_ends(
+                    in_min in any::<$tin>(),
+                    in_max in any::<$tin>(),
+                    out_min in any::<$tout>(),
+                    out_max in any::<$tout>(),
+                ) {
+                    prop_assume!(in_min != in_max && out_min != out_max);
+                    let mut pot = pot((in_min, in_max), (out_min, out_max));
+
+                    prop_assert_eq!(pot.update(in_min), out_min);
+                    prop_assert_eq!(pot.update(in_max), out_max);
+                }
+
+                #[test]
+                fn rounds_to_nearest(
+                    in_max in 1..=<$tin>::MAX,
+                    out_max in 1..=<$tout>::MAX,
+                    input in any::<$tin>(),
+                ) {
+                    let mut pot = pot((0, in_max), (0, out_max));
+                    let input = input.min(in_max);
+
+                    let output = pot.update(input) as f64;
+                    let target = exact(input as f64, (0.0, in_max as f64), (0.0, out_max as f64));
+                    // Allow for Float (f32) resolution on wide output ranges
+                    let tolerance = 0.5 + out_max as f64 * 1e-6;
+                    prop_assert!((output - target).abs() <= tolerance, "{} vs {}", output, target);
+                }
+
+                #[test]
+                fn monotonic(
+                    in_max in 1..=<$tin>::MAX,
+                    out_max in 1..=<$tout>::MAX,
+                    a in any::<$tin>(),
+                    b in any::<$tin>(),
+                ) {
+                    let mut pot = pot((0, in_max), (0, out_max));
+                    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+                    let low_out = pot.update(low.min(in_max));
+                    let high_out = pot.update(high.min(in_max));
+                    prop_assert!(low_out <= high_out);
+                }
+            }
+        }
+    };
+}
+
+rounding_props!(u16_to_u8, u16, u8);
+rounding_props!(u8_to_u16, u8, u16);
+rounding_props!(u16_to_u16, u16, u16);
+rounding_props!(u16_to_i8, u16, i8);
+rounding_props!(u8_to_i32, u8, i32);
+
+#[test]
+fn test_midpoint_is_well_defined() {
+    let mut pot = pot((0_u16, 4095), (0_u8, 255));
+
+    // 2047 -> 127.47, 2048 -> 127.53
+    assert_eq!(pot.update(2047), 127);
+    assert_eq!(pot.update(2048), 128);
+}
+
+#[test]
+fn test_full_coverage_when_input_is_finer() {
+    let mut pot = pot((0_u16, 4095), (0_u8, 255));
+
+    let mut seen = [false; 256];
+    for input in 0..=4095 {
+        seen[pot.update(input) as usize] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+}
+
+#[test]
+fn test_descending_output_rounds_to_nearest() {
+    let mut pot = pot((0_u16, 1000), (100_u8, 0));
+
+    assert_eq!(pot.update(0), 100);
+    assert_eq!(pot.update(994), 1);
+    assert_eq!(pot.update(996), 0);
+    assert_eq!(pot.update(1000), 0);
+}
@@ -0,0 +1,110 @@
+use pot_head::{Config, ConfigError, PotHead, ValidationErrors};
+
+static BPM: [u16; 5] = [60, 90, 120, 140, 180];
+
+fn bpm_pot() -> PotHead<'static, u16, u16> {
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(60, 180)
+        .values(&BPM)
+        .build()
+        .unwrap();
+    PotHead::new(config).unwrap()
+}
+
+#[test]
+fn test_outputs_nearest_value() {
+    let mut pot = bpm_pot();
+
+    assert_eq!(pot.update(0), 60);
+    assert_eq!(pot.update(1000), 180);
+
+    // 0.5 -> 120 BPM exactly
+    assert_eq!(pot.update(500), 120);
+    assert_eq!(pot.last_output(), 120);
+
+    // 0.95 -> 174 BPM, nearest is 180
+    assert_eq!(pot.update(950), 180);
+}
+
+#[test]
+fn test_boundary_hysteresis() {
+    let mut pot = bpm_pot();
+
+    // Midpoint between 120 (0.5) and 140 (0.667) is 0.583, margin a quarter gap (0.042)
+    assert_eq!(pot.update(500), 120);
+    assert_eq!(pot.update(600), 120);
+    assert_eq!(pot.update(620), 120);
+    assert_eq!(pot.update(630), 140);
+
+    // Back below the midpoint, but not far enough
+    assert_eq!(pot.update(560), 140);
+    assert_eq!(pot.update(530), 120);
+}
+
+#[test]
+fn test_exact_values_for_uneven_table() {
+    static RESISTORS: [u32; 6] = [1000, 2200, 4700, 10000, 22000, 47000];
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(1000_u32, 47000)
+        .values(&RESISTORS)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    for raw in (0..=1000).step_by(10) {
+        let value = pot.update(raw);
+        assert!(RESISTORS.contains(&value), "{} -> {}", raw, value);
+    }
+}
+
+#[test]
+fn test_change_callback_only_on_selection_change() {
+    use core::sync::atomic::{AtomicU32, Ordering};
+    static CHANGES: AtomicU32 = AtomicU32::new(0);
+
+    let mut pot = bpm_pot();
+    pot.set_on_change(Some(|_| {
+        CHANGES.fetch_add(1, Ordering::Relaxed);
+    }));
+
+    pot.update(500);
+    pot.update(510);
+    pot.update(490);
+    assert_eq!(CHANGES.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_set_output_selects_nearest() {
+    let mut pot = bpm_pot();
+    pot.set_output(135);
+    assert_eq!(pot.last_output(), 140);
+}
+
+#[test]
+fn test_unsorted_values_rejected() {
+    static UNSORTED: [u16; 3] = [60, 140, 120];
+
+    let result = Config::<u16, u16>::builder()
+        .input_range(0, 1000)
+        .output_range(60, 180)
+        .values(&UNSORTED)
+        .build();
+    assert_eq!(result.err(), Some(ConfigError::UnsortedValues { index: 2 }));
+
+    let config = Config {
+        input_min: 0_u16,
+        input_max: 1000,
+        output_min: 60_u16,
+        output_max: 180,
+        values: &UNSORTED,
+        ..Config::builder()
+            .input_range(0, 1000)
+            .output_range(60, 180)
+            .build()
+            .unwrap()
+    };
+    assert!(config.validate_all().contains(ValidationErrors::VALUES));
+}
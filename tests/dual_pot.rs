@@ -0,0 +1,88 @@
+use pot_head::{Config, ConfigError, DualPotHead, Float, GangCombine};
+
+fn config() -> Config<'static, u16, Float> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 100.0)
+        .build()
+        .unwrap()
+}
+
+fn close(a: Float, b: Float) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+#[test]
+fn test_average() {
+    let mut pot = DualPotHead::new(config(), GangCombine::Average, 0.05).unwrap();
+
+    assert!(close(pot.update(500, 520), 51.0));
+    assert!(close(pot.last_output(), 51.0));
+    assert_eq!(pot.update(1000, 1000), 100.0);
+}
+
+#[test]
+fn test_min() {
+    let mut pot = DualPotHead::new(config(), GangCombine::Min, 0.05).unwrap();
+
+    assert!(close(pot.update(500, 520), 50.0));
+    assert!(close(pot.update(700, 650), 65.0));
+}
+
+#[test]
+fn test_values() {
+    static VALUES: [Float; 4] = [0.0, 50.0, 60.0, 100.0];
+    let config = Config {
+        values: &VALUES,
+        ..config()
+    };
+    let mut pot = DualPotHead::new(config, GangCombine::Average, 0.05).unwrap();
+
+    assert_eq!(pot.update(480, 520), 50.0);
+
+    // The gangs select 50 and 100; the combined output is the entry nearest
+    // their mean rather than 75
+    assert_eq!(pot.update(500, 1000), 60.0);
+}
+
+#[test]
+fn test_mismatch_detection() {
+    let mut pot = DualPotHead::new(config(), GangCombine::Average, 0.05).unwrap();
+
+    pot.update(500, 530);
+    assert!(close(pot.mismatch(), 0.03));
+    assert!(!pot.is_mismatched());
+
+    pot.update(500, 600);
+    assert!(pot.is_mismatched());
+
+    // Peak is kept until reset
+    pot.update(500, 500);
+    assert!(!pot.is_mismatched());
+    assert!(close(pot.peak_mismatch(), 0.1));
+
+    pot.reset();
+    assert_eq!(pot.peak_mismatch(), 0.0);
+    assert_eq!(pot.last_output(), 0.0);
+}
+
+#[test]
+fn test_gangs_share_config() {
+    let mut pot = DualPotHead::new(config(), GangCombine::Average, 0.05).unwrap();
+    pot.update(250, 750);
+
+    let [a, b] = pot.gangs();
+    assert!(close(a.last_output(), 25.0));
+    assert!(close(b.last_output(), 75.0));
+    assert_eq!(pot.config().output_max, 100.0);
+}
+
+#[test]
+fn test_invalid_tolerance() {
+    for tolerance in [-0.1, 1.5, Float::NAN] {
+        assert!(matches!(
+            DualPotHead::new(config(), GangCombine::Average, tolerance),
+            Err(ConfigError::InvalidTolerance { .. })
+        ));
+    }
+}
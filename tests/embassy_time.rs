@@ -0,0 +1,68 @@
+#![cfg(feature = "embassy-time")]
+
+use embassy_time::{Duration, Instant};
+use pot_head::{Config, Float, PotHead};
+
+fn pot(slew_rate: Option<Float>) -> PotHead<'static, u16, Float> {
+    let mut builder = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 100.0);
+    if let Some(rate) = slew_rate {
+        builder = builder.slew_rate(rate);
+    }
+    PotHead::new(builder.build().unwrap()).unwrap()
+}
+
+fn at(millis: u64) -> Instant {
+    Instant::from_millis(millis)
+}
+
+#[test]
+fn test_velocity_per_second() {
+    let mut pot = pot(None);
+
+    pot.update_at(0, at(0));
+    assert_eq!(pot.velocity(), 0.0);
+
+    // 0.1 of the travel in 10 ms
+    pot.update_at(100, at(10));
+    assert!((pot.velocity() - 10.0).abs() < 1e-3, "{}", pot.velocity());
+
+    // Same movement, irregular timing: 20 ms
+    pot.update_at(200, at(30));
+    assert!((pot.velocity() - 5.0).abs() < 1e-3, "{}", pot.velocity());
+}
+
+#[test]
+fn test_slew_rate_per_second() {
+    // 100 output units per second
+    let mut pot = pot(Some(100.0));
+
+    assert_eq!(pot.update_at(0, at(0)), 0.0);
+    let output = pot.update_at(1000, at(100));
+    assert!((output - 10.0).abs() < 1e-3, "{}", output);
+    let output = pot.update_at(1000, at(400));
+    assert!((output - 40.0).abs() < 1e-3, "{}", output);
+
+    // Plain update() still steps per update
+    let output = pot.update(1000);
+    assert!((output - 100.0).abs() < 1e-3, "{}", output);
+}
+
+#[test]
+fn test_idle_for() {
+    let mut pot = pot(None);
+    assert!(!pot.is_idle_for(Duration::from_millis(1), at(0)));
+
+    pot.update_at(500, at(0));
+    pot.update_at(500, at(300));
+    assert_eq!(pot.idle_for(at(300)), Duration::from_millis(300));
+    assert!(pot.is_idle_for(Duration::from_millis(200), at(300)));
+
+    pot.update_at(600, at(400));
+    assert_eq!(pot.idle_for(at(450)), Duration::from_millis(50));
+    assert!(!pot.is_idle_for(Duration::from_millis(200), at(450)));
+
+    pot.reset();
+    assert_eq!(pot.idle_for(at(1000)), Duration::MIN);
+}
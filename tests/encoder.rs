@@ -0,0 +1,176 @@
+use pot_head::{
+    Acceleration, Config, ConfigError, EncoderEdge, EncoderHead, Float, SnapZone, SnapZoneType,
+};
+
+/// 100 detents from 0 to 127.
+fn encoder() -> EncoderHead<'static, u8> {
+    let config = Config::builder()
+        .input_range(0.0, 100.0)
+        .output_range(0_u8, 127)
+        .build()
+        .unwrap();
+    EncoderHead::new(config).unwrap()
+}
+
+#[test]
+fn test_deltas_accumulate() {
+    let mut encoder = encoder();
+
+    assert_eq!(encoder.last_output(), 0);
+    assert_eq!(encoder.update(10), 13);
+    assert_eq!(encoder.update(40), 64);
+    assert_eq!(encoder.update(-25), 32);
+    assert_eq!(encoder.position(), 25.0);
+}
+
+#[test]
+fn test_clamps_at_ends() {
+    let mut encoder = encoder();
+
+    assert_eq!(encoder.update(-5), 0);
+    assert_eq!(encoder.update(150), 127);
+    assert_eq!(encoder.position(), 100.0);
+
+    // Turning back responds immediately, without winding off the overshoot
+    assert_eq!(encoder.update(-1), 126);
+}
+
+#[test]
+fn test_wraps_at_ends() {
+    let mut encoder = encoder();
+    encoder.set_edge(EncoderEdge::Wrap);
+
+    encoder.update(-1);
+    assert_eq!(encoder.position(), 99.0);
+
+    encoder.update(3);
+    assert_eq!(encoder.position(), 2.0);
+
+    encoder.update(-250);
+    assert_eq!(encoder.position(), 52.0);
+}
+
+#[test]
+fn test_acceleration() {
+    let mut encoder = encoder();
+    encoder
+        .set_acceleration(Acceleration::Linear {
+            gain: 0.5,
+            max: 4.0,
+        })
+        .unwrap();
+
+    // Slow turns move one step per detent
+    encoder.update(1);
+    assert_eq!(encoder.position(), 1.0);
+
+    // 3 detents in one update: 2 steps per detent
+    encoder.update(3);
+    assert_eq!(encoder.position(), 7.0);
+
+    // Capped at 4 steps per detent
+    encoder.update(-20);
+    assert_eq!(encoder.position(), 0.0);
+    encoder.update(20);
+    assert_eq!(encoder.position(), 80.0);
+
+    let quadratic = Acceleration::Quadratic {
+        gain: 0.5,
+        max: 10.0,
+    };
+    assert_eq!(quadratic.multiplier(1.0), 1.0);
+    assert_eq!(quadratic.multiplier(3.0), 3.0);
+    assert_eq!(quadratic.multiplier(9.0), 10.0);
+}
+
+#[test]
+fn test_invalid_acceleration() {
+    let mut encoder = encoder();
+
+    for acceleration in [
+        Acceleration::Linear {
+            gain: -1.0,
+            max: 4.0,
+        },
+        Acceleration::Quadratic {
+            gain: 1.0,
+            max: 0.5,
+        },
+        Acceleration::Linear {
+            gain: Float::NAN,
+            max: 4.0,
+        },
+    ] {
+        assert!(matches!(
+            encoder.set_acceleration(acceleration),
+            Err(ConfigError::InvalidAcceleration { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_set_output_moves_position() {
+    let mut encoder = encoder();
+
+    encoder.set_output(127);
+    assert_eq!(encoder.position(), 100.0);
+    assert_eq!(encoder.update(-50), 64);
+}
+
+#[test]
+fn test_snap_zone() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.03, SnapZoneType::Snap)];
+
+    let config = Config::builder()
+        .input_range(0.0 as Float, 100.0)
+        .output_range(0.0 as Float, 1.0)
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap();
+    let mut encoder = EncoderHead::new(config).unwrap();
+
+    encoder.update(48);
+    assert_eq!(encoder.last_output(), 0.5);
+
+    // Turning on leaves the zone
+    encoder.update(6);
+    assert!((encoder.last_output() - 0.54).abs() < 1e-4);
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_curve() {
+    use pot_head::ResponseCurve;
+
+    let config = Config::builder()
+        .input_range(0.0 as Float, 100.0)
+        .output_range(0.0 as Float, 1.0)
+        .curve(ResponseCurve::Logarithmic)
+        .build()
+        .unwrap();
+    let mut encoder = EncoderHead::new(config).unwrap();
+
+    let half = encoder.update(50);
+    assert!((half - ResponseCurve::Logarithmic.apply(0.5)).abs() < 1e-5);
+
+    encoder.set_output(half);
+    assert!((encoder.position() - 50.0).abs() < 1e-3);
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_always_grabbed() {
+    use pot_head::GrabMode;
+
+    let config = Config::builder()
+        .input_range(0.0 as Float, 100.0)
+        .output_range(0.0 as Float, 1.0)
+        .grab_mode(GrabMode::Pickup)
+        .build()
+        .unwrap();
+    let mut encoder = EncoderHead::new(config).unwrap();
+
+    encoder.set_output(0.8);
+    assert!(!encoder.pot().is_waiting_for_grab());
+    assert!((encoder.update(1) - 0.81).abs() < 1e-4);
+}
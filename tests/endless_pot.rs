@@ -0,0 +1,204 @@
+use pot_head::{
+    Config, ConfigError, EndlessPotHead, Float, HysteresisMode, NoiseFilter, SnapZone, SnapZoneType,
+};
+
+fn endless(filter: NoiseFilter) -> EndlessPotHead<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .filter(filter)
+        .build()
+        .unwrap();
+    EndlessPotHead::new(config).unwrap()
+}
+
+fn close(a: Float, b: Float) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+#[test]
+fn test_output_wraps() {
+    let mut pot = endless(NoiseFilter::None);
+
+    assert!(close(pot.update(900), 0.9));
+    assert!(close(pot.update(50), 0.05));
+    assert!(close(pot.position(), 1.05));
+
+    // And back the other way
+    assert!(close(pot.update(950), 0.95));
+    assert!(close(pot.position(), 0.95));
+}
+
+#[test]
+fn test_filter_takes_shortest_path() {
+    let mut pot = endless(NoiseFilter::ExponentialMovingAverage { alpha: 0.5 });
+
+    pot.update(950);
+
+    // Crossing the seam: the filter moves forward through 0, not back through 0.5
+    let output = pot.update(50);
+    assert!(close(output, 0.0) || close(output, 1.0), "{}", output);
+    assert!(close(pot.position(), 1.0));
+
+    let output = pot.update(50);
+    assert!(close(output, 0.025), "{}", output);
+}
+
+#[test]
+fn test_hysteresis_across_seam() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.05 })
+        .build()
+        .unwrap();
+    let mut pot = EndlessPotHead::new(config).unwrap();
+
+    assert!(close(pot.update(990), 0.99));
+
+    // 2% across the seam is held, like any other small move
+    assert!(close(pot.update(10), 0.99));
+    assert!(close(pot.update(60), 0.06));
+}
+
+#[test]
+fn test_relative_motion() {
+    let mut pot = endless(NoiseFilter::None);
+
+    pot.update(200);
+    assert_eq!(pot.take_motion(), 0.0);
+
+    // Two full turns forward in quarter steps
+    for step in 1..=8 {
+        pot.update(((200 + step * 250) % 1000) as u16);
+    }
+    assert!(close(pot.delta(), 0.25));
+    assert!(close(pot.take_motion(), 2.0));
+    assert_eq!(pot.take_motion(), 0.0);
+
+    pot.update(100);
+    assert!(close(pot.delta(), -0.1));
+}
+
+#[test]
+fn test_resolution_kept_after_many_turns() {
+    let mut pot = endless(NoiseFilter::ExponentialMovingAverage { alpha: 0.5 });
+
+    // 20000 turns forward, as a knob might see over months of use
+    for step in 0..200_000_u32 {
+        pot.update((step % 10 * 100) as u16);
+    }
+    for _ in 0..20 {
+        pot.update(0);
+    }
+    pot.take_motion();
+
+    // A 3‰ move still registers as one, not a whole float step of the turn count
+    for _ in 0..20 {
+        pot.update(3);
+    }
+    assert!((pot.take_motion() - 0.003).abs() < 1e-4);
+    assert!((pot.position() - 20_000.003).abs() < 0.01);
+}
+
+#[test]
+fn test_reset_and_invalid_input() {
+    let mut pot = endless(NoiseFilter::None);
+    pot.update(300);
+    pot.update(600);
+
+    pot.reset();
+    pot.update(100);
+    assert!(close(pot.position(), 0.1));
+    assert_eq!(pot.take_motion(), 0.0);
+
+    let config = Config::builder()
+        .input_range(0.0 as Float, 1.0)
+        .output_range(0.0 as Float, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = EndlessPotHead::new(config).unwrap();
+    pot.update(0.4);
+    assert!(close(pot.update(Float::NAN), 0.4));
+    assert!(close(pot.update(Float::INFINITY), 0.4));
+}
+
+#[test]
+fn test_normalizes_like_pothead() {
+    // Detent at 300 is half a turn; 5% either side of the seam is dead
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .input_center(300)
+        .end_margin(0.05)
+        .output_range(0.0 as Float, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = EndlessPotHead::new(config).unwrap();
+
+    assert!(close(pot.update(300), 0.5));
+    pot.update(800);
+    assert!(close(pot.update(975), 0.0));
+    assert!(close(pot.update(10), 0.0));
+    assert!(close(pot.position(), 1.0));
+
+    // Readings past the range clamp to the seam
+    assert!(close(pot.update(1200), 0.0));
+}
+
+#[test]
+fn test_schmitt_trigger_rejected() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 1.0)
+        .hysteresis(HysteresisMode::SchmittTrigger {
+            rising: 0.6,
+            falling: 0.4,
+        })
+        .build()
+        .unwrap();
+    assert!(matches!(
+        EndlessPotHead::new(config),
+        Err(ConfigError::InvalidHysteresis { .. })
+    ));
+}
+
+#[test]
+fn test_unsupported_settings_rejected() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.05, SnapZoneType::Snap)];
+    static VALUES: [Float; 2] = [0.0, 1.0];
+
+    let base = Config::<u16, Float>::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    let unsupported = [
+        Config {
+            snap_zones: &ZONES,
+            ..base
+        },
+        Config {
+            values: &VALUES,
+            ..base
+        },
+        Config {
+            slew_rate: Some(0.1),
+            ..base
+        },
+    ];
+    for config in unsupported {
+        assert!(matches!(
+            EndlessPotHead::new(config),
+            Err(ConfigError::UnsupportedSetting { .. })
+        ));
+    }
+
+    #[cfg(feature = "grab-mode")]
+    assert!(matches!(
+        EndlessPotHead::new(Config {
+            grab_mode: pot_head::GrabMode::Pickup,
+            ..base
+        }),
+        Err(ConfigError::UnsupportedSetting { .. })
+    ));
+}
@@ -0,0 +1,54 @@
+use pot_head::{Config, EspAttenuation, HysteresisMode, NoiseFilter, PotHead};
+
+#[test]
+fn millivolt_span_follows_attenuation() {
+    assert_eq!(EspAttenuation::Db0.esp32c3_millivolts(), (0, 750));
+    assert_eq!(EspAttenuation::default().esp32c3_millivolts(), (0, 2500));
+}
+
+#[test]
+fn builder_sets_range_and_noise_preset() {
+    let config = Config::<u16, f32>::builder()
+        .input_esp32c3_millivolts(EspAttenuation::Db11)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+
+    assert_eq!((config.input_min, config.input_max), (0, 2500));
+    assert_eq!(
+        config.filter,
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.2 }
+    );
+    assert_eq!(
+        config.hysteresis,
+        HysteresisMode::ChangeThreshold { threshold: 0.01 }
+    );
+}
+
+#[test]
+fn preset_can_be_overridden() {
+    let config = Config::<u16, f32>::builder()
+        .input_esp32c3_millivolts(EspAttenuation::Db6)
+        .filter(NoiseFilter::None)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.input_max, 1300);
+    assert_eq!(config.filter, NoiseFilter::None);
+}
+
+#[test]
+fn readings_beyond_span_clamp() {
+    let config = Config::<u16, f32>::builder()
+        .input_esp32c3_millivolts(EspAttenuation::Db11)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    for _ in 0..100 {
+        pot.update(2800);
+    }
+    assert_eq!(pot.last_output(), 1.0);
+}
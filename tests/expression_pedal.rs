@@ -0,0 +1,171 @@
+use pot_head::{Config, ConfigError, ExpressionPedal, Float, PedalProfile};
+
+const PROFILE: PedalProfile = PedalProfile {
+    heel_dead_zone: 0.1,
+    toe_plateau: 0.1,
+    return_speed: 0.2,
+};
+
+fn pedal(heel: u16, toe: u16) -> ExpressionPedal<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(heel, toe)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    ExpressionPedal::new(config, PROFILE).unwrap()
+}
+
+#[test]
+fn test_dead_zone_and_plateau() {
+    let mut pedal = pedal(0, 1000);
+    assert_eq!(pedal.config().input_min, 100);
+    assert_eq!(pedal.config().input_max, 900);
+
+    assert_eq!(pedal.update(50), 0.0);
+    assert_eq!(pedal.update(500), 0.5);
+    assert_eq!(pedal.update(950), 1.0);
+}
+
+#[test]
+fn test_calibrate() {
+    let mut pedal = pedal(0, 1000);
+
+    pedal.calibrate(200, 600).unwrap();
+    assert_eq!(pedal.calibration(), (200, 600));
+    assert_eq!(pedal.update(400), 0.5);
+    assert_eq!(pedal.update(570), 1.0);
+
+    // Too narrow for the profile: previous calibration kept
+    assert!(pedal.calibrate(300, 300).is_err());
+    assert_eq!(pedal.calibration(), (200, 600));
+}
+
+#[test]
+fn test_learning() {
+    let mut pedal = pedal(0, 4095);
+    pedal.start_learning();
+    assert!(pedal.is_learning());
+
+    for input in [1500, 300, 900, 2300, 1200] {
+        pedal.update(input);
+    }
+    pedal.finish_learning().unwrap();
+
+    assert!(!pedal.is_learning());
+    assert_eq!(pedal.calibration(), (300, 2300));
+    assert_eq!(pedal.update(2300), 1.0);
+}
+
+#[test]
+fn test_learning_keeps_orientation() {
+    let mut pedal = pedal(1000, 0);
+    pedal.start_learning();
+    for input in [800, 200] {
+        pedal.update(input);
+    }
+    pedal.finish_learning().unwrap();
+
+    assert_eq!(pedal.calibration(), (800, 200));
+    assert_eq!(pedal.update(800), 0.0);
+    assert_eq!(pedal.update(200), 1.0);
+}
+
+#[test]
+fn test_learning_without_samples() {
+    let mut pedal = pedal(0, 1000);
+    pedal.start_learning();
+
+    assert!(matches!(
+        pedal.finish_learning(),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+    assert!(!pedal.is_learning());
+    assert_eq!(pedal.calibration(), (0, 1000));
+}
+
+#[test]
+fn test_spring_return() {
+    let mut pedal = pedal(0, 1000);
+
+    pedal.update(900);
+    assert!(!pedal.is_at_heel());
+
+    // Snapping back fast
+    pedal.update(500);
+    assert!(!pedal.returned());
+    pedal.update(0);
+    assert!(pedal.returned());
+    assert!(pedal.is_at_heel());
+
+    // Only reported once
+    pedal.update(0);
+    assert!(!pedal.returned());
+}
+
+#[test]
+fn test_slow_return_is_not_spring() {
+    let mut pedal = pedal(0, 1000);
+
+    pedal.update(900);
+    let mut returned = false;
+    for input in (0..900).rev().step_by(50) {
+        pedal.update(input);
+        returned |= pedal.returned();
+    }
+    assert!(pedal.is_at_heel());
+    assert!(!returned);
+}
+
+#[test]
+fn test_invalid_profile() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+
+    let profiles = [
+        PedalProfile {
+            heel_dead_zone: -0.1,
+            ..PROFILE
+        },
+        PedalProfile {
+            toe_plateau: 1.0,
+            ..PROFILE
+        },
+        PedalProfile {
+            heel_dead_zone: 0.5,
+            toe_plateau: 0.5,
+            ..PROFILE
+        },
+        PedalProfile {
+            return_speed: 0.0,
+            ..PROFILE
+        },
+    ];
+    for profile in profiles {
+        assert!(matches!(
+            ExpressionPedal::new(config, profile),
+            Err(ConfigError::InvalidPedal { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_set_profile() {
+    let mut pedal = pedal(0, 1000);
+
+    pedal.set_profile(PedalProfile::DEFAULT).unwrap();
+    assert_eq!(pedal.config().input_min, 20);
+    assert_eq!(pedal.config().input_max, 950);
+
+    assert!(
+        pedal
+            .set_profile(PedalProfile {
+                return_speed: -1.0,
+                ..PedalProfile::DEFAULT
+            })
+            .is_err()
+    );
+    assert_eq!(pedal.profile(), PedalProfile::DEFAULT);
+}
@@ -0,0 +1,119 @@
+use pot_head::{Config, ConfigError, FaderServo, Float, PotHead, SnapZone, SnapZoneType};
+
+const SERVO: FaderServo = FaderServo {
+    dead_band: 0.01,
+    slow_zone: 0.11,
+    min_duty: 0.2,
+    max_duty: 1.0,
+};
+
+fn close(a: Float, b: Float) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+#[test]
+fn test_drive_direction_and_slow_down() {
+    // Far away: full duty towards the target
+    assert_eq!(SERVO.drive(0.9, 0.1), 1.0);
+    assert_eq!(SERVO.drive(0.1, 0.9), -1.0);
+
+    // Inside the slow zone: ramps from max_duty down to min_duty
+    assert!(close(SERVO.drive(0.5, 0.44), 0.6));
+    assert!(close(SERVO.drive(0.5, 0.5105), -0.204));
+
+    // Dead band stops the motor
+    assert_eq!(SERVO.drive(0.5, 0.505), 0.0);
+    assert_eq!(SERVO.drive(0.5, Float::NAN), 0.0);
+}
+
+#[test]
+fn test_drive_to_pot_target() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0_u8, 127)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(200);
+    assert_eq!(SERVO.drive_to(&pot, 127), 1.0);
+    assert!(!SERVO.at_target(&pot, 127));
+
+    pot.update(1000);
+    assert_eq!(SERVO.drive_to(&pot, 127), 0.0);
+    assert!(SERVO.at_target(&pot, 127));
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_target_mapped_through_inverse_curve() {
+    use pot_head::ResponseCurve;
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 1.0)
+        .curve(ResponseCurve::Logarithmic)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    // The output at half travel is well below 0.5 on an audio taper
+    let half = ResponseCurve::Logarithmic.apply(0.5);
+    pot.update(500);
+    assert!(SERVO.at_target(&pot, half));
+    assert!(SERVO.drive_to(&pot, 0.5) > 0.0);
+}
+
+#[test]
+fn test_stops_once_the_pot_outputs_the_target() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.1, SnapZoneType::Snap)];
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 1.0)
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(200);
+    assert_eq!(SERVO.drive_to(&pot, 0.5), 1.0);
+
+    // Inside the snap zone the pot already outputs the target
+    pot.update(420);
+    assert_eq!(SERVO.drive_to(&pot, 0.5), 0.0);
+    assert!(SERVO.at_target(&pot, 0.5));
+}
+
+#[test]
+fn test_validation() {
+    assert!(FaderServo::DEFAULT.validate().is_ok());
+
+    for servo in [
+        FaderServo {
+            dead_band: -0.1,
+            ..SERVO
+        },
+        FaderServo {
+            slow_zone: 0.005,
+            ..SERVO
+        },
+        FaderServo {
+            min_duty: 1.5,
+            ..SERVO
+        },
+        FaderServo {
+            max_duty: 2.0,
+            min_duty: 0.2,
+            ..SERVO
+        },
+        FaderServo {
+            max_duty: Float::NAN,
+            ..SERVO
+        },
+    ] {
+        assert!(matches!(
+            servo.validate(),
+            Err(ConfigError::InvalidServo { .. })
+        ));
+    }
+}
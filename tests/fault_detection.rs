@@ -0,0 +1,174 @@
+#![cfg(feature = "fault-detection")]
+
+use pot_head::{Config, ConfigError, Fault, FaultDetection, PotHead, UpdateEvent};
+
+const DETECTION: FaultDetection<u16> = FaultDetection {
+    low_rail: 20,
+    high_rail: 4075,
+    rail_samples: 3,
+    max_step: Some(0.5),
+    hold_output: false,
+};
+
+fn pot(detection: FaultDetection<u16>) -> PotHead<'static, u16, f32> {
+    let config = Config::builder()
+        .input_range(100_u16, 3995)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.set_fault_detection(Some(detection)).unwrap();
+    pot
+}
+
+#[test]
+fn disabled_by_default() {
+    let config = Config::builder()
+        .input_range(100_u16, 3995)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    for _ in 0..10 {
+        pot.update(0);
+    }
+    assert_eq!(pot.fault(), None);
+}
+
+#[test]
+fn sustained_rail_reading() {
+    let mut pot = pot(FaultDetection {
+        max_step: None,
+        ..DETECTION
+    });
+
+    pot.update(0);
+    pot.update(0);
+    assert_eq!(pot.fault(), None);
+    pot.update(0);
+    assert_eq!(pot.fault(), Some(Fault::LowRail));
+
+    // Clears once the reading is plausible again
+    pot.update(500);
+    assert_eq!(pot.fault(), None);
+
+    for _ in 0..3 {
+        pot.update(4095);
+    }
+    assert_eq!(pot.fault(), Some(Fault::HighRail));
+}
+
+#[test]
+fn alternating_rails_restart_count() {
+    let mut pot = pot(FaultDetection {
+        max_step: None,
+        ..DETECTION
+    });
+
+    for input in [0, 0, 4095, 4095, 0, 0] {
+        pot.update(input);
+        assert_eq!(pot.fault(), None);
+    }
+}
+
+#[test]
+fn impossible_step() {
+    let mut pot = pot(DETECTION);
+
+    pot.update(1000);
+    pot.update(1500);
+    assert_eq!(pot.fault(), None);
+
+    pot.update(3900);
+    assert_eq!(pot.fault(), Some(Fault::Jump));
+
+    pot.update(3890);
+    assert_eq!(pot.fault(), None);
+}
+
+#[test]
+fn hold_output_ignores_faulty_samples() {
+    let mut pot = pot(FaultDetection {
+        hold_output: true,
+        ..DETECTION
+    });
+
+    pot.update(3000);
+
+    // Rail readings below rail_samples still pass through, clamped
+    assert_eq!(pot.update(4095), 1.0);
+    assert_eq!(pot.update(4095), 1.0);
+
+    // Sustained rail is held
+    assert_eq!(pot.update(4095), 1.0);
+    assert_eq!(pot.fault(), Some(Fault::HighRail));
+    let healthy = pot.update(3000);
+
+    // Jump is held
+    assert_eq!(pot.update(10), healthy);
+    assert_eq!(pot.fault(), Some(Fault::Jump));
+}
+
+#[test]
+fn fault_event_on_onset() {
+    let mut pot = pot(FaultDetection {
+        max_step: None,
+        ..DETECTION
+    });
+
+    pot.update(0);
+    pot.update(0);
+    let (_, events) = pot.update_with_events(0);
+    assert_eq!(events.fault, Some(Fault::LowRail));
+    assert!(
+        events
+            .iter()
+            .any(|e| e == UpdateEvent::Fault(Fault::LowRail))
+    );
+
+    // Reported once, while fault() stays set
+    let (_, events) = pot.update_with_events(0);
+    assert_eq!(events.fault, None);
+    assert_eq!(pot.fault(), Some(Fault::LowRail));
+}
+
+#[test]
+fn reset_clears_fault() {
+    let mut pot = pot(DETECTION);
+    for _ in 0..3 {
+        pot.update(0);
+    }
+    pot.reset();
+    assert_eq!(pot.fault(), None);
+}
+
+#[test]
+fn invalid_settings_rejected() {
+    let mut pot = pot(DETECTION);
+
+    let result = pot.set_fault_detection(Some(FaultDetection {
+        low_rail: 4075,
+        high_rail: 20,
+        ..DETECTION
+    }));
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidFaultDetection { .. })
+    ));
+
+    let result = pot.set_fault_detection(Some(FaultDetection {
+        max_step: Some(0.0),
+        ..DETECTION
+    }));
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidFaultDetection { .. })
+    ));
+
+    // Previous settings still active
+    for _ in 0..3 {
+        pot.update(0);
+    }
+    assert_eq!(pot.fault(), Some(Fault::LowRail));
+}
@@ -1,4 +1,5 @@
 mod ema_tests {
+    use pot_head::Float;
     use pot_head::filters::EmaFilter;
 
     #[test]
@@ -65,10 +66,10 @@ mod ema_tests {
         assert!(output_variance < input_variance);
     }
 
-    fn variance(data: &[f32]) -> f32 {
-        let mean: f32 = data.iter().sum::<f32>() / data.len() as f32;
-        let variance: f32 =
-            data.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / data.len() as f32;
+    fn variance(data: &[Float]) -> Float {
+        let mean: Float = data.iter().sum::<Float>() / data.len() as Float;
+        let variance: Float =
+            data.iter().map(|x| (x - mean).powi(2)).sum::<Float>() / data.len() as Float;
         variance
     }
 }
@@ -164,6 +165,7 @@ mod moving_avg_tests {
 
 #[cfg(feature = "moving-average")]
 mod comparison_tests {
+    use pot_head::Float;
     use pot_head::filters::{EmaFilter, MovingAvgFilter};
 
     #[test]
@@ -173,14 +175,14 @@ mod comparison_tests {
 
         let noisy = [1.0, 1.1, 0.9, 1.05, 0.95, 1.0];
 
-        let ema_outputs: Vec<f32> = noisy.iter().map(|&x| ema.apply(x, 0.3)).collect();
+        let ema_outputs: Vec<Float> = noisy.iter().map(|&x| ema.apply(x, 0.3)).collect();
 
-        let ma_outputs: Vec<f32> = noisy.iter().map(|&x| ma.apply(x)).collect();
+        let ma_outputs: Vec<Float> = noisy.iter().map(|&x| ma.apply(x)).collect();
 
         // Both should produce smoother output than input
         // Just verify they produce reasonable values
-        assert!(ema_outputs.iter().all(|&x| x >= 0.9 && x <= 1.1));
-        assert!(ma_outputs.iter().all(|&x| x >= 0.9 && x <= 1.1));
+        assert!(ema_outputs.iter().all(|x| (0.9..=1.1).contains(x)));
+        assert!(ma_outputs.iter().all(|x| (0.9..=1.1).contains(x)));
     }
 
     #[test]
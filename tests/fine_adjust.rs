@@ -0,0 +1,216 @@
+#![cfg(feature = "fine-adjust")]
+
+use pot_head::{
+    Config, ConfigError, FineAdjust, Float, PotHead, SnapZone, SnapZoneType, StageOrder,
+    ValidationErrors,
+};
+
+/// 10% of the movement below 1% of travel per update, 1:1 above
+const FINE: FineAdjust = FineAdjust::new(0.1, 0.01);
+
+fn fine_pot() -> PotHead<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .fine_adjust(FINE)
+        .build()
+        .unwrap();
+    PotHead::new(config).unwrap()
+}
+
+#[test]
+fn test_first_update_follows_knob() {
+    let mut pot = fine_pot();
+    assert!((pot.update(400) - 0.4).abs() < 1e-6);
+}
+
+#[test]
+fn test_slow_turn_is_scaled_down() {
+    let mut pot = fine_pot();
+    pot.update(500);
+
+    // At 1‰ per update the gain is 0.19: 10 counts move the output by 2‰
+    let mut output = 0.0;
+    for raw in 501..=510 {
+        output = pot.update(raw);
+    }
+    assert!(output > 0.5 && output < 0.5025, "{}", output);
+}
+
+#[test]
+fn test_sub_step_resolution_accumulates() {
+    // An 8-bit output moves one step every few counts of a slow turn
+    let config = Config::<u16, u8>::builder()
+        .input_range(0, 1000)
+        .output_range(0, 255)
+        .fine_adjust(FINE)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    let start = pot.update(500);
+
+    let steps: Vec<u8> = (501..=560).map(|raw| pot.update(raw)).collect();
+    assert!(steps.iter().filter(|&&s| s == start).count() > 5);
+    assert!(*steps.last().unwrap() > start);
+}
+
+#[test]
+fn test_fast_turn_maps_one_to_one() {
+    let mut pot = fine_pot();
+    pot.update(500);
+    assert!((pot.update(600) - 0.6).abs() < 1e-6);
+    assert!((pot.update(450) - 0.45).abs() < 1e-6);
+}
+
+#[test]
+fn test_stops_reach_the_ends() {
+    let mut pot = fine_pot();
+    pot.update(500);
+    for raw in 501..=520 {
+        pot.update(raw);
+    }
+    let offset = pot.update(520);
+    assert!(offset < 0.52);
+
+    // Fast turn to the top still reaches full scale
+    for raw in (520..=1000).step_by(50) {
+        pot.update(raw);
+    }
+    assert_eq!(pot.update(1000), 1.0);
+    assert_eq!(pot.update(0), 0.0);
+}
+
+#[test]
+fn test_offset_shrinks_towards_the_end() {
+    let mut pot = fine_pot();
+    pot.update(500);
+    for raw in (490..500).rev() {
+        pot.update(raw);
+    }
+
+    // Knob below the output: a fast turn up closes the gap proportionally
+    let low = pot.update(490);
+    let high = pot.update(745);
+    assert!(low > 0.49);
+    assert!(high > 0.745 && high - 0.745 < low - 0.49);
+}
+
+#[test]
+fn test_set_output_continues_from_there() {
+    let mut pot = fine_pot();
+    pot.update(500);
+    pot.set_output(0.2);
+
+    // Next slow move is relative to the set value
+    let output = pot.update(501);
+    assert!((output - 0.2).abs() < 0.001, "{}", output);
+}
+
+#[test]
+fn test_reset_follows_knob_again() {
+    let mut pot = fine_pot();
+    pot.update(500);
+    for raw in 501..=520 {
+        pot.update(raw);
+    }
+    pot.reset();
+    assert!((pot.update(520) - 0.52).abs() < 1e-6);
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let config = Config::<u16, Float>::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    assert_eq!(config.fine_adjust, None);
+
+    let mut pot = PotHead::new(config).unwrap();
+    pot.update(500);
+    assert!((pot.update(501) - 0.501).abs() < 1e-6);
+}
+
+#[test]
+fn test_snap_zone_sees_adjusted_value() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.01, SnapZoneType::Snap)];
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .snap_zones(&ZONES)
+        .fine_adjust(FINE)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    // Knob at 52% with the output creeping up from 50.3%: still in the zone
+    pot.update(503);
+    for raw in 504..=520 {
+        pot.update(raw);
+    }
+    assert_eq!(pot.update(520), 0.5);
+}
+
+#[test]
+fn test_physical_zones_stay_on_the_knob() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.005, SnapZoneType::Snap)];
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .snap_zones(&ZONES)
+        .stage_order(StageOrder::PhysicalFirst)
+        .fine_adjust(FINE)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(503);
+    assert_eq!(pot.update(503), 0.5);
+
+    // Leaving the zone slowly: the output eases away from the detent
+    let output = pot.update(506);
+    assert!(output > 0.5 && output < 0.506, "{}", output);
+}
+
+#[test]
+fn test_reenabling_starts_afresh() {
+    let mut pot = fine_pot();
+    pot.update(500);
+    pot.reconfigure(|c| c.fine_adjust = None).unwrap();
+    pot.update(900);
+    pot.reconfigure(|c| c.fine_adjust = Some(FINE)).unwrap();
+    assert!((pot.update(901) - 0.901).abs() < 1e-6);
+}
+
+#[test]
+fn test_validation() {
+    let invalid = [
+        FineAdjust::new(0.0, 0.01),
+        FineAdjust::new(1.5, 0.01),
+        FineAdjust::new(Float::NAN, 0.01),
+        FineAdjust::new(0.1, 0.0),
+        FineAdjust::new(0.1, Float::INFINITY),
+    ];
+    for fine in invalid {
+        let result = Config::<u16, Float>::builder()
+            .input_range(0, 1000)
+            .output_range(0.0, 1.0)
+            .fine_adjust(fine)
+            .build();
+        assert!(
+            matches!(result, Err(ConfigError::InvalidFineAdjust { .. })),
+            "{:?}",
+            fine
+        );
+    }
+
+    let config = Config {
+        fine_adjust: Some(FineAdjust::new(0.0, 0.01)),
+        ..Config::<u16, f32>::DEFAULT
+    };
+    assert!(
+        config
+            .validate_all()
+            .contains(ValidationErrors::FINE_ADJUST)
+    );
+}
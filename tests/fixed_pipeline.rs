@@ -0,0 +1,166 @@
+#![cfg(feature = "no-float")]
+
+use pot_head::fixed::{ONE, ratio};
+use pot_head::{ConfigError, FixedConfig, FixedCurve, FixedPotHead, SnapZone, SnapZoneType};
+
+const LINEAR: FixedConfig = FixedConfig {
+    input_min: 0,
+    input_max: 4095,
+    output_min: 0,
+    output_max: 1000,
+    curve: FixedCurve::Linear,
+    change_threshold: 0,
+    snap_zones: &[],
+};
+
+#[test]
+fn test_linear_mapping() {
+    let mut pot = FixedPotHead::new(&LINEAR).unwrap();
+
+    assert_eq!(pot.update(0), 0);
+    assert_eq!(pot.update(4095), 1000);
+    assert_eq!(pot.update(2048), 500);
+    assert_eq!(pot.last_output(), 500);
+
+    // Out-of-range input clamps
+    assert_eq!(pot.update(-50), 0);
+    assert_eq!(pot.update(5000), 1000);
+    assert_eq!(pot.last_output_normalized(), ONE);
+}
+
+#[test]
+fn test_descending_ranges() {
+    let config = FixedConfig {
+        input_min: 1000,
+        input_max: 0,
+        output_min: 100,
+        output_max: 0,
+        ..LINEAR
+    };
+    let mut pot = FixedPotHead::new(&config).unwrap();
+
+    // Both reversals cancel out
+    assert_eq!(pot.update(0), 0);
+    assert_eq!(pot.update(250), 25);
+    assert_eq!(pot.update(1000), 100);
+}
+
+#[test]
+fn test_lut_curve_interpolates() {
+    static TABLE: [i32; 3] = [0, ONE / 4, ONE];
+    let curve = FixedCurve::Lut(&TABLE);
+
+    assert_eq!(curve.apply(0), 0);
+    assert_eq!(curve.apply(ONE / 4), ONE / 8);
+    assert_eq!(curve.apply(ONE / 2), ONE / 4);
+    assert_eq!(curve.apply(3 * ONE / 4), 5 * ONE / 8);
+    assert_eq!(curve.apply(ONE), ONE);
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_log_lut_tracks_float_curve() {
+    use pot_head::fixed::LOG_LUT;
+    use pot_head::{Float, ResponseCurve};
+
+    for step in 0..=100 {
+        let q = ratio(step, 100);
+        let fixed = FixedCurve::Lut(&LOG_LUT).apply(q) as Float / ONE as Float;
+        let float = ResponseCurve::Logarithmic.apply(step as Float / 100.0);
+        assert!(
+            (fixed - float).abs() < 0.005,
+            "step {}: fixed {} vs float {}",
+            step,
+            fixed,
+            float
+        );
+    }
+}
+
+#[test]
+fn test_change_threshold() {
+    let config = FixedConfig {
+        change_threshold: ratio(5, 100),
+        ..LINEAR
+    };
+    let mut pot = FixedPotHead::new(&config).unwrap();
+
+    let first = pot.update(2048);
+    assert_eq!(first, 500);
+
+    // 2% move is held
+    assert_eq!(pot.update(2130), first);
+
+    // 10% move passes
+    assert_eq!(pot.update(2457), 600);
+}
+
+#[test]
+fn test_snap_and_dead_zones() {
+    static ZONES: [SnapZone<i32>; 2] = [
+        SnapZone::new(ONE / 2, ratio(5, 100), SnapZoneType::Snap),
+        SnapZone::new(ONE, ratio(5, 100), SnapZoneType::Dead),
+    ];
+    let config = FixedConfig {
+        snap_zones: &ZONES,
+        ..LINEAR
+    };
+    let mut pot = FixedPotHead::new(&config).unwrap();
+
+    assert_eq!(pot.update(2150), 500);
+    assert_eq!(pot.update(3800), 928);
+
+    // Dead zone holds the last output
+    assert_eq!(pot.update(4000), 928);
+    assert_eq!(pot.update(4095), 928);
+}
+
+#[test]
+fn test_validation() {
+    let config = FixedConfig {
+        input_max: 0,
+        ..LINEAR
+    };
+    assert!(matches!(
+        FixedPotHead::new(&config),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+
+    let config = FixedConfig {
+        output_max: 0,
+        ..LINEAR
+    };
+    assert!(matches!(
+        FixedPotHead::new(&config),
+        Err(ConfigError::InvalidOutputRange { .. })
+    ));
+
+    static SHORT: [i32; 1] = [0];
+    let config = FixedConfig {
+        curve: FixedCurve::Lut(&SHORT),
+        ..LINEAR
+    };
+    assert!(matches!(
+        FixedPotHead::new(&config),
+        Err(ConfigError::InvalidCurve { .. })
+    ));
+
+    let config = FixedConfig {
+        change_threshold: -1,
+        ..LINEAR
+    };
+    assert!(matches!(
+        FixedPotHead::new(&config),
+        Err(ConfigError::InvalidHysteresis { .. })
+    ));
+
+    static BAD_ZONES: [SnapZone<i32>; 1] = [SnapZone::new(ONE / 2, -1, SnapZoneType::Snap)];
+    let config = FixedConfig {
+        snap_zones: &BAD_ZONES,
+        ..LINEAR
+    };
+    assert!(matches!(
+        FixedPotHead::new(&config),
+        Err(ConfigError::InvalidSnapZone { index: 0, .. })
+    ));
+}
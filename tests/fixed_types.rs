@@ -0,0 +1,47 @@
+#![cfg(feature = "fixed")]
+
+use fixed::types::{I8F8, I16F16, U0F16, U8F8};
+use pot_head::{Config, FromNormalized, IntoNormalized, PotHead};
+
+#[test]
+fn test_conversions() {
+    assert_eq!(I16F16::from_num(1.5).into_normalized(), 1.5);
+    assert_eq!(I16F16::from_normalized(-2.25), I16F16::from_num(-2.25));
+
+    // Saturates at the type's range
+    assert_eq!(U0F16::from_normalized(1.5), U0F16::MAX);
+    assert_eq!(U8F8::from_normalized(-1.0), U8F8::ZERO);
+    assert_eq!(I8F8::from_normalized(1000.0), I8F8::MAX);
+
+    // NaN becomes zero instead of panicking
+    assert_eq!(I16F16::from_normalized(pot_head::Float::NAN), I16F16::ZERO);
+}
+
+#[test]
+fn test_fixed_input_and_output() {
+    let config = Config::builder()
+        .input_range(I16F16::ZERO, I16F16::from_num(3.3))
+        .output_range(U0F16::ZERO, U0F16::MAX)
+        .build()
+        .unwrap();
+    let mut pot: PotHead<I16F16, U0F16> = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(I16F16::ZERO), U0F16::ZERO);
+    assert_eq!(pot.update(I16F16::from_num(3.3)), U0F16::MAX);
+
+    let half = pot.update(I16F16::from_num(1.65));
+    assert!((half.to_num::<f32>() - 0.5).abs() < 1e-3, "{}", half);
+}
+
+#[test]
+fn test_mixed_with_primitives() {
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(I16F16::from_num(-1), I16F16::from_num(1))
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(0), I16F16::from_num(-1));
+    assert_eq!(pot.update(4095), I16F16::from_num(1));
+}
@@ -0,0 +1,36 @@
+use pot_head::{Config, PotHead};
+
+// Both are usable in const contexts for compile-time budgeting
+const STATE: usize = Config::<u16, u8>::state_size_bytes();
+const TOTAL: usize = PotHead::<u16, u8>::SIZE_BYTES;
+
+const _: () = assert!(STATE > 0);
+const _: () = assert!(TOTAL >= STATE + size_of::<Config<u16, u8>>());
+
+#[cfg(feature = "moving-average")]
+const _: () = assert!(STATE >= 32 * size_of::<pot_head::Float>());
+
+// Default features: 16 pots fit in 4 KB. Features adding per-pot state
+// (see the cost notes in Cargo.toml) are budgeted by the application
+#[cfg(not(any(
+    feature = "moving-average",
+    feature = "taps",
+    feature = "fine-adjust",
+    feature = "observed-range",
+    feature = "auto-range",
+    feature = "calibration",
+    feature = "fault-detection",
+    feature = "noise-report",
+    feature = "usage-stats",
+    feature = "ratiometric",
+    feature = "self-test",
+    feature = "defmt-trace",
+    feature = "embassy-time",
+    feature = "f64"
+)))]
+const _: () = assert!(16 * TOTAL <= 4096);
+
+#[test]
+fn test_state_independent_of_io_types() {
+    assert_eq!(STATE, Config::<f32, u32>::state_size_bytes());
+}
@@ -1,18 +1,31 @@
 #![cfg(feature = "grab-mode")]
 
-use pot_head::{Config, GrabMode, HysteresisMode, NoiseFilter, PotHead, ResponseCurve};
+use pot_head::{
+    Config, ConfigError, Float, GrabMode, HysteresisMode, NoiseFilter, PotHead, ReleasePolicy,
+    ResponseCurve, SnapZone, SnapZoneType, StageOrder,
+};
 
-fn create_test_config(grab_mode: GrabMode) -> Config<u16, f32> {
+fn create_test_config(grab_mode: GrabMode) -> Config<'static, u16, f32> {
     Config {
         input_min: 0,
         input_max: 1000,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0,
         output_max: 1.0,
         hysteresis: HysteresisMode::None(core::marker::PhantomData),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &[],
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         grab_mode,
+        grab_margin: 0.0,
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     }
 }
 
@@ -47,12 +60,12 @@ fn test_pickup_mode_basic() {
     assert_eq!(pot.update(500), 0.7); // Still locked
 
     // Cross virtual value (70% = 700)
-    assert_eq!(pot.update(700), 0.7); // Grabbed!
+    assert_close(pot.update(700), 0.7); // Grabbed!
     assert!(!pot.is_waiting_for_grab());
 
     // Now follows pot position
     assert_eq!(pot.update(800), 0.8);
-    assert_eq!(pot.update(900), 0.9);
+    assert_close(pot.update(900), 0.9);
 }
 
 #[test]
@@ -87,7 +100,7 @@ fn test_passthrough_mode_from_below() {
     assert_eq!(pot.update(500), 0.7); // Still locked
 
     // Cross virtual value from below
-    assert_eq!(pot.update(700), 0.7); // Grabbed!
+    assert_close(pot.update(700), 0.7); // Grabbed!
     assert!(!pot.is_waiting_for_grab());
 
     // Now follows
@@ -160,8 +173,8 @@ fn test_physical_position_tracking() {
 
     // Grab at 70%
     pot.update(700);
-    assert_eq!(pot.current_output(), 0.7); // Now matches
-    assert_eq!(pot.physical_position(), 0.7);
+    assert_close(pot.current_output(), 0.7); // Now matches
+    assert_close(pot.physical_position(), 0.7);
 }
 
 #[test]
@@ -192,7 +205,7 @@ fn test_pickup_mode_no_overshoot_required() {
     pot.set_virtual_value(0.7);
 
     // Move exactly to virtual value
-    assert_eq!(pot.update(700), 0.7); // Should grab at exact value
+    assert_close(pot.update(700), 0.7); // Should grab at exact value
     assert!(!pot.is_waiting_for_grab());
 }
 
@@ -225,8 +238,8 @@ fn test_grab_mode_helpers() {
     // Grab
     pot.update(700);
     assert!(!pot.is_waiting_for_grab());
-    assert_eq!(pot.current_output(), 0.7);
-    assert_eq!(pot.physical_position(), 0.7);
+    assert_close(pot.current_output(), 0.7);
+    assert_close(pot.physical_position(), 0.7);
 }
 
 #[test]
@@ -273,19 +286,220 @@ fn test_release_for_mode_switching() {
 
     // Move to 70%
     volume_pot.update(700);
-    assert_eq!(volume_pot.current_output(), 0.7);
+    assert_close(volume_pot.current_output(), 0.7);
 
     // Switch to backlight mode - release volume
     volume_pot.release();
     assert!(volume_pot.is_waiting_for_grab());
-    assert_eq!(volume_pot.current_output(), 0.7); // Virtual stays at last physical
+    assert_close(volume_pot.current_output(), 0.7); // Virtual stays at last physical
 
     // Backlight pot starts fresh - for mode switching, you'd typically
     // want to initialize it to current pot position to avoid jumps
     // Option 1: Let it grab naturally (might cause jump)
     backlight_pot.update(700); // Pot at 70%, backlight grabs
-    assert_eq!(backlight_pot.current_output(), 0.7);
+    assert_close(backlight_pot.current_output(), 0.7);
 
     // Now backlight follows the pot
     assert_eq!(backlight_pot.update(600), 0.6);
 }
+
+fn create_margin_config(grab_mode: GrabMode, grab_margin: Float) -> Config<'static, u16, f32> {
+    Config {
+        grab_margin,
+        ..create_test_config(grab_mode)
+    }
+}
+
+#[test]
+fn test_passthrough_margin_ignores_jitter() {
+    let config = create_margin_config(GrabMode::PassThrough, 0.05);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.set_virtual_value(0.5);
+
+    // Pot resting just below virtual value with noise
+    assert_eq!(pot.update(490), 0.5);
+    assert_eq!(pot.update(510), 0.5); // Crosses virtual value but not the margin
+    assert_eq!(pot.update(495), 0.5);
+    assert_eq!(pot.update(530), 0.5);
+    assert!(pot.is_waiting_for_grab());
+
+    // Deliberate movement past the margin grabs
+    assert_eq!(pot.update(550), 0.55);
+    assert!(!pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_passthrough_margin_slow_crossing() {
+    let config = create_margin_config(GrabMode::PassThrough, 0.05);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.set_virtual_value(0.5);
+
+    // Approach from above in small steps through the margin band
+    assert_eq!(pot.update(700), 0.5);
+    assert_eq!(pot.update(540), 0.5);
+    assert_eq!(pot.update(500), 0.5);
+    assert_eq!(pot.update(460), 0.5);
+    assert!(pot.is_waiting_for_grab());
+
+    assert_close(pot.update(440), 0.44);
+    assert!(!pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_pickup_margin() {
+    let config = create_margin_config(GrabMode::Pickup, 0.05);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.set_virtual_value(0.5);
+
+    assert_eq!(pot.update(500), 0.5); // Reaching virtual value is not enough
+    assert_eq!(pot.update(540), 0.5);
+    assert!(pot.is_waiting_for_grab());
+
+    assert_eq!(pot.update(550), 0.55);
+    assert!(!pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_pickup_margin_near_top() {
+    let config = create_margin_config(GrabMode::Pickup, 0.05);
+    let mut pot = PotHead::new(config).unwrap();
+
+    // Virtual value plus margin is past the end: the end grabs
+    pot.set_virtual_value(0.98);
+    assert_eq!(pot.update(960), 0.98);
+    assert_eq!(pot.update(990), 0.98);
+    assert!(pot.is_waiting_for_grab());
+
+    assert_eq!(pot.update(1000), 1.0);
+    assert!(!pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_passthrough_margin_near_top() {
+    let config = create_margin_config(GrabMode::PassThrough, 0.05);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.set_virtual_value(0.98);
+    assert_eq!(pot.update(900), 0.98);
+    assert_eq!(pot.update(990), 0.98);
+    assert!(pot.is_waiting_for_grab());
+
+    assert_eq!(pot.update(1000), 1.0);
+    assert!(!pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_passthrough_margin_near_bottom() {
+    let config = create_margin_config(GrabMode::PassThrough, 0.05);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.set_virtual_value(0.02);
+    assert_eq!(pot.update(100), 0.02);
+    assert_eq!(pot.update(10), 0.02);
+    assert!(pot.is_waiting_for_grab());
+
+    assert_eq!(pot.update(0), 0.0);
+    assert!(!pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_invalid_grab_margin() {
+    let result = PotHead::new(create_margin_config(GrabMode::PassThrough, -0.1));
+    assert!(matches!(result, Err(ConfigError::InvalidGrabMargin { .. })));
+
+    let result = PotHead::new(create_margin_config(GrabMode::PassThrough, 1.0));
+    assert!(matches!(result, Err(ConfigError::InvalidGrabMargin { .. })));
+}
+
+fn create_release_config(
+    grab_mode: GrabMode,
+    release_policy: ReleasePolicy,
+) -> Config<'static, u16, f32> {
+    Config {
+        release_policy,
+        ..create_test_config(grab_mode)
+    }
+}
+
+#[test]
+fn test_release_require_movement_passthrough() {
+    let config = create_release_config(GrabMode::PassThrough, ReleasePolicy::RequireMovement);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(400);
+    pot.release();
+
+    // Staying at the released position does not re-grab
+    assert_eq!(pot.update(400), 0.4);
+    assert_eq!(pot.update(400), 0.4);
+    assert!(pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_release_grab_in_place_passthrough() {
+    let config = create_release_config(GrabMode::PassThrough, ReleasePolicy::GrabInPlace);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(400);
+    pot.release();
+    assert!(pot.is_waiting_for_grab());
+
+    // Next update at the same position re-grabs
+    assert_eq!(pot.update(400), 0.4);
+    assert!(!pot.is_waiting_for_grab());
+    assert_close(pot.update(450), 0.45);
+}
+
+#[test]
+fn test_release_grab_in_place_after_movement() {
+    let config = create_release_config(GrabMode::PassThrough, ReleasePolicy::GrabInPlace);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(400);
+    pot.release();
+
+    // Pot moved while released (e.g. controlling another parameter) - no jump
+    assert_eq!(pot.update(800), 0.4);
+    assert!(pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_release_grab_in_place_inside_snap_zone() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.1, SnapZoneType::Snap)];
+    let config = Config {
+        snap_zones: &ZONES,
+        ..create_release_config(GrabMode::PassThrough, ReleasePolicy::GrabInPlace)
+    };
+    let mut pot = PotHead::new(config).unwrap();
+
+    // The zone snaps to 0.5 while the pot sits at 0.45
+    pot.update(450);
+    pot.release();
+
+    // Unmoved pot re-grabs: the zone doesn't move the catch point
+    assert_eq!(pot.update(450), 0.5);
+    assert!(!pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_set_virtual_value_clears_grab_in_place() {
+    let config = create_release_config(GrabMode::PassThrough, ReleasePolicy::GrabInPlace);
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(400);
+    pot.release();
+    pot.set_virtual_value(0.7);
+
+    assert_eq!(pot.update(700), 0.7);
+    assert!(pot.is_waiting_for_grab());
+}
+
+fn assert_close<T: Into<f64> + core::fmt::Display + Copy>(actual: T, expected: T) {
+    assert!(
+        (actual.into() - expected.into()).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
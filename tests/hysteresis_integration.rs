@@ -1,23 +1,37 @@
-use pot_head::{Config, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone};
+use pot_head::{
+    Config, Float, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone, StageOrder,
+};
 
 #[cfg(feature = "grab-mode")]
-use pot_head::GrabMode;
+use pot_head::{GrabMode, ReleasePolicy};
 
-static EMPTY_SNAP_ZONES: [SnapZone<f32>; 0] = [];
+static EMPTY_SNAP_ZONES: [SnapZone<Float>; 0] = [];
 
 #[test]
 fn test_pothead_with_no_hysteresis() {
     let config = Config {
         input_min: 0_u16,
         input_max: 4095_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).expect("Valid config");
@@ -46,14 +60,26 @@ fn test_pothead_with_change_threshold() {
     let config = Config {
         input_min: 0_u16,
         input_max: 4095_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::ChangeThreshold { threshold: 0.05 }, // 5% threshold in normalized space
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).expect("Valid config");
@@ -88,17 +114,29 @@ fn test_pothead_with_schmitt_trigger() {
     let config = Config {
         input_min: 0_u16,
         input_max: 4095_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         hysteresis: HysteresisMode::SchmittTrigger {
             rising: 0.6,
             falling: 0.4,
         },
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).expect("Valid config");
@@ -130,14 +168,26 @@ fn test_hysteresis_with_different_types() {
     let config = Config {
         input_min: 0_u8,
         input_max: 255_u8,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: -100_i16,
         output_max: 100_i16,
         hysteresis: HysteresisMode::ChangeThreshold { threshold: 0.1 }, // 10% threshold
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).expect("Valid config");
@@ -161,17 +211,29 @@ fn test_invalid_schmitt_config() {
     let config = Config {
         input_min: 0_u16,
         input_max: 4095_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         hysteresis: HysteresisMode::SchmittTrigger {
             rising: 0.4, // Invalid: rising <= falling
             falling: 0.6,
         },
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     // Should fail validation
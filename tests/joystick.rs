@@ -0,0 +1,121 @@
+use pot_head::{Config, ConfigError, Float, JoystickDeadZone, JoystickHead};
+
+fn axis() -> Config<'static, u16, Float> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(-1.0, 1.0)
+        .build()
+        .unwrap()
+}
+
+fn stick(dead_zone: JoystickDeadZone) -> JoystickHead<'static, u16, Float> {
+    JoystickHead::new(axis(), axis(), dead_zone).unwrap()
+}
+
+fn close(a: Float, b: Float) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+#[test]
+fn test_axes_centered_on_input_range() {
+    let mut stick = stick(JoystickDeadZone::None);
+
+    assert_eq!(stick.last_output(), (0.0, 0.0));
+
+    let (x, y) = stick.update(500, 750);
+    assert!(close(x, 0.0) && close(y, 0.5));
+
+    assert_eq!(stick.update(0, 1000), (-1.0, 1.0));
+    assert_eq!(stick.centered(), (-1.0, 1.0));
+}
+
+#[test]
+fn test_output_range_per_axis() {
+    let y_axis = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 255.0)
+        .build()
+        .unwrap();
+    let mut stick = JoystickHead::new(axis(), y_axis, JoystickDeadZone::None).unwrap();
+
+    let (x, y) = stick.update(500, 500);
+    assert!(close(x, 0.0) && close(y, 127.5));
+}
+
+#[test]
+fn test_cross_dead_zone() {
+    let mut stick = stick(JoystickDeadZone::Cross { width: 0.2 });
+
+    // Small deflections on either axis are ignored independently
+    let (x, y) = stick.update(550, 900);
+    assert!(close(x, 0.0), "{}", x);
+    assert!(close(y, 0.75), "{}", y);
+
+    // Full deflection still reaches the end
+    assert_eq!(stick.update(0, 1000), (-1.0, 1.0));
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_radial_dead_zone() {
+    let mut stick = stick(JoystickDeadZone::Radial { radius: 0.2 });
+
+    // 0.1 on both axes is inside the circle
+    assert_eq!(stick.update(550, 550), (0.0, 0.0));
+
+    // Straight up: rescaled like an axis
+    let (x, y) = stick.update(500, 800);
+    assert!(close(x, 0.0) && close(y, 0.5));
+
+    // Corner: limited to the unit circle
+    stick.update(1000, 1000);
+    assert!(close(stick.magnitude(), 1.0));
+    assert!(close(stick.angle(), core::f64::consts::FRAC_PI_4 as Float));
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_symmetric_curve() {
+    use pot_head::ResponseCurve;
+
+    let x_axis = Config {
+        curve: ResponseCurve::Logarithmic,
+        ..axis()
+    };
+    let mut stick = JoystickHead::new(x_axis, axis(), JoystickDeadZone::None).unwrap();
+
+    let (right, _) = stick.update(750, 500);
+    let (left, _) = stick.update(250, 500);
+    assert!(close(right, -left));
+    assert!(close(right, ResponseCurve::Logarithmic.apply(0.5)));
+}
+
+#[test]
+fn test_filter_and_reset() {
+    use pot_head::NoiseFilter;
+
+    let x_axis = Config {
+        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+        ..axis()
+    };
+    let mut stick = JoystickHead::new(x_axis, axis(), JoystickDeadZone::None).unwrap();
+
+    stick.update(500, 500);
+    let (x, _) = stick.update(1000, 500);
+    assert!(close(x, 0.5));
+
+    stick.reset();
+    assert_eq!(stick.centered(), (0.0, 0.0));
+    let (x, _) = stick.update(1000, 500);
+    assert!(close(x, 1.0));
+}
+
+#[test]
+fn test_invalid_dead_zone() {
+    for width in [-0.1, 1.0, Float::NAN] {
+        assert!(matches!(
+            JoystickHead::new(axis(), axis(), JoystickDeadZone::Cross { width }),
+            Err(ConfigError::InvalidDeadZone { .. })
+        ));
+    }
+}
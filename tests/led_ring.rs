@@ -0,0 +1,64 @@
+use pot_head::{Float, LedMode};
+
+fn close<const N: usize>(actual: [Float; N], expected: [Float; N]) -> bool {
+    actual
+        .iter()
+        .zip(&expected)
+        .all(|(a, b)| (a - b).abs() < 1e-4)
+}
+
+#[test]
+fn test_bar() {
+    assert_eq!(LedMode::Bar.render::<4>(0.0), [0.0; 4]);
+    assert_eq!(LedMode::Bar.render::<4>(0.5), [1.0, 1.0, 0.0, 0.0]);
+    assert!(close(LedMode::Bar.render::<4>(0.6), [1.0, 1.0, 0.4, 0.0]));
+    assert_eq!(LedMode::Bar.render::<4>(1.0), [1.0; 4]);
+}
+
+#[test]
+fn test_dot() {
+    assert_eq!(LedMode::Dot.render::<5>(0.0), [1.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(LedMode::Dot.render::<5>(0.5), [0.0, 0.0, 1.0, 0.0, 0.0]);
+    assert!(close(
+        LedMode::Dot.render::<5>(0.5625),
+        [0.0, 0.0, 0.75, 0.25, 0.0]
+    ));
+    assert_eq!(LedMode::Dot.render::<5>(1.0), [0.0, 0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_from_center() {
+    assert_eq!(LedMode::FromCenter.render::<4>(0.5), [0.0; 4]);
+    assert_eq!(LedMode::FromCenter.render::<4>(1.0), [0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(LedMode::FromCenter.render::<4>(0.0), [1.0, 1.0, 0.0, 0.0]);
+    assert!(close(
+        LedMode::FromCenter.render::<4>(0.375),
+        [0.0, 0.5, 0.0, 0.0]
+    ));
+
+    // Odd count: each side owns half of the center LED
+    assert!(close(
+        LedMode::FromCenter.render::<5>(0.6),
+        [0.0, 0.0, 0.5, 0.0, 0.0]
+    ));
+}
+
+#[test]
+fn test_out_of_range_and_nan() {
+    assert_eq!(LedMode::Bar.render::<3>(2.0), [1.0; 3]);
+    assert_eq!(LedMode::Bar.render::<3>(-1.0), [0.0; 3]);
+    assert_eq!(LedMode::Dot.render::<3>(Float::NAN), [0.0; 3]);
+}
+
+#[test]
+fn test_render_into() {
+    let mut leds = [0.5; 8];
+    LedMode::Bar.render_into(0.25, &mut leds);
+    assert_eq!(leds, [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+    // Degenerate sizes don't panic
+    LedMode::Dot.render_into(0.5, &mut []);
+    let mut one = [0.0];
+    LedMode::Dot.render_into(0.7, &mut one);
+    assert_eq!(one, [1.0]);
+}
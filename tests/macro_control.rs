@@ -0,0 +1,80 @@
+mod common;
+
+use common::assert_close;
+use pot_head::{Config, ConfigError, Float, MacroControl, PotHead};
+
+fn configs() -> [Config<'static, u16, Float>; 3] {
+    let cutoff = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 127.0)
+        .build()
+        .unwrap();
+    let resonance = Config::builder()
+        .input_range(0, 1000)
+        .output_range(20.0, 90.0)
+        .invert_input(true)
+        .build()
+        .unwrap();
+    let drive = Config::builder()
+        .input_range(500, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    [cutoff, resonance, drive]
+}
+
+#[test]
+fn test_fan_out() {
+    let mut macro_knob = MacroControl::from_configs(configs()).unwrap();
+    assert_eq!(macro_knob.len(), 3);
+
+    assert_eq!(macro_knob.update(0), [0.0, 90.0, 0.0]);
+    assert_eq!(macro_knob.update(500), [63.5, 55.0, 0.0]);
+    let outputs = macro_knob.update(750);
+    for (output, expected) in outputs.into_iter().zip([95.25, 37.5, 0.5]) {
+        assert_close(output, expected);
+    }
+    assert_eq!(macro_knob.update(1000), [127.0, 20.0, 1.0]);
+    assert_eq!(macro_knob.outputs(), [127.0, 20.0, 1.0]);
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_per_target_curve() {
+    let mut macro_knob = MacroControl::from_configs(configs()).unwrap();
+    macro_knob
+        .get_mut(0)
+        .unwrap()
+        .set_curve(pot_head::ResponseCurve::Logarithmic);
+
+    let [cutoff, _, _] = macro_knob.update(500);
+    assert!(cutoff < 63.5, "{}", cutoff);
+}
+
+#[test]
+fn test_invalid_config() {
+    let mut configs = configs();
+    configs[1].output_min = configs[1].output_max;
+
+    assert!(matches!(
+        MacroControl::from_configs(configs),
+        Err(ConfigError::InvalidOutputRange { .. })
+    ));
+}
+
+#[test]
+fn test_new_and_into_inner() {
+    let targets = configs().map(|config| PotHead::new(config).unwrap());
+    let mut macro_knob = MacroControl::new(targets);
+
+    macro_knob.update(1000);
+    macro_knob.reset_all();
+    assert!(
+        macro_knob
+            .iter()
+            .all(|target| target.updates_since_change() == 0)
+    );
+
+    let targets = macro_knob.into_inner();
+    assert_eq!(targets[2].config().input_min, 500);
+}
@@ -0,0 +1,87 @@
+use pot_head::{Cc14, Config, ConfigError, Nrpn, PotHead, split14};
+
+#[test]
+fn test_split14() {
+    assert_eq!(split14(0), (0, 0));
+    assert_eq!(split14(0x3FFF), (0x7F, 0x7F));
+    assert_eq!(split14(8192), (64, 0));
+    assert_eq!(split14(0x1234), (0x24, 0x34));
+    assert_eq!(split14(u16::MAX), (0x7F, 0x7F));
+}
+
+#[test]
+fn test_cc14_sends_only_changes() {
+    let mut cc = Cc14::new(2, 7).unwrap();
+
+    // First value: both halves
+    assert_eq!(cc.encode(8193).as_slice(), &[[0xB2, 7, 64], [0xB2, 39, 1]]);
+
+    // Same value: nothing
+    assert!(cc.encode(8193).is_empty());
+
+    // LSB only
+    assert_eq!(cc.encode(8200).as_slice(), &[[0xB2, 39, 8]]);
+
+    // New MSB: LSB follows even though it is unchanged
+    assert_eq!(cc.encode(8328).as_slice(), &[[0xB2, 7, 65], [0xB2, 39, 8]]);
+}
+
+#[test]
+fn test_cc14_resend() {
+    let mut cc = Cc14::new(0, 1).unwrap();
+    cc.encode(100);
+    cc.resend();
+    assert_eq!(cc.encode(100).len(), 2);
+}
+
+#[test]
+fn test_nrpn() {
+    let mut nrpn = Nrpn::new(0, 0x0123).unwrap();
+
+    assert_eq!(
+        nrpn.encode(0x1234).as_slice(),
+        &[
+            [0xB0, 99, 0x02],
+            [0xB0, 98, 0x23],
+            [0xB0, 6, 0x24],
+            [0xB0, 38, 0x34]
+        ]
+    );
+    assert_eq!(
+        nrpn.encode(0x1235).as_slice(),
+        &[[0xB0, 99, 0x02], [0xB0, 98, 0x23], [0xB0, 38, 0x35]]
+    );
+    assert!(nrpn.encode(0x1235).is_empty());
+}
+
+#[test]
+fn test_invalid() {
+    assert!(matches!(
+        Cc14::new(16, 0),
+        Err(ConfigError::InvalidMidi { .. })
+    ));
+    assert!(matches!(
+        Cc14::new(0, 32),
+        Err(ConfigError::InvalidMidi { .. })
+    ));
+    assert!(matches!(
+        Nrpn::new(0, 0x4000),
+        Err(ConfigError::InvalidMidi { .. })
+    ));
+}
+
+#[test]
+fn test_with_pot() {
+    let config = Config::builder()
+        .input_adc_bits(12)
+        .output_midi14()
+        .build()
+        .unwrap();
+    let mut fader: PotHead<u16, u16> = PotHead::new(config).unwrap();
+    let mut cc = Cc14::new(0, 7).unwrap();
+
+    let value = fader.update(4095);
+    assert_eq!(value, 16383);
+    let sent: Vec<[u8; 3]> = (&cc.encode(value)).into_iter().copied().collect();
+    assert_eq!(sent, [[0xB0, 7, 127], [0xB0, 39, 127]]);
+}
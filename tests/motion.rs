@@ -0,0 +1,100 @@
+mod common;
+
+use common::pot;
+use pot_head::{Config, Direction, NoiseFilter, PotHead};
+
+#[test]
+fn test_no_velocity_on_first_update() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(800);
+    assert_eq!(pot.velocity(), 0.0);
+    assert_eq!(pot.direction(), Direction::Stationary);
+}
+
+#[test]
+fn test_velocity_per_update() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(200);
+
+    pot.update(300);
+    assert!((pot.velocity() - 0.1).abs() < 1e-6);
+    assert_eq!(pot.direction(), Direction::Increasing);
+
+    pot.update(100);
+    assert!((pot.velocity() + 0.2).abs() < 1e-6);
+    assert_eq!(pot.direction(), Direction::Decreasing);
+
+    pot.update(100);
+    assert_eq!(pot.velocity(), 0.0);
+    assert_eq!(pot.direction(), Direction::Stationary);
+}
+
+#[test]
+fn test_velocity_per_second() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update_timed(200, 0.01);
+    pot.update_timed(300, 0.01);
+    assert!((pot.velocity() - 10.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_velocity_uses_filtered_position() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.5 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(0);
+    pot.update(1000);
+    assert_eq!(pot.velocity(), 0.5);
+}
+
+#[test]
+fn test_reset_clears_velocity() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(0);
+    pot.update(500);
+
+    pot.reset();
+    assert_eq!(pot.velocity(), 0.0);
+    pot.update(900);
+    assert_eq!(pot.velocity(), 0.0);
+}
+
+#[test]
+fn test_updates_since_change() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(500);
+    assert_eq!(pot.updates_since_change(), 0);
+
+    pot.update(500);
+    pot.update(500);
+    assert_eq!(pot.updates_since_change(), 2);
+    assert!(pot.is_idle(2));
+    assert!(!pot.is_idle(3));
+
+    pot.update(600);
+    assert_eq!(pot.updates_since_change(), 0);
+    assert!(!pot.is_idle(1));
+}
+
+#[test]
+fn test_idle_ignores_movement_absorbed_by_hysteresis() {
+    use pot_head::HysteresisMode;
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.05 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(500);
+    pot.update(510);
+    pot.update(490);
+    assert!(pot.is_idle(2));
+}
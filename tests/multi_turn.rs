@@ -0,0 +1,79 @@
+use pot_head::{Config, ConfigError, Float, MultiTurnPotHead};
+
+/// 12-bit single-turn sensor over a 10-turn knob, output 0..=1000.
+fn ten_turn() -> MultiTurnPotHead<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(0.0, 10.0)
+        .output_range(0.0, 1000.0)
+        .build()
+        .unwrap();
+    MultiTurnPotHead::new((0, 4096), config).unwrap()
+}
+
+fn close(a: Float, b: Float) -> bool {
+    (a - b).abs() < 0.1
+}
+
+#[test]
+fn test_counts_turns_on_wrap() {
+    let mut pot = ten_turn();
+
+    assert!(close(pot.update(2048), 50.0));
+
+    // Turn forward through the wrap twice
+    for angle in [3072, 4000, 100, 2048, 4000, 50, 1024] {
+        pot.update(angle);
+    }
+    assert_eq!(pot.turns(), 2);
+    assert!(close(pot.last_output(), 225.0));
+    assert!(close(pot.position(), 2.25));
+
+    // And back down
+    for angle in [100, 4000, 2048] {
+        pot.update(angle);
+    }
+    assert_eq!(pot.turns(), 1);
+    assert!(close(pot.last_output(), 150.0));
+}
+
+#[test]
+fn test_explicit_turn() {
+    let mut pot = ten_turn();
+
+    assert!(close(pot.update_with_turn(1024, 7), 725.0));
+
+    // Wrap detection continues from the given turn
+    pot.update(200);
+    pot.update(3500);
+    assert_eq!(pot.turns(), 6);
+}
+
+#[test]
+fn test_restored_turns() {
+    let mut pot = ten_turn();
+    pot.set_turns(4);
+    assert!(close(pot.update(0), 400.0));
+}
+
+#[test]
+fn test_pipeline_clamps_extended_range() {
+    let mut pot = ten_turn();
+    pot.set_turns(-1);
+    assert_eq!(pot.update(2048), 0.0);
+
+    pot.set_turns(11);
+    assert_eq!(pot.update(2048), 1000.0);
+}
+
+#[test]
+fn test_invalid_angle_range() {
+    let config = Config::builder()
+        .input_range(0.0 as Float, 10.0)
+        .output_range(0.0 as Float, 1.0)
+        .build()
+        .unwrap();
+    assert!(matches!(
+        MultiTurnPotHead::new((100_u16, 100), config),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+}
@@ -0,0 +1,65 @@
+use pot_head::{Config, MuxScanner, PotBank};
+
+fn scanner(settle_samples: u16) -> MuxScanner<'static, 3, u16, f32> {
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    MuxScanner::new(PotBank::from_config(config).unwrap(), settle_samples)
+}
+
+#[test]
+fn test_sample_sequences_channels() {
+    let mut scanner = scanner(0);
+
+    assert_eq!(scanner.channel(), 0);
+    assert_eq!(scanner.sample(100), Some((0, 0.1)));
+    assert_eq!(scanner.channel(), 1);
+    assert_eq!(scanner.sample(200), Some((1, 0.2)));
+    assert_eq!(scanner.sample(300), Some((2, 0.3)));
+
+    // Wraps back to the first channel
+    assert_eq!(scanner.channel(), 0);
+    assert_eq!(scanner.bank().outputs(), [0.1, 0.2, 0.3]);
+}
+
+#[test]
+fn test_settling_samples_discarded() {
+    let mut scanner = scanner(2);
+
+    // Two samples after the switch are still showing the previous channel
+    assert_eq!(scanner.sample(999), None);
+    assert_eq!(scanner.sample(999), None);
+    assert_eq!(scanner.sample(500), Some((0, 0.5)));
+
+    assert_eq!(scanner.channel(), 1);
+    assert_eq!(scanner.sample(999), None);
+    assert_eq!(scanner.sample(999), None);
+    assert_eq!(scanner.sample(250), Some((1, 0.25)));
+    assert_eq!(scanner.bank().outputs(), [0.5, 0.25, 0.0]);
+}
+
+#[test]
+fn test_scan_with_callbacks() {
+    let mut scanner = scanner(1);
+    let levels = [1000_u16, 0, 500];
+    let selected = core::cell::Cell::new(0);
+    let mut settles = 0;
+    let mut reads = 0;
+
+    let outputs = scanner.scan(
+        |channel| selected.set(channel),
+        || settles += 1,
+        || {
+            reads += 1;
+            levels[selected.get()]
+        },
+    );
+
+    assert_eq!(outputs, [1.0, 0.0, 0.5]);
+    assert_eq!(settles, 3);
+    assert_eq!(reads, 6);
+    assert_eq!(scanner.bank().changed_mask(), 0b101);
+    assert_eq!(scanner.channel(), 0);
+}
@@ -0,0 +1,137 @@
+//! Link-time proof that the hot path cannot panic.
+//!
+//! Each call is wrapped in a guard whose `Drop` references an undefined symbol.
+//! The guard is only dropped while unwinding, so the optimizer removes it unless
+//! the call can panic, in which case linking fails with the message below.
+//!
+//! Needs LTO so the optimizer can see into non-generic functions (filters, libm):
+//!
+//! `CARGO_PROFILE_RELEASE_LTO=fat cargo test --release --features panic-check --test no_panic`
+
+#![cfg(all(feature = "panic-check", not(debug_assertions)))]
+
+use core::hint::black_box;
+
+use pot_head::{Config, Float, HysteresisMode, NoiseFilter, PotHead, SnapZone, SnapZoneType};
+
+struct PanicGuard;
+
+impl Drop for PanicGuard {
+    fn drop(&mut self) {
+        unsafe extern "C" {
+            #[link_name = "\n\nERROR: pot-head hot path may panic\n\n"]
+            fn may_panic() -> !;
+        }
+        unsafe { may_panic() }
+    }
+}
+
+#[inline(always)]
+fn no_panic<R>(f: impl FnOnce() -> R) -> R {
+    let guard = PanicGuard;
+    let result = f();
+    core::mem::forget(guard);
+    result
+}
+
+fn run<TOut>(config: Config<'static, u16, TOut>, inputs: &[u16]) -> Option<TOut>
+where
+    TOut: Copy + PartialOrd + pot_head::IntoNormalized + pot_head::FromNormalized,
+{
+    no_panic(|| {
+        let mut pot = PotHead::new(config).ok()?;
+        let mut output = None;
+        for &input in inputs {
+            // A registered callback could panic; show the optimizer there is none
+            pot.set_on_change(None);
+            #[cfg(feature = "auto-range")]
+            pot.set_on_range_change(None);
+            output = Some(pot.update(input));
+        }
+        output
+    })
+}
+
+static ZONES: [SnapZone<Float>; 2] = [
+    SnapZone::new(0.0, 0.02, SnapZoneType::Snap),
+    SnapZone::new(0.5, 0.03, SnapZoneType::Dead),
+];
+
+const INPUTS: [u16; 6] = [0, 2048, u16::MAX, 17, 4095, 1];
+
+#[test]
+fn test_update_cannot_panic() {
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(0.0_f32, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap();
+
+    let output = run(config, black_box(&INPUTS)).unwrap();
+    assert!((0.0..=1.0).contains(&output));
+}
+
+#[cfg(feature = "moving-average")]
+#[test]
+fn test_moving_average_cannot_panic() {
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(0_u8, 255)
+        .filter(NoiseFilter::MovingAverage { window_size: 8 })
+        .build()
+        .unwrap();
+
+    assert!(run(config, black_box(&INPUTS)).is_some());
+}
+
+#[test]
+fn test_update_with_callback_cannot_panic() {
+    fn on_change(output: u8) {
+        black_box(output);
+    }
+
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(0_u8, 127)
+        .build()
+        .unwrap();
+
+    let output = no_panic(|| {
+        let mut pot = PotHead::new(config).ok()?;
+        let mut output = None;
+        for &input in black_box(&INPUTS) {
+            // Set before each update, so the optimizer knows which callback runs
+            pot.set_on_change(Some(on_change));
+            #[cfg(feature = "auto-range")]
+            pot.set_on_range_change(None);
+            output = Some(pot.update(input));
+        }
+        output
+    });
+    assert!(output.is_some());
+}
+
+#[cfg(feature = "no-float")]
+#[test]
+fn test_fixed_update_cannot_panic() {
+    use pot_head::fixed::{LOG_LUT, ratio};
+    use pot_head::{FixedConfig, FixedCurve, FixedPotHead};
+
+    static CONFIG: FixedConfig = FixedConfig {
+        input_min: 0,
+        input_max: 4095,
+        output_min: 0,
+        output_max: 127,
+        curve: FixedCurve::Lut(&LOG_LUT),
+        change_threshold: ratio(1, 100),
+        snap_zones: &[],
+    };
+    let mut pot = FixedPotHead::new(&CONFIG).unwrap();
+
+    for input in [i32::MIN, 0, 2048, i32::MAX] {
+        no_panic(|| pot.update(black_box(input)));
+    }
+}
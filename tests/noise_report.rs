@@ -0,0 +1,100 @@
+#![cfg(feature = "noise-report")]
+
+mod common;
+
+use common::{assert_close, pot};
+use pot_head::{Config, NoiseFilter, PotHead};
+
+#[test]
+fn none_until_window_completes() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.set_noise_window(4);
+
+    for _ in 0..3 {
+        pot.update(500);
+    }
+    assert_eq!(pot.noise_report(), None);
+
+    pot.update(500);
+    let report = pot.noise_report().unwrap();
+    assert_eq!(report.samples, 4);
+    assert_eq!(report.peak_to_peak, 0.0);
+    assert_eq!(report.rms, 0.0);
+}
+
+#[test]
+fn measures_jitter() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.set_noise_window(4);
+
+    // ±2 counts around 500
+    for input in [498, 502, 498, 502] {
+        pot.update(input);
+    }
+
+    let report = pot.noise_report().unwrap();
+    assert_close(report.peak_to_peak, 0.004);
+    assert_close(report.rms, 0.002);
+}
+
+#[test]
+fn measures_before_filter() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.05 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.set_noise_window(4);
+
+    for input in [490, 510, 490, 510] {
+        pot.update(input);
+    }
+    assert_close(pot.noise_report().unwrap().peak_to_peak, 0.02);
+}
+
+#[test]
+fn jitter_at_end_stop_not_clamped() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.set_noise_window(2);
+
+    pot.update(1000);
+    pot.update(1010);
+    assert_close(pot.noise_report().unwrap().peak_to_peak, 0.01);
+}
+
+#[test]
+fn report_covers_latest_window() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.set_noise_window(2);
+
+    pot.update(400);
+    pot.update(600);
+    pot.update(500);
+    assert_close(pot.noise_report().unwrap().peak_to_peak, 0.2);
+
+    pot.update(500);
+    assert_eq!(pot.noise_report().unwrap().peak_to_peak, 0.0);
+}
+
+#[test]
+fn zero_window_disables() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.set_noise_window(0);
+
+    for _ in 0..100 {
+        pot.update(500);
+    }
+    assert_eq!(pot.noise_report(), None);
+}
+
+#[test]
+fn default_window() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+
+    for _ in 0..64 {
+        pot.update(500);
+    }
+    assert_eq!(pot.noise_report().unwrap().samples, 64);
+}
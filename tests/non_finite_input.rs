@@ -0,0 +1,111 @@
+mod common;
+
+use common::filtered_pot;
+use pot_head::static_pot::{Ema, Linear, NoHysteresis};
+use pot_head::{InputError, NoiseFilter, StaticPotHead};
+
+#[test]
+fn test_nan_holds_last_output() {
+    let mut pot = filtered_pot(
+        (0.0_f32, 10.0),
+        (0.0_f32, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+
+    assert_eq!(pot.update(5.0), 0.5);
+    assert_eq!(pot.update(f32::NAN), 0.5);
+    assert_eq!(pot.last_output(), 0.5);
+}
+
+#[test]
+fn test_nan_does_not_poison_filter() {
+    let mut pot = filtered_pot(
+        (0.0_f32, 10.0),
+        (0.0_f32, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+
+    pot.update(5.0);
+    pot.update(f32::NAN);
+
+    // Filter continues from 0.5 as if the NaN never arrived
+    assert_eq!(pot.update(10.0), 0.75);
+}
+
+#[test]
+fn test_nan_before_first_update() {
+    let mut pot = filtered_pot(
+        (0.0_f32, 10.0),
+        (0.0_f32, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+
+    assert_eq!(pot.update(f32::NAN), 0.0);
+    assert_eq!(pot.update(10.0), 1.0);
+}
+
+#[test]
+fn test_infinity_is_clamped() {
+    let mut pot = filtered_pot(
+        (0.0_f32, 10.0),
+        (0.0_f32, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+
+    assert_eq!(pot.update(f32::INFINITY), 1.0);
+    assert_eq!(pot.update(f32::NEG_INFINITY), 0.5);
+    assert_eq!(pot.update(f32::NEG_INFINITY), 0.25);
+}
+
+#[test]
+fn test_try_update_reports_nan() {
+    let mut pot = filtered_pot(
+        (0.0_f32, 10.0),
+        (0.0_f32, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+
+    assert_eq!(pot.try_update(5.0), Ok(0.5));
+    assert_eq!(pot.try_update(f32::NAN), Err(InputError::NotANumber));
+    assert_eq!(pot.last_output(), 0.5);
+    assert_eq!(InputError::NotANumber.to_string(), "input is NaN");
+}
+
+#[test]
+fn test_nan_does_not_count_as_update() {
+    let mut pot = filtered_pot(
+        (0.0_f32, 10.0),
+        (0.0_f32, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+
+    pot.update(5.0);
+    pot.update(5.0);
+    assert_eq!(pot.updates_since_change(), 1);
+
+    pot.update(f32::NAN);
+    assert_eq!(pot.updates_since_change(), 1);
+}
+
+#[test]
+fn test_set_output_ignores_nan() {
+    let mut pot = filtered_pot(
+        (0.0_f32, 10.0),
+        (0.0_f32, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+
+    pot.update(5.0);
+    pot.set_output(f32::NAN);
+    assert_eq!(pot.last_output(), 0.5);
+}
+
+#[test]
+fn test_static_pot_holds_on_nan() {
+    let mut pot: StaticPotHead<f32, f32, Linear, Ema, NoHysteresis> =
+        StaticPotHead::new((0.0, 10.0), (0.0, 1.0), Ema::new(0.5), NoHysteresis).unwrap();
+
+    assert_eq!(pot.update(5.0), 0.5);
+    assert_eq!(pot.update(f32::NAN), 0.5);
+    assert_eq!(pot.update(10.0), 0.75);
+}
@@ -1,23 +1,38 @@
-use pot_head::{Config, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone};
+use pot_head::{
+    Config, Float, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone, SnapZoneType,
+    StageOrder,
+};
 
 #[cfg(feature = "grab-mode")]
-use pot_head::GrabMode;
+use pot_head::{GrabMode, ReleasePolicy};
 
-static EMPTY_SNAP_ZONES: [SnapZone<f32>; 0] = [];
+static EMPTY_SNAP_ZONES: [SnapZone<Float>; 0] = [];
 
 #[test]
 fn test_u16_to_u16_normalization() {
     let config = Config {
         input_min: 0_u16,
         input_max: 4095_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0_u16,
         output_max: 255_u16,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -30,12 +45,12 @@ fn test_u16_to_u16_normalization() {
 
     // Test middle (should be approximately 127-128)
     let mid = pot.update(2047);
-    assert!(mid >= 127 && mid <= 128, "Middle value was {}", mid);
+    assert!((127..=128).contains(&mid), "Middle value was {}", mid);
 
     // Test quarter point
     let quarter = pot.update(1023);
     assert!(
-        quarter >= 63 && quarter <= 64,
+        (63..=64).contains(&quarter),
         "Quarter value was {}",
         quarter
     );
@@ -46,14 +61,26 @@ fn test_u16_to_f32_normalization() {
     let config = Config {
         input_min: 0_u16,
         input_max: 4095_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -82,14 +109,26 @@ fn test_input_clamping() {
     let config = Config {
         input_min: 100_u16,
         input_max: 200_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -109,14 +148,26 @@ fn test_inverted_output_range() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 1.0_f32,
         output_max: 0.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -132,19 +183,66 @@ fn test_inverted_output_range() {
     assert!((mid - 0.5).abs() < 0.01, "Middle value was {}", mid);
 }
 
+#[test]
+fn test_descending_input_range() {
+    let config = Config::builder()
+        .input_range(200_u16, 100)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(200), 0.0);
+    assert_eq!(pot.update(150), 0.5);
+    assert_eq!(pot.update(100), 1.0);
+
+    // Clamping works on either side of a descending range
+    assert_eq!(pot.update(50), 1.0);
+    assert_eq!(pot.update(300), 0.0);
+}
+
+#[test]
+fn test_descending_input_with_invert_input() {
+    let config = Config::builder()
+        .input_range(200_u16, 100)
+        .invert_input(true)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    // Both inversions cancel out
+    assert_eq!(pot.update(100), 0.0);
+    assert_eq!(pot.update(200), 1.0);
+}
+
 #[test]
 fn test_same_type_conversion() {
     let config = Config {
         input_min: 0_f32,
         input_max: 1.0_f32,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 100.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &EMPTY_SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -153,3 +251,115 @@ fn test_same_type_conversion() {
     assert_eq!(pot.update(1.0), 100.0);
     assert_eq!(pot.update(0.5), 50.0);
 }
+
+#[test]
+fn test_inverted_input() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .invert_input(true)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(0), 1.0);
+    assert_eq!(pot.update(250), 0.75);
+    assert_eq!(pot.update(1000), 0.0);
+
+    // Out-of-range input still clamps
+    assert_eq!(pot.update(1500), 0.0);
+}
+
+#[test]
+fn test_inverted_input_snap_zones_in_corrected_space() {
+    // Zone at 0.0 applies to the fully counter-clockwise position of a reversed pot
+    let zones = [SnapZone::new(0.0, 0.05, SnapZoneType::Snap)];
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .invert_input(true)
+        .output_range(0.0_f32, 1.0)
+        .snap_zones(&zones)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(970), 0.0);
+    assert_eq!(pot.update(30), 0.97);
+}
+
+#[test]
+fn test_input_center_maps_to_half() {
+    // Pan pot whose detent reads 600 instead of 500
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .input_center(600)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(0), 0.0);
+    assert_eq!(pot.update(300), 0.25);
+    assert_eq!(pot.update(600), 0.5);
+    assert_eq!(pot.update(800), 0.75);
+    assert_eq!(pot.update(1000), 1.0);
+}
+
+#[test]
+fn test_input_center_with_descending_range() {
+    let config = Config::builder()
+        .input_range(1000_u16, 0)
+        .input_center(600)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(1000), 0.0);
+    assert_eq!(pot.update(800), 0.25);
+    assert_eq!(pot.update(600), 0.5);
+    assert_eq!(pot.update(300), 0.75);
+}
+
+#[test]
+fn test_end_margin_reaches_extremes_early() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .end_margin(0.1)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(0), 0.0);
+    assert_eq!(pot.update(100), 0.0);
+    assert_eq!(pot.update(500), 0.5);
+    assert!((pot.update(700) - 0.75).abs() < 1e-6);
+    assert!((pot.update(900) - 1.0).abs() < 1e-6);
+    assert_eq!(pot.update(950), 1.0);
+    assert_eq!(pot.update(1000), 1.0);
+}
+
+#[test]
+fn test_end_margin_keeps_center() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .input_center(600)
+        .end_margin(0.1)
+        .invert_input(true)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap();
+
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(600), 0.5);
+    assert_eq!(pot.update(1000), 0.0);
+    assert_eq!(pot.update(0), 1.0);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "observed-range")]
+
+mod common;
+
+use common::pot;
+
+#[test]
+fn test_observed_range_empty_before_update() {
+    assert_eq!(pot((100_u16, 1000), (0.0_f32, 1.0)).observed_range(), None);
+}
+
+#[test]
+fn test_observed_range_tracks_extremes() {
+    let mut pot = pot((100_u16, 1000), (0.0_f32, 1.0));
+    pot.update(500);
+    assert_eq!(pot.observed_range(), Some((500, 500)));
+
+    pot.update(300);
+    pot.update(900);
+    pot.update(600);
+    assert_eq!(pot.observed_range(), Some((300, 900)));
+}
+
+#[test]
+fn test_observed_range_records_raw_out_of_range_inputs() {
+    let mut pot = pot((100_u16, 1000), (0.0_f32, 1.0));
+    pot.update(20);
+    pot.update(1100);
+    assert_eq!(pot.observed_range(), Some((20, 1100)));
+}
+
+#[test]
+fn test_observed_range_survives_reset_until_cleared() {
+    let mut pot = pot((100_u16, 1000), (0.0_f32, 1.0));
+    pot.update(400);
+    pot.reset();
+    assert_eq!(pot.observed_range(), Some((400, 400)));
+
+    pot.clear_observed_range();
+    assert_eq!(pot.observed_range(), None);
+}
@@ -0,0 +1,169 @@
+//! Property tests for pipeline invariants across random configs and input sequences.
+
+use pot_head::{
+    Config, Float, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone, SnapZoneType,
+    StageOrder,
+};
+use proptest::prelude::*;
+
+fn curve() -> impl Strategy<Value = ResponseCurve> {
+    #[cfg(feature = "std-math")]
+    return prop_oneof![
+        Just(ResponseCurve::Linear),
+        Just(ResponseCurve::Logarithmic)
+    ];
+
+    #[cfg(not(feature = "std-math"))]
+    return Just(ResponseCurve::Linear);
+}
+
+fn filter() -> impl Strategy<Value = NoiseFilter> {
+    let ema =
+        (0.01..=1.0 as Float).prop_map(|alpha| NoiseFilter::ExponentialMovingAverage { alpha });
+
+    #[cfg(feature = "moving-average")]
+    return prop_oneof![
+        Just(NoiseFilter::None),
+        ema,
+        (1..=32_usize).prop_map(|window_size| NoiseFilter::MovingAverage { window_size }),
+    ];
+
+    #[cfg(not(feature = "moving-average"))]
+    return prop_oneof![Just(NoiseFilter::None), ema];
+}
+
+fn hysteresis() -> impl Strategy<Value = HysteresisMode<Float>> {
+    prop_oneof![
+        Just(HysteresisMode::none()),
+        (0.0..0.2 as Float).prop_map(|threshold| HysteresisMode::ChangeThreshold { threshold }),
+        (0.0..0.5 as Float, 0.001..0.5 as Float).prop_map(|(falling, gap)| {
+            HysteresisMode::SchmittTrigger {
+                rising: falling + gap,
+                falling,
+            }
+        }),
+    ]
+}
+
+fn stage_order() -> impl Strategy<Value = StageOrder> {
+    prop_oneof![
+        Just(StageOrder::CurveFirst),
+        Just(StageOrder::HysteresisFirst),
+        Just(StageOrder::PhysicalFirst),
+    ]
+}
+
+fn snap_zones() -> impl Strategy<Value = Vec<SnapZone<Float>>> {
+    let zone_type = prop_oneof![Just(SnapZoneType::Snap), Just(SnapZoneType::Dead)];
+    prop::collection::vec(
+        (0.0..=1.0 as Float, 0.0..0.1 as Float, zone_type)
+            .prop_map(|(target, threshold, zone_type)| SnapZone::new(target, threshold, zone_type)),
+        0..4,
+    )
+}
+
+/// Total variation of a sequence: the sum of absolute steps.
+fn total_variation(values: &[Float]) -> Float {
+    values
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .sum()
+}
+
+proptest! {
+    #[test]
+    fn output_stays_in_range(
+        curve in curve(),
+        filter in filter(),
+        hysteresis in hysteresis(),
+        stage_order in stage_order(),
+        zones in snap_zones(),
+        output_max in 1.0..1000.0 as Float,
+        inputs in prop::collection::vec(any::<u16>(), 1..64),
+    ) {
+        let config = Config::builder()
+            .input_range(0_u16, 4095)
+            .output_range(0.0, output_max)
+            .curve(curve)
+            .filter(filter)
+            .hysteresis(hysteresis)
+            .stage_order(stage_order)
+            .snap_zones(&zones)
+            .build()
+            .unwrap();
+        let mut pot = PotHead::new(config).unwrap();
+
+        for input in inputs {
+            let output = pot.update(input);
+            prop_assert!((0.0..=output_max).contains(&output), "{} out of range", output);
+        }
+    }
+
+    #[test]
+    fn curves_are_monotonic(curve in curve(), a in 0.0..=1.0 as Float, b in 0.0..=1.0 as Float) {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        prop_assert!(curve.apply(low) <= curve.apply(high));
+    }
+
+    #[test]
+    fn sorted_inputs_give_sorted_outputs(
+        curve in curve(),
+        mut inputs in prop::collection::vec(any::<u16>(), 1..64),
+    ) {
+        inputs.sort_unstable();
+
+        let config = Config::builder()
+            .input_range(0_u16, u16::MAX)
+            .output_range(0.0 as Float, 1.0)
+            .curve(curve)
+            .build()
+            .unwrap();
+        let mut pot = PotHead::new(config).unwrap();
+
+        let outputs: Vec<Float> = inputs.iter().map(|&input| pot.update(input)).collect();
+        prop_assert!(outputs.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn hysteresis_never_adds_variation(
+        threshold in 0.0..0.2 as Float,
+        inputs in prop::collection::vec(0.0..=1.0 as Float, 1..64),
+    ) {
+        let config = Config::builder()
+            .input_range(0.0 as Float, 1.0)
+            .output_range(0.0, 1.0)
+            .hysteresis(HysteresisMode::ChangeThreshold { threshold })
+            .build()
+            .unwrap();
+        let mut pot = PotHead::new(config).unwrap();
+
+        // Hysteresis state starts at 0.0, so compare against the input from there
+        let mut raw = vec![0.0];
+        raw.extend(&inputs);
+        let mut held = vec![0.0];
+        held.extend(inputs.iter().map(|&input| pot.update(input)));
+
+        prop_assert!(total_variation(&held) <= total_variation(&raw) + 1e-4);
+    }
+
+    #[test]
+    fn snap_zone_yields_target(
+        target in 0.0..=1.0 as Float,
+        threshold in 0.0..0.2 as Float,
+        offset in -1.0..=1.0 as Float,
+    ) {
+        let zones = [SnapZone::new(target, threshold, SnapZoneType::Snap)];
+        let config = Config::builder()
+            .input_range(0.0 as Float, 1.0)
+            .output_range(0.0, 1.0)
+            .snap_zones(&zones)
+            .build()
+            .unwrap();
+        let mut pot = PotHead::new(config).unwrap();
+
+        let input = target + offset * threshold;
+        prop_assume!((0.0..=1.0).contains(&input));
+
+        prop_assert_eq!(pot.update(input), target);
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "taps")]
+
+use pot_head::{Config, HysteresisMode, NoiseFilter, PipelineTaps, PotHead, StageOrder};
+
+#[test]
+fn test_taps_default_before_update() {
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    let pot = PotHead::new(config).unwrap();
+    assert_eq!(pot.taps(), PipelineTaps::default());
+}
+
+#[test]
+fn test_taps_record_each_stage() {
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .invert_input(true)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.5 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.3 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(0);
+    pot.update(1000);
+
+    let taps = pot.taps();
+    assert_eq!(taps.normalized, 0.0);
+    assert_eq!(taps.filtered, 0.5);
+    assert_eq!(taps.curved, 0.5);
+    assert_eq!(taps.hysteresis, 0.5);
+    assert_eq!(taps.snapped, 0.5);
+    assert_eq!(taps.output, 0.5);
+
+    // Below threshold: hysteresis holds while the filter keeps moving
+    pot.update(1000);
+    let taps = pot.taps();
+    assert_eq!(taps.filtered, 0.25);
+    assert_eq!(taps.hysteresis, 0.5);
+    assert_eq!(taps.output, 0.5);
+}
+
+#[test]
+fn test_taps_follow_stage_order() {
+    let config = Config::<u16, f32>::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .stage_order(StageOrder::HysteresisFirst)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(400);
+    pot.update(450);
+
+    let taps = pot.taps();
+    assert!((taps.filtered - 0.45).abs() < 1e-6);
+    assert!((taps.hysteresis - 0.4).abs() < 1e-6);
+    assert!((taps.curved - 0.4).abs() < 1e-6);
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::assert_close;
+use pot_head::{Config, HysteresisMode, PotBank, PotHead};
+
+fn config(output_max: f32) -> Config<'static, u16, f32> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, output_max)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_update_all_returns_outputs() {
+    let mut bank: PotBank<4, u16, f32> = PotBank::from_config(config(1.0)).unwrap();
+
+    assert_eq!(bank.update_all(&[0, 250, 500, 1000]), [0.0, 0.25, 0.5, 1.0]);
+    assert_eq!(bank.outputs(), [0.0, 0.25, 0.5, 1.0]);
+    assert_eq!(bank.len(), 4);
+}
+
+#[test]
+fn test_heterogeneous_configs() {
+    let mut bank = PotBank::new([
+        PotHead::new(config(1.0)).unwrap(),
+        PotHead::new(config(100.0)).unwrap(),
+    ]);
+
+    assert_eq!(bank.update_all(&[500, 500]), [0.5, 50.0]);
+}
+
+#[test]
+fn test_changed_mask() {
+    let mut bank: PotBank<3, u16, f32> = PotBank::from_config(config(1.0)).unwrap();
+
+    bank.update_all(&[0, 500, 1000]);
+    assert_eq!(bank.changed_mask(), 0b110);
+
+    bank.update_all(&[0, 600, 1000]);
+    assert_eq!(bank.changed_mask(), 0b010);
+    assert_eq!(bank.changed().collect::<Vec<_>>(), [(1, 0.6)]);
+
+    bank.update_all(&[0, 600, 1000]);
+    assert_eq!(bank.changed_mask(), 0);
+    assert_eq!(bank.changed().count(), 0);
+}
+
+#[test]
+fn test_per_channel_access() {
+    let mut bank: PotBank<2, u16, f32> = PotBank::from_config(config(1.0)).unwrap();
+
+    assert_close(bank.update(1, 750).unwrap(), 0.75);
+    assert_eq!(bank.update(2, 750), None);
+    assert_eq!(bank.outputs()[0], 0.0);
+    assert_close(bank.outputs()[1], 0.75);
+
+    bank.get_mut(0)
+        .unwrap()
+        .set_hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .unwrap();
+    bank.update_all(&[500, 500]);
+    bank.update_all(&[550, 550]);
+    assert_eq!(bank.outputs(), [0.5, 0.55]);
+    assert!(bank.get(2).is_none());
+}
+
+#[test]
+fn test_reset_all() {
+    let mut bank: PotBank<2, u16, f32> = PotBank::from_config(config(1.0)).unwrap();
+    bank.update_all(&[500, 1000]);
+
+    bank.reset_all();
+    assert_eq!(bank.outputs(), [0.0, 0.0]);
+    assert_eq!(bank.changed_mask(), 0);
+    assert!(bank.iter().all(|pot| !pot.is_dirty()));
+}
@@ -0,0 +1,310 @@
+mod common;
+
+use pot_head::{Config, Float, HysteresisMode, NoiseFilter, PotHead, SnapZone, SnapZoneType};
+
+use common::assert_close;
+#[cfg(feature = "grab-mode")]
+use pot_head::GrabMode;
+
+fn config() -> Config<'static, u16, f32> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 100.0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_last_output_before_update() {
+    let pot = PotHead::new(config()).unwrap();
+    assert_eq!(pot.last_output(), 0.0);
+    assert_eq!(pot.last_output_normalized(), 0.0);
+}
+
+#[test]
+fn test_last_output_tracks_update() {
+    let mut pot = PotHead::new(config()).unwrap();
+
+    let output = pot.update(250);
+    assert_eq!(pot.last_output(), output);
+    assert_eq!(pot.last_output(), 25.0);
+    assert_eq!(pot.last_output_normalized(), 0.25);
+
+    // Reading does not process a new sample
+    assert_eq!(pot.last_output(), 25.0);
+}
+
+#[test]
+fn test_reset_clears_filter_history() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.1 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(0);
+    pot.update(0);
+
+    // Without reset the EMA crawls towards the new value
+    assert!(pot.update(1000) < 0.5);
+
+    pot.reset();
+    assert_eq!(pot.last_output(), 0.0);
+    assert_eq!(pot.update(1000), 1.0);
+}
+
+#[test]
+fn test_reset_clears_hysteresis() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(500);
+    assert_eq!(pot.update(550), 0.5);
+
+    pot.reset();
+    assert_eq!(pot.update(550), 0.55);
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_reset_clears_grab_state() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .grab_mode(GrabMode::Pickup)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.set_virtual_value(0.8);
+    pot.update(500);
+    assert!(pot.is_waiting_for_grab());
+
+    pot.reset();
+    assert_eq!(pot.current_output(), 0.0);
+    assert_eq!(pot.update(500), 0.5);
+}
+
+#[test]
+fn test_update_batch_matches_per_sample() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .build()
+        .unwrap();
+    let samples = [100, 400, 380, 420, 900, 880];
+
+    let mut per_sample = PotHead::new(config).unwrap();
+    let mut expected = 0.0;
+    for &sample in &samples {
+        expected = per_sample.update(sample);
+    }
+
+    let mut batched = PotHead::new(config).unwrap();
+    assert_eq!(batched.update_batch(&samples), expected);
+}
+
+#[test]
+fn test_update_batch_empty_returns_last_output() {
+    let mut pot = PotHead::new(config()).unwrap();
+    let output = pot.update(300);
+    assert_eq!(pot.update_batch(&[]), output);
+}
+
+#[test]
+fn test_update_iter_yields_each_output() {
+    let mut pot = PotHead::new(config()).unwrap();
+    let outputs: Vec<f32> = pot.update_iter(&[0, 500, 1000]).collect();
+    assert_eq!(outputs, [0.0, 50.0, 100.0]);
+    assert_eq!(pot.last_output(), 100.0);
+}
+
+#[test]
+fn test_set_output_updates_last_output() {
+    let mut pot = PotHead::new(config()).unwrap();
+    pot.update(200);
+
+    pot.set_output(80.0);
+    assert_eq!(pot.last_output(), 80.0);
+    assert_eq!(pot.last_output_normalized(), 0.8);
+}
+
+#[test]
+fn test_set_output_seeds_hysteresis_reference() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.update(200);
+
+    pot.set_output(0.5);
+
+    // Within threshold of the external value: hold it
+    assert_eq!(pot.update(550), 0.5);
+
+    // Beyond threshold: pot takes over
+    assert_close(pot.update(700), 0.7);
+}
+
+#[test]
+fn test_set_output_seeds_dead_zone_hold() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.1, SnapZoneType::Dead)];
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.update(200);
+
+    pot.set_output(0.45);
+    assert_eq!(pot.update(550), 0.45);
+}
+
+#[test]
+fn test_set_output_clamps_to_range() {
+    let mut pot = PotHead::new(config()).unwrap();
+    pot.set_output(150.0);
+    assert_eq!(pot.last_output(), 100.0);
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_set_output_hysteresis_first_uses_pre_curve_space() {
+    use pot_head::{ResponseCurve, StageOrder};
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0, 1.0)
+        .curve(ResponseCurve::Logarithmic)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .stage_order(StageOrder::HysteresisFirst)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    let target = ResponseCurve::Logarithmic.apply(0.5);
+    pot.set_output(target);
+
+    // 0.55 physical is within threshold of 0.5 physical
+    assert!((pot.update(550) - target).abs() < 1e-5);
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_set_output_with_grab_mode_sets_virtual_value() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .grab_mode(GrabMode::Pickup)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.update(200);
+
+    pot.set_output(0.6);
+    assert!((pot.current_output() - 0.6).abs() < 1e-6);
+    assert!((pot.update(300) - 0.6).abs() < 1e-6);
+    assert!(pot.is_waiting_for_grab());
+}
+
+#[test]
+fn test_on_change_called_only_on_change() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+    static LAST: AtomicU32 = AtomicU32::new(0);
+
+    fn on_change(value: f32) {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        LAST.store(value.to_bits(), Ordering::SeqCst);
+    }
+
+    let mut pot = PotHead::new(config()).unwrap();
+    pot.set_on_change(Some(on_change));
+
+    pot.update(500);
+    pot.update(500);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(f32::from_bits(LAST.load(Ordering::SeqCst)), 50.0);
+
+    pot.update(700);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    // External changes don't trigger the callback
+    pot.set_output(10.0);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    pot.set_on_change(None);
+    pot.update(900);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_dirty_flag_and_take_output() {
+    let mut pot = PotHead::new(config()).unwrap();
+    assert!(!pot.is_dirty());
+    assert_eq!(pot.take_output(), None);
+
+    pot.update(500);
+    assert!(pot.is_dirty());
+
+    // Stays dirty across unchanged updates until taken
+    pot.update(500);
+    assert!(pot.is_dirty());
+    assert_eq!(pot.take_output(), Some(50.0));
+    assert!(!pot.is_dirty());
+    assert_eq!(pot.take_output(), None);
+
+    pot.update(500);
+    assert_eq!(pot.take_output(), None);
+
+    // Several changes coalesce into one take
+    pot.update(600);
+    pot.update(700);
+    assert_close(pot.take_output().unwrap(), 70.0);
+}
+
+#[test]
+fn test_clone_copies_state() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 100.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.5 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.update(1000);
+
+    let mut copy = pot.clone();
+    assert_eq!(copy.last_output(), pot.last_output());
+
+    // Filter history is copied, so both converge identically
+    assert_eq!(copy.update(1000), pot.update(1000));
+    assert_eq!(copy.update(0), pot.update(0));
+}
+
+#[test]
+fn test_debug_prints_summary_and_state() {
+    let mut pot = PotHead::new(config()).unwrap();
+    pot.update(250);
+
+    let debug = format!("{:?}", pot);
+    assert!(debug.starts_with("PotHead { config: ConfigSummary {"));
+    assert!(debug.contains("curve: \"Linear\""));
+    assert!(debug.contains("state: State {"));
+    assert!(debug.contains("last_output: 0.25"));
+}
@@ -0,0 +1,26 @@
+#![cfg(all(feature = "f64", feature = "std-math"))]
+
+use pot_head::{Config, Float, PotHead, ResponseCurve};
+
+#[test]
+fn test_float_is_f64() {
+    assert_eq!(core::mem::size_of::<Float>(), 8);
+}
+
+#[test]
+fn test_24bit_codes_stay_distinct_after_log_curve() {
+    let config = Config::builder()
+        .input_range(0_u32, (1 << 24) - 1)
+        .output_range(0.0_f64, 1.0)
+        .curve(ResponseCurve::Logarithmic)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    let mut prev = pot.update(1000);
+    for code in 1001..1100 {
+        let out = pot.update(code);
+        assert!(out > prev, "code {} did not increase output", code);
+        prev = out;
+    }
+}
@@ -0,0 +1,166 @@
+use pot_head::{Config, ConfigError, Float, PressAction, PushPot, SwitchEvents, SwitchTiming};
+
+const TIMING: SwitchTiming = SwitchTiming {
+    debounce: 2,
+    hold: 10,
+    double_click: 5,
+};
+
+fn knob(action: PressAction) -> PushPot<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    PushPot::new(config, action, TIMING).unwrap()
+}
+
+/// Feed `count` updates with the same input and switch state, collecting events.
+fn run(
+    knob: &mut PushPot<'_, u16, Float>,
+    input: u16,
+    pressed: bool,
+    count: usize,
+) -> SwitchEvents {
+    let mut all = SwitchEvents::default();
+    for _ in 0..count {
+        let (_, events) = knob.update(input, pressed);
+        all.pressed |= events.pressed;
+        all.released |= events.released;
+        all.click |= events.click;
+        all.double_click |= events.double_click;
+        all.hold |= events.hold;
+    }
+    all
+}
+
+#[test]
+fn test_debounce() {
+    let mut knob = knob(PressAction::None);
+
+    // A single-update glitch is ignored
+    assert!(knob.update(0, true).1.is_empty());
+    assert!(knob.update(0, false).1.is_empty());
+    assert!(!knob.is_pressed());
+
+    assert!(knob.update(0, true).1.is_empty());
+    let (_, events) = knob.update(0, true);
+    assert!(events.pressed);
+    assert!(knob.is_pressed());
+}
+
+#[test]
+fn test_click() {
+    let mut knob = knob(PressAction::None);
+
+    run(&mut knob, 0, true, 4);
+    let events = run(&mut knob, 0, false, 2);
+    assert!(events.released);
+    assert!(events.click);
+    assert!(!events.double_click);
+    assert!(!events.hold);
+}
+
+#[test]
+fn test_double_click() {
+    let mut knob = knob(PressAction::None);
+
+    run(&mut knob, 0, true, 3);
+    assert!(run(&mut knob, 0, false, 2).click);
+    run(&mut knob, 0, true, 2);
+    let events = run(&mut knob, 0, false, 2);
+    assert!(events.click);
+    assert!(events.double_click);
+
+    // A third click starts a new sequence
+    run(&mut knob, 0, true, 2);
+    assert!(!run(&mut knob, 0, false, 2).double_click);
+}
+
+#[test]
+fn test_slow_clicks_are_not_double() {
+    let mut knob = knob(PressAction::None);
+
+    run(&mut knob, 0, true, 3);
+    run(&mut knob, 0, false, 10);
+    run(&mut knob, 0, true, 3);
+    let events = run(&mut knob, 0, false, 2);
+    assert!(events.click);
+    assert!(!events.double_click);
+}
+
+#[test]
+fn test_hold() {
+    let mut knob = knob(PressAction::None);
+
+    let mut holds = 0;
+    for _ in 0..30 {
+        if knob.update(0, true).1.hold {
+            holds += 1;
+        }
+    }
+    assert_eq!(holds, 1);
+
+    // Releasing after a hold is not a click
+    let events = run(&mut knob, 0, false, 2);
+    assert!(events.released);
+    assert!(!events.click);
+}
+
+#[test]
+fn test_freeze() {
+    let mut knob = knob(PressAction::Freeze);
+
+    assert_eq!(knob.update(500, false).0, 0.5);
+    run(&mut knob, 500, true, 2);
+    assert!(knob.is_frozen());
+
+    // Turning while pressed doesn't change the output
+    assert_eq!(knob.update(800, true).0, 0.5);
+    assert_eq!(knob.update(200, true).0, 0.5);
+
+    // Released: the pot takes over again (no grab mode)
+    run(&mut knob, 200, false, 2);
+    assert!(!knob.is_frozen());
+    assert_eq!(knob.update(200, false).0, 0.2);
+}
+
+#[test]
+fn test_toggle_fine() {
+    let mut knob = knob(PressAction::ToggleFine { scale: 0.1 });
+
+    knob.update(500, false);
+    run(&mut knob, 500, true, 2);
+    run(&mut knob, 500, false, 2);
+    assert!(knob.is_fine());
+
+    // 0.3 of pot travel moves the output by 0.03
+    let (output, _) = knob.update(800, false);
+    assert!((output - 0.53).abs() < 1e-4, "{}", output);
+
+    // Back below the anchor point
+    let (output, _) = knob.update(0, false);
+    assert!((output - 0.45).abs() < 1e-4, "{}", output);
+
+    // Second click leaves fine mode
+    run(&mut knob, 0, true, 2);
+    run(&mut knob, 0, false, 2);
+    assert!(!knob.is_fine());
+    assert_eq!(knob.update(1000, false).0, 1.0);
+}
+
+#[test]
+fn test_invalid_fine_scale() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+
+    for scale in [0.0, -0.5, 1.5, Float::NAN] {
+        assert!(matches!(
+            PushPot::new(config, PressAction::ToggleFine { scale }, TIMING),
+            Err(ConfigError::InvalidPressAction { .. })
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "python")]
+
+use pot_head::{Float, PyConfig, PyPotHead};
+
+fn config(alpha: Option<Float>) -> PyConfig {
+    PyConfig::new(0.0, 100.0, 0.0, 1.0, false, false, alpha, None, None, None).unwrap()
+}
+
+#[test]
+fn process_replays_trace() {
+    let mut pot = PyPotHead::new(config(None)).unwrap();
+    assert_eq!(pot.process(vec![0.0, 50.0, 100.0]), vec![0.0, 0.5, 1.0]);
+    assert_eq!(pot.output(), 1.0);
+}
+
+#[test]
+fn set_config_sweeps_parameters() {
+    let mut pot = PyPotHead::new(config(None)).unwrap();
+
+    for alpha in [0.25, 0.5] {
+        pot.set_config(config(Some(alpha))).unwrap();
+        pot.reset();
+        pot.update(0.0);
+        assert_eq!(pot.update(100.0), alpha);
+    }
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    assert!(
+        PyConfig::new(
+            0.0,
+            100.0,
+            0.0,
+            1.0,
+            false,
+            false,
+            Some(0.0),
+            None,
+            None,
+            None
+        )
+        .is_err()
+    );
+    assert!(PyConfig::new(5.0, 5.0, 0.0, 1.0, false, false, None, None, None, None).is_err());
+    assert!(
+        PyConfig::new(
+            0.0,
+            100.0,
+            0.0,
+            1.0,
+            false,
+            false,
+            None,
+            Some(0.01),
+            Some((0.6, 0.4)),
+            None
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn to_rust_emits_builder_code() {
+    let config = PyConfig::new(
+        0.0,
+        4095.0,
+        0.0,
+        1.0,
+        false,
+        false,
+        Some(0.3),
+        Some(0.01),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let code = config.to_rust();
+    assert!(code.starts_with("Config::builder()\n    .input_range(0.0, 4095.0)\n"));
+    assert!(code.contains(".filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })"));
+    assert!(code.contains(".hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })"));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "queue-sink")]
+
+use heapless::spsc::Queue;
+use pot_head::{Config, PotBank, PotHead, QueueSink};
+
+fn config() -> Config<'static, u16, u8> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(0, 100)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_update_pushes_changes_only() {
+    let mut queue: Queue<(usize, u8), 8> = Queue::new();
+    let (producer, mut consumer) = queue.split();
+    let mut sink = QueueSink::new(producer);
+    let mut pot = PotHead::new(config()).unwrap();
+
+    assert_eq!(sink.update(3, &mut pot, 500), 50);
+    sink.update(3, &mut pot, 500);
+    sink.update(3, &mut pot, 700);
+
+    assert_eq!(consumer.dequeue(), Some((3, 50)));
+    assert_eq!(consumer.dequeue(), Some((3, 70)));
+    assert_eq!(consumer.dequeue(), None);
+}
+
+#[test]
+fn test_send_changed_from_bank() {
+    let mut queue: Queue<(usize, u8), 8> = Queue::new();
+    let (producer, mut consumer) = queue.split();
+    let mut sink = QueueSink::new(producer);
+    let mut bank: PotBank<3, u16, u8> = PotBank::from_config(config()).unwrap();
+
+    bank.update_all(&[0, 0, 0]);
+    sink.send_changed(&bank);
+    bank.update_all(&[0, 400, 900]);
+    assert_eq!(sink.send_changed(&bank), 2);
+
+    assert_eq!(consumer.dequeue(), Some((1, 40)));
+    assert_eq!(consumer.dequeue(), Some((2, 90)));
+    assert_eq!(consumer.dequeue(), None);
+}
+
+#[test]
+fn test_full_queue_drops() {
+    let mut queue: Queue<(usize, u8), 3> = Queue::new();
+    let (producer, mut consumer) = queue.split();
+    let mut sink = QueueSink::new(producer);
+
+    assert!(sink.send(0, 1));
+    assert!(sink.send(0, 2));
+    assert!(!sink.send(0, 3));
+    assert_eq!(sink.dropped(), 1);
+
+    assert_eq!(consumer.dequeue(), Some((0, 1)));
+    assert!(sink.send(0, 4));
+}
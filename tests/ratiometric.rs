@@ -0,0 +1,56 @@
+#![cfg(feature = "ratiometric")]
+
+mod common;
+
+use common::{assert_close, pot};
+
+#[test]
+fn no_reference_leaves_input_unscaled() {
+    let mut pot = pot((0_u16, 4000), (0.0_f32, 1.0));
+    assert_close(pot.update(2000), 0.5);
+}
+
+#[test]
+fn compensates_sagging_supply() {
+    let mut pot = pot((0_u16, 4000), (0.0_f32, 1.0));
+    pot.set_nominal_reference(Some(3300));
+
+    // Supply sagged by 10%: the wiper at half travel reads 10% low
+    pot.set_reference(2970);
+    assert_close(pot.update(1800), 0.5);
+    assert_close(pot.update(3600), 1.0);
+
+    // Back to nominal
+    pot.set_reference(3300);
+    assert_close(pot.update(2000), 0.5);
+}
+
+#[test]
+fn first_reading_becomes_nominal() {
+    let mut pot = pot((0_u16, 4000), (0.0_f32, 1.0));
+    pot.set_reference(3300);
+    assert_close(pot.update(2000), 0.5);
+
+    pot.set_reference(1650);
+    assert_close(pot.update(1000), 0.5);
+}
+
+#[test]
+fn invalid_readings_are_ignored() {
+    let mut pot = pot((0_u16, 4000), (0.0_f32, 1.0));
+    pot.set_nominal_reference(Some(3300));
+    pot.set_reference(2970);
+    pot.set_reference(0);
+
+    assert_close(pot.update(1800), 0.5);
+}
+
+#[test]
+fn clearing_nominal_disables_compensation() {
+    let mut pot = pot((0_u16, 4000), (0.0_f32, 1.0));
+    pot.set_nominal_reference(Some(3300));
+    pot.set_reference(2970);
+    pot.set_nominal_reference(None);
+
+    assert_close(pot.update(2000), 0.5);
+}
@@ -0,0 +1,176 @@
+mod common;
+
+use common::assert_close;
+use pot_head::{Config, ConfigError, Float, HysteresisMode, NoiseFilter, PotHead, ResponseCurve};
+
+fn ema_config(alpha: Float) -> Config<'static, u16, f32> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha })
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_set_config_preserves_filter_state() {
+    let mut pot = PotHead::new(ema_config(0.5)).unwrap();
+    pot.update(0);
+    assert!((pot.update(1000) - 0.5).abs() < 1e-6);
+
+    // Change output range only - filter keeps its history
+    let mut config = ema_config(0.5);
+    config.output_max = 100.0;
+    pot.set_config(config).unwrap();
+
+    assert!((pot.update(1000) - 75.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_set_config_new_alpha_keeps_history() {
+    let mut pot = PotHead::new(ema_config(0.5)).unwrap();
+    pot.update(0);
+    pot.update(1000); // Filter at 0.5
+
+    pot.set_config(ema_config(0.25)).unwrap();
+
+    // 0.25 * 1.0 + 0.75 * 0.5
+    assert!((pot.update(1000) - 0.625).abs() < 1e-6);
+}
+
+#[test]
+fn test_set_config_filter_change_resets() {
+    let mut pot = PotHead::new(ema_config(0.5)).unwrap();
+    pot.update(0);
+    pot.update(1000);
+
+    let mut config = ema_config(0.5);
+    config.filter = NoiseFilter::None;
+    pot.set_config(config).unwrap();
+    assert_eq!(pot.update(300), 0.3);
+
+    // Switching back starts a fresh filter
+    pot.set_config(ema_config(0.5)).unwrap();
+    assert_eq!(pot.update(800), 0.8);
+}
+
+#[test]
+fn test_set_config_input_range_change_resets_filter() {
+    let mut pot = PotHead::new(ema_config(0.5)).unwrap();
+    pot.update(0);
+
+    let mut config = ema_config(0.5);
+    config.input_max = 2000;
+    pot.set_config(config).unwrap();
+
+    assert_eq!(pot.update(1000), 0.5);
+}
+
+#[test]
+fn test_set_config_preserves_hysteresis_state() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    assert_eq!(pot.update(500), 0.5);
+
+    pot.reconfigure(|c| c.hysteresis = HysteresisMode::ChangeThreshold { threshold: 0.2 })
+        .unwrap();
+
+    // Change of 0.15 is below the new threshold - held at previous output
+    assert_eq!(pot.update(650), 0.5);
+    assert_close(pot.update(750), 0.75);
+}
+
+#[test]
+fn test_set_config_invalid_keeps_current() {
+    let mut pot = PotHead::new(ema_config(0.5)).unwrap();
+
+    let result =
+        pot.reconfigure(|c| c.filter = NoiseFilter::ExponentialMovingAverage { alpha: 2.0 });
+    assert!(matches!(result, Err(ConfigError::InvalidFilter { .. })));
+    assert_eq!(
+        pot.config().filter,
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 }
+    );
+}
+
+#[test]
+fn test_set_filter_adjusts_alpha_live() {
+    let mut pot = PotHead::new(ema_config(0.5)).unwrap();
+    pot.update(0);
+    pot.update(1000); // Filter at 0.5
+
+    pot.set_filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.25 })
+        .unwrap();
+    assert!((pot.update(1000) - 0.625).abs() < 1e-6);
+
+    let result = pot.set_filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.0 });
+    assert!(matches!(result, Err(ConfigError::InvalidFilter { .. })));
+}
+
+#[cfg(feature = "moving-average")]
+#[test]
+fn test_set_filter_keeps_moving_average_buffer() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .filter(NoiseFilter::MovingAverage { window_size: 2 })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.update(0);
+
+    // Same window - buffer kept
+    pot.set_filter(NoiseFilter::MovingAverage { window_size: 2 })
+        .unwrap();
+    assert_eq!(pot.update(1000), 0.5);
+}
+
+#[test]
+fn test_set_hysteresis_threshold() {
+    let mut pot = PotHead::new(ema_config(1.0)).unwrap();
+    pot.set_hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .unwrap();
+
+    assert_eq!(pot.update(500), 0.5);
+    assert_eq!(pot.update(550), 0.5);
+
+    let result = pot.set_hysteresis(HysteresisMode::SchmittTrigger {
+        rising: 0.4,
+        falling: 0.6,
+    });
+    assert!(matches!(result, Err(ConfigError::InvalidHysteresis { .. })));
+}
+
+#[test]
+fn test_set_curve() {
+    let mut pot = PotHead::new(ema_config(1.0)).unwrap();
+    pot.set_curve(ResponseCurve::Linear);
+    assert_eq!(pot.config().curve, ResponseCurve::Linear);
+    assert_eq!(pot.update(250), 0.25);
+}
+
+#[test]
+fn test_set_output_range_keeps_state() {
+    let mut pot = PotHead::new(ema_config(0.5)).unwrap();
+    pot.set_hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.1 })
+        .unwrap();
+    pot.update(0);
+    assert!((pot.update(1000) - 0.5).abs() < 1e-6);
+
+    // Limit to 80%: filter history and hysteresis carry over
+    pot.set_output_range(0.0, 0.8).unwrap();
+    assert!((pot.last_output() - 0.4).abs() < 1e-6);
+    assert!((pot.update(1000) - 0.6).abs() < 1e-6);
+
+    let result = pot.set_output_range(0.5, 0.5);
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidOutputRange { .. })
+    ));
+    assert_eq!(pot.config().output_max, 0.8);
+}
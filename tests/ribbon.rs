@@ -0,0 +1,104 @@
+use pot_head::{Config, ConfigError, Float, NoiseFilter, RibbonHead, RibbonRelease, TouchEvent};
+
+fn ribbon(release: RibbonRelease<Float>) -> RibbonHead<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(100, 900)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    RibbonHead::new(config, release).unwrap()
+}
+
+#[test]
+fn test_touch_and_release_events() {
+    let mut ribbon = ribbon(RibbonRelease::Hold);
+
+    assert_eq!(ribbon.update(0), (0.0, TouchEvent::None));
+    assert!(!ribbon.is_touched());
+
+    assert_eq!(ribbon.update(500), (0.5, TouchEvent::Touch));
+    assert_eq!(ribbon.update(700), (0.75, TouchEvent::None));
+    assert!(ribbon.is_touched());
+
+    assert_eq!(ribbon.update(4095), (0.75, TouchEvent::Release));
+    assert_eq!(ribbon.update(4095), (0.75, TouchEvent::None));
+}
+
+#[test]
+fn test_reset_on_release() {
+    let mut ribbon = ribbon(RibbonRelease::Reset(0.5));
+    assert_eq!(ribbon.last_output(), 0.5);
+
+    ribbon.update(900);
+    assert_eq!(ribbon.last_output(), 1.0);
+    assert_eq!(ribbon.update(0), (0.5, TouchEvent::Release));
+}
+
+#[test]
+fn test_window() {
+    let mut ribbon = ribbon(RibbonRelease::Hold);
+    ribbon.set_window(50, 950).unwrap();
+
+    // In the window but below the input range: touched, clamped
+    assert_eq!(ribbon.update(60), (0.0, TouchEvent::Touch));
+    assert_eq!(ribbon.update(20).1, TouchEvent::Release);
+
+    assert!(matches!(
+        ribbon.set_window(300, 300),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+}
+
+#[test]
+fn test_settle() {
+    let mut ribbon = ribbon(RibbonRelease::Hold);
+    ribbon.set_settle(3);
+
+    assert_eq!(ribbon.update(200).1, TouchEvent::None);
+    assert_eq!(ribbon.update(400).1, TouchEvent::None);
+    assert!(!ribbon.is_touched());
+    assert_eq!(ribbon.update(500), (0.5, TouchEvent::Touch));
+
+    // A glitch out of the window restarts settling
+    ribbon.update(0);
+    ribbon.update(500);
+    ribbon.update(0);
+    assert!(!ribbon.is_touched());
+}
+
+#[test]
+fn test_gate() {
+    let mut ribbon = ribbon(RibbonRelease::Hold);
+
+    // Gate off: the reading is ignored even inside the range
+    assert_eq!(ribbon.update_gated(300, false), (0.0, TouchEvent::None));
+    assert_eq!(ribbon.update_gated(300, true), (0.25, TouchEvent::Touch));
+    assert_eq!(ribbon.update_gated(700, false), (0.25, TouchEvent::Release));
+}
+
+#[test]
+fn test_new_touch_does_not_glide() {
+    let config = Config::builder()
+        .input_range(100_u16, 900)
+        .output_range(0.0, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.1 })
+        .build()
+        .unwrap();
+    let mut ribbon = RibbonHead::new(config, RibbonRelease::Hold).unwrap();
+
+    ribbon.update(100);
+    ribbon.update(0);
+    let (output, _) = ribbon.update(900);
+    assert_eq!(output, 1.0);
+}
+
+#[test]
+fn test_set_release() {
+    let mut ribbon = ribbon(RibbonRelease::Hold);
+    ribbon.update(300);
+    ribbon.update(0);
+
+    ribbon.set_release(RibbonRelease::Reset(1.0));
+    assert_eq!(ribbon.last_output(), 1.0);
+    assert_eq!(ribbon.release(), RibbonRelease::Reset(1.0));
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "self-test")]
+
+use pot_head::{Config, Float, HysteresisMode, NoiseFilter, PotHead, SnapZone, SnapZoneType};
+
+fn config() -> Config<'static, u16, f32> {
+    Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap()
+}
+
+fn run(pot: &mut PotHead<'_, u16, f32>, steps: u16) -> pot_head::SelfTestReport {
+    pot.begin_self_test(steps);
+    loop {
+        if let Some(report) = pot.self_test_step() {
+            return report;
+        }
+        assert!(pot.is_self_testing());
+    }
+}
+
+#[test]
+fn linear_config_passes() {
+    let mut pot = PotHead::new(config()).unwrap();
+    let report = run(&mut pot, 64);
+
+    assert!(report.passed());
+    assert_eq!(report.lowest, 0.0);
+    assert_eq!(report.highest, 1.0);
+    assert_eq!(report.samples, 32 + 64 + 32);
+    assert!(!pot.is_self_testing());
+    assert_eq!(pot.last_output(), 1.0);
+}
+
+#[test]
+fn step_without_session_does_nothing() {
+    let mut pot = PotHead::new(config()).unwrap();
+    assert_eq!(pot.self_test_step(), None);
+    assert_eq!(pot.last_output(), 0.0);
+}
+
+#[test]
+fn inverted_and_filtered_configs_pass() {
+    let mut pot = PotHead::new(Config {
+        invert_input: true,
+        filter: NoiseFilter::ExponentialMovingAverage { alpha: 0.2 },
+        ..config()
+    })
+    .unwrap();
+
+    assert!(run(&mut pot, 128).passed());
+}
+
+#[test]
+fn snap_zone_below_the_top_fails_coverage() {
+    // A snap zone that pins the top of the travel just short of the maximum
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.9, 0.1, SnapZoneType::Snap)];
+    let mut pot = PotHead::new(Config {
+        snap_zones: &ZONES,
+        ..config()
+    })
+    .unwrap();
+
+    let report = run(&mut pot, 64);
+    assert!(report.monotonic);
+    assert!(!report.covers_full_range());
+    assert!(!report.passed());
+}
+
+#[test]
+fn wide_hysteresis_reports_highest_reached() {
+    let mut pot = PotHead::new(Config {
+        hysteresis: HysteresisMode::ChangeThreshold { threshold: 0.4 },
+        ..config()
+    })
+    .unwrap();
+
+    let report = run(&mut pot, 10);
+    assert!(report.monotonic);
+    assert!(report.highest < 1.0);
+    assert!(!report.passed());
+}
+
+#[test]
+fn cancel_ends_session() {
+    let mut pot = PotHead::new(config()).unwrap();
+    pot.begin_self_test(16);
+    pot.self_test_step();
+    pot.cancel_self_test();
+
+    assert!(!pot.is_self_testing());
+    assert_eq!(pot.self_test_step(), None);
+}
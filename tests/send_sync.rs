@@ -0,0 +1,54 @@
+//! Compile-time check that pots can move between interrupt handlers and tasks
+//! (RTIC resources, embassy tasks) and that shared parts can sit in statics.
+
+use pot_head::static_pot::{Ema, Linear, NoHysteresis};
+use pot_head::{
+    Config, ConfigBuilder, Crossfader, DualPotHead, EncoderHead, EndlessPotHead, ExpressionPedal,
+    JoystickHead, MacroControl, MultiTurnPotHead, MuxScanner, OutputSlot, PotBank, PotHead,
+    PotReader, PotSampler, PushPot, RibbonHead, State, StateSnapshot, StaticPotHead,
+};
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+#[test]
+fn test_pots_are_send_and_sync() {
+    assert_send::<PotHead<u16, f32>>();
+    assert_sync::<PotHead<u16, f32>>();
+    assert_send::<Config<u16, f32>>();
+    assert_sync::<Config<u16, f32>>();
+    assert_send::<ConfigBuilder<u16, f32>>();
+    assert_send::<State<f32>>();
+    assert_send::<StateSnapshot<u16>>();
+
+    assert_send::<PotBank<4, u16, u8>>();
+    assert_send::<MacroControl<4, u16, u8>>();
+    assert_send::<MuxScanner<8, u16, u8>>();
+    assert_send::<DualPotHead<u16, f32>>();
+    assert_send::<EndlessPotHead<u16, f32>>();
+    assert_send::<MultiTurnPotHead<u16, f32>>();
+    assert_send::<EncoderHead<u8>>();
+    assert_send::<JoystickHead<u16, f32>>();
+    assert_send::<Crossfader<u16, f32>>();
+    assert_send::<RibbonHead<u16, f32>>();
+    assert_send::<ExpressionPedal<u16, f32>>();
+    assert_send::<PushPot<u16, f32>>();
+    assert_send::<StaticPotHead<u16, f32, Linear, Ema, NoHysteresis>>();
+
+    #[cfg(feature = "no-float")]
+    assert_send::<pot_head::FixedPotHead>();
+}
+
+#[test]
+fn test_split_halves_cross_contexts() {
+    assert_sync::<OutputSlot>();
+    assert_send::<PotSampler<'static, 'static, u16, u8>>();
+    assert_send::<PotReader<'static, u8>>();
+    assert_sync::<PotReader<'static, u8>>();
+}
+
+#[cfg(feature = "queue-sink")]
+#[test]
+fn test_queue_sink_is_send() {
+    assert_send::<pot_head::QueueSink<'static, u8>>();
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "simd")]
+
+use pot_head::{Config, Float, NoiseFilter, PotBank, PotHead};
+
+const CHANNELS: usize = 19;
+
+/// A different input mapping on each channel, so every lane of a group
+/// normalizes with its own constants.
+fn pots() -> [PotHead<'static, Float, Float>; CHANNELS] {
+    core::array::from_fn(|channel| {
+        let mut builder = Config::builder().output_range(0.0, 100.0);
+        builder = match channel % 4 {
+            0 => builder.input_range(0.0, 4095.0),
+            1 => builder.input_range(100.0, 4000.0).input_center(1800.0),
+            2 => builder.input_range(4095.0, 0.0),
+            _ => builder.input_range(-1.0, 1.0).input_center(0.25),
+        };
+        if channel % 3 == 0 {
+            builder = builder.invert_input(true);
+        }
+        if channel % 5 == 0 {
+            builder = builder.end_margin(0.05);
+        }
+        if channel % 2 == 0 {
+            builder = builder.filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.4 });
+        }
+        PotHead::new(builder.build().unwrap()).unwrap()
+    })
+}
+
+/// Inputs sweeping past both ends of every range, with NaNs mixed in.
+fn inputs(step: usize) -> [Float; CHANNELS] {
+    core::array::from_fn(|channel| match (step + channel) % 17 {
+        0 => Float::NAN,
+        1 => Float::INFINITY,
+        2 => Float::NEG_INFINITY,
+        n => (n as Float - 8.0) * 600.0 / (channel % 4 + 1) as Float,
+    })
+}
+
+#[test]
+fn test_matches_scalar_updates() {
+    let mut bank = PotBank::new(pots());
+    let mut scalar = pots();
+
+    for step in 0..200 {
+        let inputs = inputs(step);
+        let outputs = bank.update_all(&inputs);
+
+        let mut changed = 0_u64;
+        for (channel, pot) in scalar.iter_mut().enumerate() {
+            let before = pot.last_output();
+            let output = pot.update(inputs[channel]);
+            assert_eq!(
+                outputs[channel].to_bits(),
+                output.to_bits(),
+                "channel {channel}, step {step}, input {}",
+                inputs[channel]
+            );
+            if output != before {
+                changed |= 1 << channel;
+            }
+        }
+        assert_eq!(bank.changed_mask(), changed, "step {step}");
+    }
+}
+
+#[test]
+fn test_bank_smaller_than_a_group() {
+    let config = Config::builder()
+        .input_range(0.0, 1000.0)
+        .output_range(0.0, 1.0)
+        .build()
+        .unwrap();
+    let mut bank: PotBank<3, Float, Float> = PotBank::from_config(config).unwrap();
+
+    assert_eq!(bank.update_all(&[0.0, 500.0, 1000.0]), [0.0, 0.5, 1.0]);
+    assert_eq!(bank.changed_mask(), 0b110);
+}
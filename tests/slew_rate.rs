@@ -0,0 +1,125 @@
+use pot_head::{Config, ConfigError, Float, PotHead, SnapZone, SnapZoneType, ValidationErrors};
+
+fn slewed_pot(rate: Float) -> PotHead<'static, u16, Float> {
+    let config = Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 100.0)
+        .slew_rate(rate)
+        .build()
+        .unwrap();
+    PotHead::new(config).unwrap()
+}
+
+#[test]
+fn test_first_update_jumps() {
+    let mut pot = slewed_pot(5.0);
+    assert!((pot.update(800) - 80.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_ramps_in_output_units() {
+    let mut pot = slewed_pot(5.0);
+    pot.update(0);
+
+    // Jump to the top of the range ramps 5 units per update
+    for step in 1..=20 {
+        let output = pot.update(1000);
+        assert!(
+            (output - step as Float * 5.0).abs() < 1e-3,
+            "step {}: {}",
+            step,
+            output
+        );
+    }
+    assert_eq!(pot.update(1000), 100.0);
+
+    // And back down
+    assert!((pot.update(0) - 95.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_small_moves_pass_through() {
+    let mut pot = slewed_pot(5.0);
+    pot.update(500);
+    assert!((pot.update(530) - 53.0).abs() < 1e-3);
+    assert!((pot.update(480) - 48.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_snap_zone_ramps() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.1, SnapZoneType::Snap)];
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 100.0)
+        .snap_zones(&ZONES)
+        .slew_rate(2.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert!((pot.update(350) - 35.0).abs() < 1e-3);
+
+    // Entering the snap zone ramps towards the centre instead of stepping
+    assert!((pot.update(420) - 37.0).abs() < 1e-3);
+    assert!((pot.update(420) - 39.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_dead_zone_holds_target_not_ramp() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(1.0, 0.2, SnapZoneType::Dead)];
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0 as Float, 100.0)
+        .snap_zones(&ZONES)
+        .slew_rate(10.0)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(0);
+    pot.update(790);
+
+    // Output keeps ramping to the value held by the dead zone
+    let mut output = 0.0;
+    for _ in 0..10 {
+        output = pot.update(900);
+    }
+    assert!((output - 79.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_set_output_and_reset() {
+    let mut pot = slewed_pot(5.0);
+    pot.update(0);
+
+    pot.set_output(60.0);
+    assert!((pot.last_output() - 60.0).abs() < 1e-3);
+    assert!((pot.update(600) - 60.0).abs() < 1e-3);
+
+    pot.reset();
+    assert!((pot.update(1000) - 100.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_invalid_slew_rate() {
+    for rate in [0.0, -1.0, Float::NAN] {
+        let result = Config::<u16, Float>::builder()
+            .input_range(0, 1000)
+            .output_range(0.0, 1.0)
+            .slew_rate(rate)
+            .build();
+        assert!(
+            matches!(result, Err(ConfigError::InvalidSlewRate { .. })),
+            "rate {}",
+            rate
+        );
+    }
+
+    let config = Config {
+        slew_rate: Some(0.0),
+        ..*slewed_pot(1.0).config()
+    };
+    assert!(config.validate_all().contains(ValidationErrors::SLEW_RATE));
+}
@@ -1,27 +1,40 @@
 use pot_head::{
-    Config, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone, SnapZoneType,
+    Config, ConfigError, Float, HysteresisMode, NoiseFilter, PotHead, ResponseCurve, SnapZone,
+    SnapZoneType, StageOrder,
 };
 
 #[cfg(feature = "grab-mode")]
-use pot_head::GrabMode;
+use pot_head::{GrabMode, ReleasePolicy};
 
 #[test]
 fn test_snap_zone_basic() {
-    static SNAP_ZONES: [SnapZone<f32>; 1] = [
+    static SNAP_ZONES: [SnapZone<Float>; 1] = [
         SnapZone::new(0.0, 0.05, SnapZoneType::Snap), // Snap to 0% within ±5%
     ];
 
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -44,7 +57,7 @@ fn test_snap_zone_basic() {
 
 #[test]
 fn test_multiple_snap_zones() {
-    static SNAP_ZONES: [SnapZone<f32>; 3] = [
+    static SNAP_ZONES: [SnapZone<Float>; 3] = [
         SnapZone::new(0.0, 0.02, SnapZoneType::Snap), // 0% ±2%
         SnapZone::new(0.5, 0.03, SnapZoneType::Snap), // 50% ±3%
         SnapZone::new(1.0, 0.02, SnapZoneType::Snap), // 100% ±2%
@@ -53,14 +66,26 @@ fn test_multiple_snap_zones() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -81,21 +106,33 @@ fn test_multiple_snap_zones() {
 
 #[test]
 fn test_dead_zone_basic() {
-    static SNAP_ZONES: [SnapZone<f32>; 1] = [
+    static SNAP_ZONES: [SnapZone<Float>; 1] = [
         SnapZone::new(0.5, 0.05, SnapZoneType::Dead), // Dead zone at 50% ±5%
     ];
 
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -135,7 +172,7 @@ fn test_dead_zone_basic() {
 
 #[test]
 fn test_mixed_snap_and_dead_zones() {
-    static SNAP_ZONES: [SnapZone<f32>; 2] = [
+    static SNAP_ZONES: [SnapZone<Float>; 2] = [
         SnapZone::new(0.0, 0.05, SnapZoneType::Snap), // Snap to 0%
         SnapZone::new(0.5, 0.05, SnapZoneType::Dead), // Dead zone at 50%
     ];
@@ -143,14 +180,26 @@ fn test_mixed_snap_and_dead_zones() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -178,7 +227,7 @@ fn test_mixed_snap_and_dead_zones() {
 #[test]
 fn test_overlapping_zones_first_match_wins() {
     // Intentional overlap: second zone is within first zone's range
-    static SNAP_ZONES: [SnapZone<f32>; 2] = [
+    static SNAP_ZONES: [SnapZone<Float>; 2] = [
         SnapZone::new(0.0, 0.1, SnapZoneType::Snap), // 0% ±10% (range: -0.1 to 0.1)
         SnapZone::new(0.05, 0.03, SnapZoneType::Snap), // 5% ±3% (range: 0.02 to 0.08, overlaps!)
     ];
@@ -186,14 +235,26 @@ fn test_overlapping_zones_first_match_wins() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -209,19 +270,31 @@ fn test_overlapping_zones_first_match_wins() {
 
 #[test]
 fn test_empty_snap_zones() {
-    static SNAP_ZONES: [SnapZone<f32>; 0] = [];
+    static SNAP_ZONES: [SnapZone<Float>; 0] = [];
 
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &SNAP_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     let mut pot = PotHead::new(config).unwrap();
@@ -234,7 +307,7 @@ fn test_empty_snap_zones() {
 
 #[test]
 fn test_snap_zone_validation_overlaps() {
-    static OVERLAPPING_ZONES: [SnapZone<f32>; 2] = [
+    static OVERLAPPING_ZONES: [SnapZone<Float>; 2] = [
         SnapZone::new(0.0, 0.1, SnapZoneType::Snap),
         SnapZone::new(0.05, 0.1, SnapZoneType::Snap), // Overlaps with first
     ];
@@ -242,26 +315,44 @@ fn test_snap_zone_validation_overlaps() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &OVERLAPPING_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     // Config is valid - overlaps are allowed by default
     assert!(config.validate().is_ok());
 
     // But optional validation should catch overlap
-    assert!(config.validate_snap_zones().is_err());
+    assert_eq!(
+        config.validate_snap_zones(),
+        Err(ConfigError::OverlappingSnapZones {
+            first: 0,
+            second: 1
+        })
+    );
 }
 
 #[test]
 fn test_snap_zone_validation_no_overlaps() {
-    static NON_OVERLAPPING_ZONES: [SnapZone<f32>; 3] = [
+    static NON_OVERLAPPING_ZONES: [SnapZone<Float>; 3] = [
         SnapZone::new(0.0, 0.02, SnapZoneType::Snap),
         SnapZone::new(0.5, 0.03, SnapZoneType::Snap),
         SnapZone::new(1.0, 0.02, SnapZoneType::Snap),
@@ -270,17 +361,53 @@ fn test_snap_zone_validation_no_overlaps() {
     let config = Config {
         input_min: 0_u16,
         input_max: 100_u16,
+        input_center: None,
+        end_margin: 0.0,
+        invert_input: false,
         output_min: 0.0_f32,
         output_max: 1.0_f32,
         hysteresis: HysteresisMode::none(),
         curve: ResponseCurve::Linear,
         filter: NoiseFilter::None,
         snap_zones: &NON_OVERLAPPING_ZONES,
+        stage_order: StageOrder::CurveFirst,
+        values: &[],
+        slew_rate: None,
         #[cfg(feature = "grab-mode")]
         grab_mode: GrabMode::None,
+        #[cfg(feature = "grab-mode")]
+        grab_margin: 0.0,
+        #[cfg(feature = "grab-mode")]
+        release_policy: ReleasePolicy::RequireMovement,
+        #[cfg(feature = "fine-adjust")]
+        fine_adjust: None,
     };
 
     // Should pass both validations
     assert!(config.validate().is_ok());
     assert!(config.validate_snap_zones().is_ok());
 }
+
+#[test]
+fn test_snap_zones_on_stack() {
+    // Zones built at runtime - no static table required
+    let zones = [SnapZone::new(0.5, 0.05, SnapZoneType::Snap)];
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .snap_zones(&zones)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+
+    assert_eq!(pot.update(520), 0.5);
+    assert_close(pot.update(700), 0.7);
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {expected}, got {actual}"
+    );
+}
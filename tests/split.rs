@@ -0,0 +1,62 @@
+mod common;
+
+use common::pot;
+use pot_head::{Config, OutputSlot, PotHead};
+
+#[test]
+fn test_reader_sees_latest_output() {
+    let slot = OutputSlot::new();
+    let (mut sampler, reader) = pot((0_u16, 1000), (0.0_f32, 100.0)).split(&slot);
+
+    assert_eq!(reader.read(), 0.0);
+    assert!(!reader.has_new());
+
+    assert_eq!(sampler.update(250), 25.0);
+    sampler.update(500);
+    assert_eq!(reader.read(), 50.0);
+}
+
+#[test]
+fn test_take_only_returns_new_values() {
+    let slot = OutputSlot::new();
+    let (mut sampler, reader) = pot((0_u16, 1000), (0.0_f32, 100.0)).split(&slot);
+
+    assert_eq!(reader.take(), None);
+
+    let output = sampler.update(300);
+    assert_eq!(reader.take(), Some(output));
+    assert_eq!(reader.take(), None);
+}
+
+#[test]
+fn test_integer_output() {
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_range(0_u16, 1000)
+        .build()
+        .unwrap();
+    let slot = OutputSlot::new();
+    let (mut sampler, reader) = PotHead::new(config).unwrap().split(&slot);
+
+    let output = sampler.update(4095);
+    assert_eq!(reader.read(), output);
+    assert_eq!(output, 1000);
+}
+
+#[test]
+fn test_halves_across_threads() {
+    static SLOT: OutputSlot = OutputSlot::new();
+
+    let (mut sampler, reader) = pot((0_u16, 1000), (0.0_f32, 100.0)).split(&SLOT);
+
+    let producer = std::thread::spawn(move || {
+        for input in 0..=1000 {
+            sampler.update(input);
+        }
+        sampler.into_inner()
+    });
+
+    let pot = producer.join().unwrap();
+    assert_eq!(reader.take(), Some(100.0));
+    assert_eq!(pot.last_output(), 100.0);
+}
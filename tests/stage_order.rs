@@ -0,0 +1,101 @@
+#![cfg(feature = "std-math")]
+
+use pot_head::{
+    Config, Float, HysteresisMode, PotHead, ResponseCurve, SnapZone, SnapZoneType, StageOrder,
+};
+
+fn log_config(
+    stage_order: StageOrder,
+    hysteresis: HysteresisMode<Float>,
+    snap_zones: &'static [SnapZone<Float>],
+) -> Config<'static, u16, Float> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .curve(ResponseCurve::Logarithmic)
+        .hysteresis(hysteresis)
+        .snap_zones(snap_zones)
+        .stage_order(stage_order)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_hysteresis_threshold_in_curved_terms_by_default() {
+    let config = log_config(
+        StageOrder::CurveFirst,
+        HysteresisMode::ChangeThreshold { threshold: 0.1 },
+        &[],
+    );
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(0);
+    // 15% travel is only ~3% after the log curve - below threshold
+    assert_eq!(pot.update(150), 0.0);
+}
+
+#[test]
+fn test_hysteresis_first_uses_physical_threshold() {
+    let config = log_config(
+        StageOrder::HysteresisFirst,
+        HysteresisMode::ChangeThreshold { threshold: 0.1 },
+        &[],
+    );
+    let mut pot = PotHead::new(config).unwrap();
+
+    pot.update(0);
+    let output = pot.update(150);
+    assert!((output - ResponseCurve::Logarithmic.apply(0.15)).abs() < 1e-6);
+}
+
+#[test]
+fn test_physical_first_snaps_before_curve() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.05, SnapZoneType::Snap)];
+
+    let config = log_config(StageOrder::PhysicalFirst, HysteresisMode::none(), &ZONES);
+    let mut pot = PotHead::new(config).unwrap();
+    let expected = ResponseCurve::Logarithmic.apply(0.5);
+    assert!((pot.update(520) - expected).abs() < 1e-6);
+
+    // With the default order the zone is in curved terms and 52% travel misses it
+    let config = log_config(StageOrder::CurveFirst, HysteresisMode::none(), &ZONES);
+    let mut pot = PotHead::new(config).unwrap();
+    assert!(pot.update(520) < 0.45);
+}
+
+#[test]
+fn test_physical_first_dead_zone_holds() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.1, SnapZoneType::Dead)];
+
+    let config = log_config(StageOrder::PhysicalFirst, HysteresisMode::none(), &ZONES);
+    let mut pot = PotHead::new(config).unwrap();
+
+    let held = pot.update(350);
+    assert_eq!(pot.update(450), held);
+    assert_eq!(pot.update(550), held);
+    assert!(pot.update(700) > held);
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_physical_position_is_curved_in_every_order() {
+    static ZONES: [SnapZone<Float>; 1] = [SnapZone::new(0.5, 0.1, SnapZoneType::Snap)];
+    let expected = ResponseCurve::Logarithmic.apply(0.52);
+
+    for order in [
+        StageOrder::CurveFirst,
+        StageOrder::HysteresisFirst,
+        StageOrder::PhysicalFirst,
+    ] {
+        let config = log_config(order, HysteresisMode::none(), &ZONES);
+        let mut pot = PotHead::new(config).unwrap();
+        pot.update(520);
+
+        // Snap zones don't move the physical position
+        assert!(
+            (pot.physical_position() - expected).abs() < 1e-6,
+            "{order:?}: {}",
+            pot.physical_position()
+        );
+    }
+}
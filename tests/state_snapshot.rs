@@ -0,0 +1,229 @@
+mod common;
+
+use common::filtered_pot;
+use pot_head::{CalibrationData, ConfigError, Float, NoiseFilter, SnapshotError, StateSnapshot};
+
+#[test]
+fn test_round_trip() {
+    let mut pot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    pot.reconfigure(|c| c.input_max = 900).unwrap();
+    pot.update(450);
+    pot.update(450);
+    let snapshot = pot.save_state();
+    assert_eq!(
+        (
+            snapshot.calibration.input_min,
+            snapshot.calibration.input_max
+        ),
+        (0, 900)
+    );
+    assert_eq!(snapshot.output, 0.5);
+    assert_eq!(snapshot.filtered, Some(0.5));
+
+    let mut restored = self::filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    restored.restore_state(&snapshot).unwrap();
+    assert_eq!(restored.config().input_max, 900);
+    assert_eq!(restored.last_output(), 0.5);
+    assert_eq!(restored.filtered_position(), 0.5);
+    assert_eq!(restored.save_state(), snapshot);
+}
+
+#[test]
+fn test_filter_is_seeded() {
+    let mut pot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    pot.update(800);
+    let snapshot = pot.save_state();
+
+    let mut restored = self::filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    restored.restore_state(&snapshot).unwrap();
+
+    // The EMA continues from the saved position instead of starting over
+    assert!((restored.update(400) - 0.6).abs() < 1e-6);
+}
+
+#[test]
+fn test_never_updated() {
+    let snapshot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    )
+    .save_state();
+    assert_eq!(snapshot.filtered, None);
+
+    let mut restored = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    restored.restore_state(&snapshot).unwrap();
+    assert_eq!(restored.update(300), 0.3);
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_grab_state() {
+    use pot_head::GrabMode;
+
+    let mut pot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    pot.reconfigure(|c| c.grab_mode = GrabMode::Pickup).unwrap();
+    pot.set_virtual_value(0.7);
+    pot.update(200);
+    let snapshot = pot.save_state();
+    assert_eq!(snapshot.virtual_value, 0.7);
+    assert!(!snapshot.grabbed);
+
+    let mut restored = pot.clone();
+    restored.reset();
+    restored.restore_state(&snapshot).unwrap();
+    assert!(restored.is_waiting_for_grab());
+    assert_eq!(restored.update(200), 0.7);
+}
+
+#[test]
+fn test_invalid_snapshot_leaves_pot_unchanged() {
+    let mut pot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    pot.update(500);
+    let before = pot.save_state();
+
+    let invalid = StateSnapshot {
+        calibration: CalibrationData {
+            input_min: 100,
+            input_max: 100,
+            ..before.calibration
+        },
+        ..before
+    };
+    assert!(matches!(
+        pot.restore_state(&invalid),
+        Err(ConfigError::InvalidInputRange { .. })
+    ));
+
+    let nan = StateSnapshot {
+        output: Float::NAN,
+        ..before
+    };
+    assert_eq!(pot.restore_state(&nan), Err(ConfigError::InvalidSnapshot));
+    assert_eq!(pot.save_state(), before);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_postcard() {
+    let mut pot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    pot.update(250);
+    let snapshot = pot.save_state();
+
+    let bytes = postcard::to_allocvec(&snapshot).unwrap();
+    let decoded: StateSnapshot<u16> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, snapshot);
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let mut pot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    pot.reconfigure(|c| {
+        c.input_min = 30;
+        c.input_center = Some(520);
+        c.input_max = 4050;
+        c.end_margin = 0.0625;
+        c.invert_input = true;
+    })
+    .unwrap();
+    pot.update(1000);
+    let snapshot = pot.save_state();
+
+    let bytes = snapshot.to_bytes();
+    assert_eq!(bytes.len(), StateSnapshot::<u16>::SIZE);
+    assert_eq!(bytes[0], StateSnapshot::<u16>::VERSION);
+
+    let decoded = StateSnapshot::<u16>::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.calibration, snapshot.calibration);
+    assert_eq!(decoded.grabbed, snapshot.grabbed);
+
+    // Normalized values keep 16-bit resolution
+    assert!((decoded.output - snapshot.output).abs() <= 1.0 / 65535.0);
+    assert!((decoded.filtered.unwrap() - snapshot.filtered.unwrap()).abs() <= 1.0 / 65535.0);
+
+    let mut restored = self::filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    );
+    restored.restore_state(&decoded).unwrap();
+    assert_eq!(restored.config().input_center, Some(520));
+    assert_eq!(restored.config().end_margin, 0.0625);
+}
+
+#[test]
+fn test_bytes_never_updated() {
+    let snapshot = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    )
+    .save_state();
+    let decoded = StateSnapshot::<u16>::from_bytes(&snapshot.to_bytes()).unwrap();
+    assert_eq!(decoded, snapshot);
+}
+
+#[test]
+fn test_bytes_rejected() {
+    let bytes = filtered_pot(
+        (0_u16, 1000),
+        (0.0 as Float, 1.0),
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 },
+    )
+    .save_state()
+    .to_bytes();
+
+    assert_eq!(
+        StateSnapshot::<u16>::from_bytes(&bytes[..10]),
+        Err(SnapshotError::TooShort)
+    );
+
+    // Erased EEPROM
+    assert_eq!(
+        StateSnapshot::<u16>::from_bytes(&[0xFF; 25]),
+        Err(SnapshotError::UnsupportedVersion(0xFF))
+    );
+
+    let mut torn = bytes;
+    torn[15] ^= 0x10;
+    assert_eq!(
+        StateSnapshot::<u16>::from_bytes(&torn),
+        Err(SnapshotError::Corrupted)
+    );
+}
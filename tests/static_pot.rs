@@ -0,0 +1,108 @@
+use pot_head::static_pot::{ChangeThreshold, Ema, Linear, NoFilter, NoHysteresis, SchmittTrigger};
+use pot_head::{Config, ConfigError, HysteresisMode, NoiseFilter, PotHead, StaticPotHead};
+
+const SAMPLES: [u16; 12] = [0, 120, 480, 470, 900, 1000, 990, 650, 300, 310, 20, 500];
+
+#[test]
+fn test_matches_pothead_linear_ema_threshold() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 10.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.4 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.05 })
+        .build()
+        .unwrap();
+    let mut dynamic = PotHead::new(config).unwrap();
+
+    let mut fixed: StaticPotHead<u16, f32, Linear, Ema, ChangeThreshold> = StaticPotHead::new(
+        (0, 1000),
+        (0.0, 10.0),
+        Ema::new(0.4),
+        ChangeThreshold::new(0.05),
+    )
+    .unwrap();
+
+    for sample in SAMPLES {
+        assert_eq!(fixed.update(sample), dynamic.update(sample));
+    }
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn test_matches_pothead_log_schmitt() {
+    use pot_head::ResponseCurve;
+    use pot_head::static_pot::Logarithmic;
+
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .curve(ResponseCurve::Logarithmic)
+        .hysteresis(HysteresisMode::SchmittTrigger {
+            rising: 0.6,
+            falling: 0.2,
+        })
+        .build()
+        .unwrap();
+    let mut dynamic = PotHead::new(config).unwrap();
+
+    let mut fixed: StaticPotHead<u16, f32, Logarithmic, NoFilter, SchmittTrigger> =
+        StaticPotHead::new(
+            (0, 1000),
+            (0.0, 1.0),
+            NoFilter,
+            SchmittTrigger::new(0.6, 0.2),
+        )
+        .unwrap();
+
+    for sample in SAMPLES {
+        assert_eq!(fixed.update(sample), dynamic.update(sample));
+    }
+}
+
+#[test]
+fn test_integer_output_and_clamping() {
+    let mut pot: StaticPotHead<u16, u8, Linear, NoFilter, NoHysteresis> =
+        StaticPotHead::new((100, 1100), (0, 100), NoFilter, NoHysteresis).unwrap();
+
+    assert_eq!(pot.update(0), 0);
+    assert_eq!(pot.update(600), 50);
+    assert_eq!(pot.update(5000), 100);
+    assert_eq!(pot.last_output(), 100);
+}
+
+#[test]
+fn test_descending_input_range() {
+    let mut pot: StaticPotHead<u16, u8, Linear, NoFilter, NoHysteresis> =
+        StaticPotHead::new((1100, 100), (0, 100), NoFilter, NoHysteresis).unwrap();
+
+    assert_eq!(pot.update(5000), 0);
+    assert_eq!(pot.update(600), 50);
+    assert_eq!(pot.update(0), 100);
+}
+
+#[test]
+fn test_validation() {
+    let result: Result<StaticPotHead<u16, f32, Linear, NoFilter, NoHysteresis>, _> =
+        StaticPotHead::new((1000, 1000), (0.0, 1.0), NoFilter, NoHysteresis);
+    assert!(matches!(result, Err(ConfigError::InvalidInputRange { .. })));
+
+    let result: Result<StaticPotHead<u16, f32, Linear, NoFilter, NoHysteresis>, _> =
+        StaticPotHead::new((0, 1000), (0.5, 0.5), NoFilter, NoHysteresis);
+    assert!(matches!(
+        result,
+        Err(ConfigError::InvalidOutputRange { .. })
+    ));
+
+    let result: Result<StaticPotHead<u16, f32, Linear, Ema, NoHysteresis>, _> =
+        StaticPotHead::new((0, 1000), (0.0, 1.0), Ema::new(0.0), NoHysteresis);
+    assert!(matches!(result, Err(ConfigError::InvalidFilter { .. })));
+
+    let result: Result<StaticPotHead<u16, f32, Linear, NoFilter, SchmittTrigger>, _> =
+        StaticPotHead::new(
+            (0, 1000),
+            (0.0, 1.0),
+            NoFilter,
+            SchmittTrigger::new(0.2, 0.6),
+        );
+    assert!(matches!(result, Err(ConfigError::InvalidHysteresis { .. })));
+}
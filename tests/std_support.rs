@@ -0,0 +1,63 @@
+use pot_head::{Config, PotHead};
+
+#[cfg(feature = "std")]
+use pot_head::{ConfigError, NoiseFilter};
+
+fn config() -> Config<'static, u16, f32> {
+    Config::builder()
+        .input_range(0_u16, 100)
+        .output_range(0.0_f32, 1.0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn config_display_matches_summary() {
+    let config = config();
+    assert_eq!(config.to_string(), config.describe().to_string());
+}
+
+#[test]
+fn pot_display_appends_output() {
+    let mut pot = PotHead::new(config()).unwrap();
+    pot.update(50);
+
+    let text = pot.to_string();
+    assert!(text.starts_with(&config().to_string()));
+    assert!(text.ends_with(", output: 0.5"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn from_configs_builds_every_pot() {
+    let pots = PotHead::from_configs([config(), config()]).unwrap();
+    assert_eq!(pots.len(), 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn from_configs_returns_first_error() {
+    let mut invalid = config();
+    invalid.filter = NoiseFilter::ExponentialMovingAverage { alpha: 0.0 };
+
+    let result = PotHead::from_configs([config(), invalid]);
+    assert!(matches!(result, Err(ConfigError::InvalidFilter { .. })));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn update_vec_collects_every_output() {
+    let mut pot = PotHead::new(config()).unwrap();
+    assert_eq!(pot.update_vec(&[0, 50, 100]), vec![0.0, 0.5, 1.0]);
+}
+
+#[test]
+fn config_error_boxes_as_std_error() {
+    fn build() -> Result<Config<'static, u16, f32>, Box<dyn std::error::Error>> {
+        Ok(Config::builder()
+            .input_range(10_u16, 10)
+            .output_range(0.0_f32, 1.0)
+            .build()?)
+    }
+    assert!(build().is_err());
+}
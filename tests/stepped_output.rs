@@ -0,0 +1,59 @@
+mod common;
+
+use common::pot;
+
+#[test]
+fn test_stepped_covers_full_range() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+
+    assert_eq!(pot.update_stepped(0, 4), 0);
+    assert_eq!(pot.update_stepped(1000, 4), 3);
+    assert_eq!(pot.update_stepped(0, 4), 0);
+}
+
+#[test]
+fn test_stepped_first_update_uses_plain_rounding() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    assert_eq!(pot.update_stepped(600, 4), 2);
+}
+
+#[test]
+fn test_stepped_boundary_hysteresis() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+
+    // Step width 0.25, hysteresis margin 0.0625
+    assert_eq!(pot.update_stepped(200, 4), 0);
+
+    // Just past the 0.25 boundary - not far enough to switch
+    assert_eq!(pot.update_stepped(260, 4), 0);
+    assert_eq!(pot.update_stepped(240, 4), 0);
+    assert_eq!(pot.update_stepped(260, 4), 0);
+
+    // Clearly past the boundary
+    assert_eq!(pot.update_stepped(320, 4), 1);
+
+    // Back just below the boundary - stays on step 1
+    assert_eq!(pot.update_stepped(240, 4), 1);
+    assert_eq!(pot.update_stepped(180, 4), 0);
+}
+
+#[test]
+fn test_stepped_large_jump() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    assert_eq!(pot.update_stepped(0, 10), 0);
+    assert_eq!(pot.update_stepped(750, 10), 7);
+    assert_eq!(pot.update_stepped(50, 10), 0);
+}
+
+#[test]
+fn test_stepped_keeps_continuous_output() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update_stepped(300, 4);
+    assert_eq!(pot.last_output(), 0.3);
+}
+
+#[test]
+fn test_stepped_zero_steps() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    assert_eq!(pot.update_stepped(800, 0), 0);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "test-utils")]
+
+use pot_head::test_utils::{Motion, Noise, Rng, Signal};
+use pot_head::{Config, Float, HysteresisMode, NoiseFilter, PotHead};
+
+#[test]
+fn test_same_seed_same_signal() {
+    let signal = || {
+        Signal::new(
+            Motion::Wiggle {
+                center: 0.5,
+                amplitude: 0.1,
+                period: 40,
+            },
+            Noise::Gaussian { sigma: 0.02 },
+            1234,
+        )
+    };
+
+    let a: Vec<Float> = signal().take(200).collect();
+    let b: Vec<Float> = signal().take(200).collect();
+    assert_eq!(a, b);
+
+    let other: Vec<Float> = Signal::new(Motion::Hold { position: 0.5 }, Noise::None, 1)
+        .take(3)
+        .collect();
+    assert_eq!(other, [0.5, 0.5, 0.5]);
+}
+
+#[test]
+fn test_noise_stays_within_its_bounds() {
+    let mut rng = Rng::new(0);
+
+    let uniform = Noise::Uniform { amplitude: 0.05 };
+    let gaussian = Noise::Gaussian { sigma: 0.01 };
+    let mut sum = 0.0;
+    for _ in 0..10_000 {
+        assert!(uniform.sample(&mut rng).abs() <= 0.05);
+
+        let offset = gaussian.sample(&mut rng);
+        assert!(offset.abs() <= 0.06);
+        sum += offset;
+    }
+    assert!((sum / 10_000.0).abs() < 0.001);
+
+    let spikes = Noise::Spikes {
+        probability: 0.1,
+        amplitude: 0.3,
+    };
+    let count = (0..10_000)
+        .map(|_| spikes.sample(&mut rng))
+        .filter(|&offset| offset != 0.0)
+        .inspect(|offset| assert_eq!(offset.abs(), 0.3))
+        .count();
+    assert!((800..1200).contains(&count), "{count} spikes");
+}
+
+#[test]
+fn test_motions() {
+    let sweep = Motion::Sweep {
+        from: 0.2,
+        to: 0.8,
+        samples: 100,
+    };
+    assert_eq!(sweep.position(0), 0.2);
+    assert!((sweep.position(50) - 0.5).abs() < 1e-6);
+    assert_eq!(sweep.position(500), 0.8);
+
+    let step = Motion::Step {
+        from: 0.0,
+        to: 1.0,
+        at: 10,
+    };
+    assert_eq!(step.position(9), 0.0);
+    assert_eq!(step.position(10), 1.0);
+
+    let wiggle = Motion::Wiggle {
+        center: 0.5,
+        amplitude: 0.1,
+        period: 8,
+    };
+    let cycle: Vec<Float> = (0..8).map(|i| wiggle.position(i)).collect();
+    assert_eq!(cycle, [0.5, 0.55, 0.6, 0.55, 0.5, 0.45, 0.4, 0.45]);
+    assert_eq!(wiggle.position(8), 0.5);
+}
+
+#[test]
+fn test_adc_counts_clamp_at_the_rails() {
+    let counts: Vec<u16> = Signal::new(
+        Motion::Sweep {
+            from: -0.5,
+            to: 1.5,
+            samples: 4,
+        },
+        Noise::None,
+        1,
+    )
+    .adc(12)
+    .take(5)
+    .collect();
+
+    assert_eq!(counts, [0, 0, 2048, 4095, 4095]);
+}
+
+#[test]
+fn test_hysteresis_holds_a_noisy_resting_pot() {
+    let config = Config::from_adc_bits(12)
+        .output_range(0.0, 1.0)
+        .filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.3 })
+        .hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.02 })
+        .build()
+        .unwrap();
+    let mut pot: PotHead<u16, Float> = PotHead::new(config).unwrap();
+
+    let resting = Signal::new(
+        Motion::Hold { position: 0.4 },
+        Noise::Gaussian { sigma: 0.005 },
+        99,
+    );
+    let mut raw = resting.adc(12).take(500);
+    for sample in raw.by_ref().take(50) {
+        pot.update(sample);
+    }
+
+    let settled = pot.last_output();
+    assert!((settled - 0.4).abs() < 0.02);
+    for sample in raw {
+        assert_eq!(pot.update(sample), settled);
+    }
+}
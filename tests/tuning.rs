@@ -0,0 +1,182 @@
+#![cfg(feature = "tuning")]
+
+// Values travel as f32, so tests use values exact in f32 for the f64 feature.
+
+mod common;
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use common::pot;
+use embedded_io::{ErrorType, Read, ReadReady, Write};
+use pot_head::{
+    FRAME_LEN, HysteresisMode, NoiseFilter, ResponseCurve, SYNC, TuningMessage, TuningServer,
+    TuningStatus,
+};
+
+/// In-memory serial link: bytes from the host (shared, so a test can send more
+/// later), bytes to the host.
+struct Link {
+    rx: Rc<RefCell<VecDeque<u8>>>,
+    tx: Vec<u8>,
+}
+
+impl ErrorType for Link {
+    type Error = Infallible;
+}
+
+impl Read for Link {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+        let mut rx = self.rx.borrow_mut();
+        let count = buf.len().min(rx.len());
+        for byte in &mut buf[..count] {
+            *byte = rx.pop_front().unwrap();
+        }
+        Ok(count)
+    }
+}
+
+impl ReadReady for Link {
+    fn read_ready(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.rx.borrow().is_empty())
+    }
+}
+
+impl Write for Link {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+        self.tx.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+fn server(bytes: &[u8]) -> TuningServer<Link> {
+    TuningServer::new(Link {
+        rx: Rc::new(RefCell::new(bytes.iter().copied().collect())),
+        tx: Vec::new(),
+    })
+}
+
+fn acks(server: TuningServer<Link>) -> Vec<(u8, TuningStatus)> {
+    server
+        .into_inner()
+        .tx
+        .chunks(FRAME_LEN)
+        .map(|frame| TuningStatus::from_ack(frame.try_into().unwrap()).unwrap())
+        .collect()
+}
+
+#[test]
+fn messages_round_trip() {
+    let messages = [
+        TuningMessage::SetAlpha(0.25),
+        TuningMessage::SetThreshold(0.015625),
+        TuningMessage::SetSchmitt {
+            rising: 0.625,
+            falling: 0.375,
+        },
+        TuningMessage::SetCurve(ResponseCurve::Linear),
+    ];
+    for message in messages {
+        let frame = message.encode(3);
+        assert_eq!(frame[0], SYNC);
+        assert_eq!(TuningMessage::decode(&frame), Some(Ok((3, message))));
+    }
+}
+
+#[test]
+fn corrupt_frame_is_rejected() {
+    let mut frame = TuningMessage::SetAlpha(0.5).encode(0);
+    frame[4] ^= 0x10;
+    assert_eq!(TuningMessage::decode(&frame), None);
+}
+
+#[test]
+fn server_applies_and_acknowledges() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    let mut server = server(&TuningMessage::SetAlpha(0.5).encode(0));
+
+    let (channel, message) = server.receive().unwrap().unwrap();
+    let status = server.apply(channel, message, Some(&mut pot)).unwrap();
+
+    assert_eq!(status, TuningStatus::Applied);
+    assert_eq!(
+        pot.config().filter,
+        NoiseFilter::ExponentialMovingAverage { alpha: 0.5 }
+    );
+    assert_eq!(acks(server), vec![(0, TuningStatus::Applied)]);
+}
+
+#[test]
+fn invalid_value_leaves_pot_unchanged() {
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    let mut server = server(&TuningMessage::SetAlpha(1.5).encode(0));
+
+    let (channel, message) = server.receive().unwrap().unwrap();
+    let status = server.apply(channel, message, Some(&mut pot)).unwrap();
+
+    assert_eq!(status, TuningStatus::InvalidValue);
+    assert_eq!(pot.config().filter, NoiseFilter::None);
+}
+
+#[test]
+fn unknown_channel_is_acknowledged() {
+    let mut pots = [pot((0_u16, 4095), (0.0_f32, 1.0))];
+    let mut server = server(&TuningMessage::SetThreshold(0.03125).encode(5));
+
+    let (channel, message) = server.receive().unwrap().unwrap();
+    server
+        .apply(channel, message, pots.get_mut(channel as usize))
+        .unwrap();
+
+    assert_eq!(acks(server), vec![(5, TuningStatus::UnknownChannel)]);
+}
+
+#[test]
+fn partial_frames_wait_for_the_rest() {
+    let message = TuningMessage::SetThreshold(0.03125);
+    let frame = message.encode(0);
+    let rx = Rc::new(RefCell::new(frame[..5].iter().copied().collect()));
+    let mut server = TuningServer::new(Link {
+        rx: rx.clone(),
+        tx: Vec::new(),
+    });
+    assert_eq!(server.receive().unwrap(), None);
+
+    rx.borrow_mut().extend(&frame[5..]);
+    assert_eq!(server.receive().unwrap(), Some((0, message)));
+}
+
+#[test]
+fn resyncs_after_garbage() {
+    let mut bytes = vec![0x00, 0x13, SYNC, 0x42];
+    bytes.extend_from_slice(&TuningMessage::SetThreshold(0.03125).encode(1));
+
+    let mut pot = pot((0_u16, 4095), (0.0_f32, 1.0));
+    let mut server = server(&bytes);
+    let (channel, message) = server.receive().unwrap().unwrap();
+    server.apply(channel, message, Some(&mut pot)).unwrap();
+
+    assert_eq!(channel, 1);
+    assert_eq!(
+        pot.config().hysteresis,
+        HysteresisMode::ChangeThreshold { threshold: 0.03125 }
+    );
+    assert_eq!(server.discarded(), 1);
+}
+
+#[test]
+fn unknown_command_is_unsupported() {
+    let mut frame = TuningMessage::SetAlpha(0.5).encode(2);
+    frame[2] = 0x7F;
+    frame[FRAME_LEN - 1] = frame[1..FRAME_LEN - 1].iter().fold(0, |sum, &b| sum ^ b);
+
+    let mut server = server(&frame);
+    assert_eq!(server.receive().unwrap(), None);
+    assert_eq!(acks(server), vec![(2, TuningStatus::Unsupported)]);
+}
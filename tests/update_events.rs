@@ -0,0 +1,121 @@
+use pot_head::{
+    Config, Float, HysteresisMode, PotHead, SchmittState, SnapZone, SnapZoneType, UpdateEvent,
+};
+
+#[cfg(feature = "grab-mode")]
+use pot_head::GrabMode;
+
+static ZONES: [SnapZone<Float>; 2] = [
+    SnapZone::new(0.0, 0.05, SnapZoneType::Snap),
+    SnapZone::new(1.0, 0.05, SnapZoneType::Snap),
+];
+
+fn zone_config() -> Config<'static, u16, f32> {
+    Config::builder()
+        .input_range(0, 1000)
+        .output_range(0.0, 1.0)
+        .snap_zones(&ZONES)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_changed_event() {
+    let mut pot = PotHead::new(zone_config()).unwrap();
+
+    let (output, events) = pot.update_with_events(500);
+    assert_eq!(output, 0.5);
+    assert!(events.changed);
+
+    let (_, events) = pot.update_with_events(500);
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_snap_zone_enter_and_leave() {
+    let mut pot = PotHead::new(zone_config()).unwrap();
+    pot.update(500);
+
+    let (_, events) = pot.update_with_events(980);
+    assert_eq!(events.entered_zone, Some(1));
+    assert_eq!(events.left_zone, None);
+
+    // Moving within the zone reports nothing new
+    let (_, events) = pot.update_with_events(990);
+    assert!(events.is_empty());
+
+    let (_, events) = pot.update_with_events(500);
+    assert_eq!(events.entered_zone, None);
+    assert_eq!(events.left_zone, Some(1));
+}
+
+#[test]
+fn test_jump_between_zones() {
+    let mut pot = PotHead::new(zone_config()).unwrap();
+    pot.update(10);
+
+    let (_, events) = pot.update_with_events(1000);
+    let all: Vec<_> = events.iter().collect();
+    assert_eq!(
+        all,
+        [
+            UpdateEvent::Changed,
+            UpdateEvent::LeftSnapZone(0),
+            UpdateEvent::EnteredSnapZone(1),
+        ]
+    );
+}
+
+#[test]
+fn test_schmitt_edge() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .hysteresis(HysteresisMode::SchmittTrigger {
+            rising: 0.6,
+            falling: 0.4,
+        })
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.update(0);
+
+    let (_, events) = pot.update_with_events(700);
+    assert_eq!(events.schmitt_edge, Some(SchmittState::High));
+
+    let (_, events) = pot.update_with_events(500);
+    assert_eq!(events.schmitt_edge, None);
+
+    let (_, events) = pot.update_with_events(300);
+    assert_eq!(events.schmitt_edge, Some(SchmittState::Low));
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_grabbed_event() {
+    let config = Config::builder()
+        .input_range(0_u16, 1000)
+        .output_range(0.0_f32, 1.0)
+        .grab_mode(GrabMode::Pickup)
+        .build()
+        .unwrap();
+    let mut pot = PotHead::new(config).unwrap();
+    pot.set_virtual_value(0.5);
+
+    let (_, events) = pot.update_with_events(300);
+    assert!(!events.grabbed);
+
+    let (_, events) = pot.update_with_events(600);
+    assert!(events.grabbed);
+
+    let (_, events) = pot.update_with_events(700);
+    assert!(!events.grabbed);
+}
+
+#[cfg(feature = "grab-mode")]
+#[test]
+fn test_no_grabbed_event_without_grab_mode() {
+    let mut pot = PotHead::new(zone_config()).unwrap();
+    let (_, events) = pot.update_with_events(500);
+    assert!(!events.grabbed);
+}
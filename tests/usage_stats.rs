@@ -0,0 +1,92 @@
+#![cfg(feature = "usage-stats")]
+
+mod common;
+
+use common::{assert_close, pot};
+use pot_head::{Config, PotHead};
+
+#[test]
+fn starts_empty() {
+    let stats = pot((0_u16, 1000), (0.0_f32, 1.0)).stats();
+    assert_eq!(stats.travel, 0.0);
+    assert_eq!(stats.direction_changes, 0);
+    assert_eq!(stats.updates, 0);
+}
+
+#[test]
+fn counts_updates_but_not_nan() {
+    let mut pot = PotHead::new(
+        Config::builder()
+            .input_range(0.0_f32, 1.0)
+            .output_range(0.0_f32, 1.0)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    pot.update(0.2);
+    pot.update(f32::NAN);
+    pot.update(0.3);
+    assert_eq!(pot.stats().updates, 2);
+}
+
+#[test]
+fn accumulates_travel_in_both_directions() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(0);
+
+    // Full sweep up and back down
+    for input in (0..=1000).step_by(100) {
+        pot.update(input);
+    }
+    for input in (0..=1000).rev().step_by(100) {
+        pot.update(input);
+    }
+
+    assert_close(pot.stats().travel, 2.0);
+    assert_eq!(pot.stats().direction_changes, 1);
+}
+
+#[test]
+fn jitter_is_not_a_direction_change() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(500);
+
+    for input in [502, 498, 503, 497, 501] {
+        pot.update(input);
+    }
+    assert_eq!(pot.stats().direction_changes, 0);
+
+    // Back and forth by 5% counts every reversal
+    for input in [550, 500, 550, 500] {
+        pot.update(input);
+    }
+    assert_eq!(pot.stats().direction_changes, 3);
+}
+
+#[test]
+fn travel_beyond_many_sweeps_keeps_small_steps() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(0);
+
+    for _ in 0..50 {
+        pot.update(1000);
+        pot.update(0);
+    }
+    pot.update(1);
+
+    assert_close(pot.stats().travel, 100.001);
+}
+
+#[test]
+fn survives_reset_until_cleared() {
+    let mut pot = pot((0_u16, 1000), (0.0_f32, 1.0));
+    pot.update(0);
+    pot.update(500);
+
+    pot.reset();
+    assert_close(pot.stats().travel, 0.5);
+
+    pot.clear_stats();
+    assert_eq!(pot.stats(), Default::default());
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "value-format")]
+
+use heapless::String;
+use pot_head::{Config, PotHead, ValueFormat};
+
+#[test]
+fn renders_precision_and_unit() {
+    let format = ValueFormat::new(1, " Hz");
+    let text: String<16> = format.render(440.25_f32).unwrap();
+    assert_eq!(text, "440.2 Hz");
+}
+
+#[test]
+fn renders_integer_outputs() {
+    let text: String<8> = ValueFormat::new(0, "").render(127_u8).unwrap();
+    assert_eq!(text, "127");
+}
+
+#[test]
+fn percent_scales_normalized_value() {
+    let text: String<8> = ValueFormat::percent(0).render(0.5_f32).unwrap();
+    assert_eq!(text, "50%");
+}
+
+#[cfg(feature = "std-math")]
+#[test]
+fn decibels_convert_linear_gain() {
+    let format = ValueFormat::decibels(1);
+    assert_eq!(format.render::<16, _>(0.5_f32).unwrap(), "-6.0 dB");
+    assert_eq!(format.render::<16, _>(1.0_f32).unwrap(), "0.0 dB");
+    assert_eq!(format.render::<16, _>(0.0_f32).unwrap(), "-inf dB");
+}
+
+#[test]
+fn overflow_returns_error() {
+    let result = ValueFormat::new(3, " dB").render::<4, _>(12.5_f32);
+    assert!(result.is_err());
+}
+
+#[test]
+fn renders_pot_output() {
+    let config = Config::builder()
+        .input_range(0_u16, 4095)
+        .output_percent()
+        .build()
+        .unwrap();
+    let mut pot = PotHead::<u16, f32>::new(config).unwrap();
+    pot.update(4095);
+
+    let text: String<16> = ValueFormat::new(0, "%").render(pot.last_output()).unwrap();
+    assert_eq!(text, "100%");
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "wasm")]
+
+// Error paths construct a `JsError`, which needs a JavaScript host, so only the
+// success paths run natively.
+
+use pot_head::WasmPotHead;
+
+#[test]
+fn process_replays_trace() {
+    let mut pot = WasmPotHead::new(0.0, 100.0, 0.0, 1.0).unwrap();
+    assert_eq!(pot.process(&[0.0, 50.0, 100.0]), vec![0.0, 0.5, 1.0]);
+}
+
+#[test]
+fn tuning_changes_processing() {
+    let mut pot = WasmPotHead::new(0.0, 100.0, 0.0, 1.0).unwrap();
+    pot.set_ema(0.5).unwrap();
+
+    pot.update(0.0);
+    assert_eq!(pot.update(100.0), 0.5);
+
+    pot.set_no_filter().unwrap();
+    pot.reset();
+    assert_eq!(pot.update(100.0), 1.0);
+}
+
+#[test]
+fn export_config_emits_builder_code() {
+    let mut pot = WasmPotHead::new(0.0, 4095.0, 0.0, 1.0).unwrap();
+    pot.set_ema(0.25).unwrap();
+    pot.set_threshold(0.01).unwrap();
+    pot.set_invert_input(true).unwrap();
+
+    let code = pot.export_config();
+    assert!(code.starts_with("Config::builder()\n    .input_range(0.0, 4095.0)\n"));
+    assert!(code.contains(".invert_input(true)"));
+    assert!(code.contains(".filter(NoiseFilter::ExponentialMovingAverage { alpha: 0.25 })"));
+    assert!(code.contains(".hysteresis(HysteresisMode::ChangeThreshold { threshold: 0.01 })"));
+    assert!(!code.contains(".slew_rate("));
+    assert!(code.ends_with(".build()"));
+}
+
+#[test]
+fn describe_matches_config_display() {
+    let pot = WasmPotHead::new(0.0, 100.0, 0.0, 1.0).unwrap();
+    assert!(pot.describe().starts_with("0..100 -> 0..1"));
+}